@@ -0,0 +1,36 @@
+//! Estimate the size of a module after minify and gzip, used by partial bundling when `partialBundling.sizeMetric` is `estimatedGzip`.
+use std::{io::Write, sync::Arc};
+
+use farmfe_core::{
+  module::{Module, ModuleMetaData},
+  swc_common::SourceMap,
+  swc_ecma_ast::EsVersion,
+};
+use farmfe_toolkit::{css::codegen_css_stylesheet, script::codegen_module};
+
+/// The module is printed without whitespaces and comments and compressed by the fastest gzip level.
+/// Identifiers are not mangled, so the estimated size is a bit larger than the real one
+pub fn estimate_module_size(module: &Module) -> usize {
+  let code = match module.meta.as_ref() {
+    ModuleMetaData::Script(script) => codegen_module(
+      &script.ast,
+      EsVersion::latest(),
+      Arc::new(SourceMap::default()),
+      None,
+      true,
+      None,
+    )
+    .ok(),
+    ModuleMetaData::Css(css) => Some(codegen_css_stylesheet(&css.ast, None, true).0.into_bytes()),
+    ModuleMetaData::Html(_) | ModuleMetaData::Custom(_) => None,
+  }
+  .unwrap_or_else(|| module.content.as_bytes().to_vec());
+
+  let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::fast());
+
+  encoder
+    .write_all(&code)
+    .and_then(|_| encoder.finish())
+    .map(|bytes| bytes.len())
+    .unwrap_or(code.len())
+}
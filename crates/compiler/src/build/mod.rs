@@ -9,6 +9,7 @@ use std::{
 
 use farmfe_core::{
   cache::module_cache::CachedModule,
+  config::partial_bundling::PartialBundlingSizeMetric,
   context::CompilationContext,
   error::{CompilationError, Result},
   farm_profile_function, farm_profile_scope,
@@ -23,7 +24,7 @@ use farmfe_core::{
     PluginResolveHookParam, PluginResolveHookResult, PluginTransformHookParam, ResolveKind,
   },
   rayon::ThreadPool,
-  serde_json::json,
+  serde_json,
 };
 
 use farmfe_plugin_lazy_compilation::DYNAMIC_VIRTUAL_SUFFIX;
@@ -32,8 +33,9 @@ use farmfe_utils::stringify_query;
 
 use crate::{
   build::{
-    analyze_deps::analyze_deps, finalize_module::finalize_module, load::load, parse::parse,
-    resolve::resolve, transform::transform,
+    analyze_deps::analyze_deps, estimate_module_size::estimate_module_size,
+    finalize_module::finalize_module, load::load, parse::parse, resolve::resolve,
+    transform::transform,
   },
   Compiler,
 };
@@ -55,6 +57,7 @@ macro_rules! call_and_catch_error {
 }
 
 pub(crate) mod analyze_deps;
+pub(crate) mod estimate_module_size;
 pub(crate) mod finalize_module;
 pub(crate) mod load;
 pub(crate) mod module_cache;
@@ -82,6 +85,12 @@ pub(crate) struct BuildModuleGraphThreadedParams {
   pub cached_dependency: Option<ModuleId>,
 }
 
+/// A module and its dependencies of the last successful build, see [Compiler::build_error_module]
+pub(crate) type LastBuiltModule = (
+  Module,
+  Vec<(PluginAnalyzeDepsHookResultEntry, Option<ModuleId>)>,
+);
+
 pub(crate) struct HandleDependenciesParams {
   pub module: Module,
   pub resolve_param: PluginResolveHookParam,
@@ -150,16 +159,16 @@ impl Compiler {
       self.context.record_manager.set_build_end_time();
       self.context.record_manager.set_end_time();
       self.set_module_graph_stats();
+      self.context.record_manager.set_errors(&errors);
 
-      let mut error_messages = vec![];
-      for error in errors {
-        error_messages.push(error.to_string());
+      if self.context.config.continue_on_error {
+        return Err(CompilationError::multiple(errors));
       }
-      let errors_json = json!(error_messages
-        .iter()
-        .map(|e| e.to_string())
-        .collect::<Vec<_>>());
-      return Err(CompilationError::GenericError(errors_json.to_string()));
+
+      // errors with a location are kept as json objects, see CompilationError::messages_json
+      return Err(CompilationError::GenericError(
+        CompilationError::messages_json(&errors),
+      ));
     }
 
     // set module graph cache
@@ -339,6 +348,58 @@ impl Compiler {
     Ok(deps.into_iter().map(|dep| (dep, None)).collect())
   }
 
+  /// Keep a module that failed to build as an error module when `compilation.errorTolerant` is enabled, returns the
+  /// dependencies of the error module, or the error if the module can not be kept.
+  /// The error module keeps the meta and dependencies of `last_module`, the last successful build of the module.
+  /// Otherwise it's a script module that throws the error when it's executed
+  pub(crate) fn build_error_module(
+    error: CompilationError,
+    resolve_result: PluginResolveHookResult,
+    last_module: Option<LastBuiltModule>,
+    module: &mut Module,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Vec<(PluginAnalyzeDepsHookResultEntry, Option<ModuleId>)>> {
+    if !context.config.error_tolerant {
+      return Err(error);
+    }
+
+    if let Some((last_module, deps)) = last_module {
+      *module = last_module;
+      module.error = Some(Arc::new(error));
+      return Ok(deps);
+    }
+
+    let content = format!(
+      "throw new Error({});",
+      serde_json::to_string(&error.to_string()).unwrap()
+    );
+    let transform_result = PluginDriverTransformHookResult {
+      content: content.clone(),
+      source_map_chain: vec![],
+      module_type: Some(ModuleType::Js),
+    };
+    let hook_context = PluginHookContext {
+      caller: None,
+      meta: HashMap::new(),
+    };
+    module.content = Arc::new(content);
+
+    match Self::build_module_after_transform(
+      resolve_result,
+      ModuleType::Js,
+      transform_result,
+      module,
+      context,
+      &hook_context,
+    ) {
+      Ok(deps) => {
+        module.error = Some(Arc::new(error));
+        Ok(deps.into_iter().map(|dep| (dep, None)).collect())
+      }
+      Err(_) => Err(error),
+    }
+  }
+
   fn build_module_after_transform(
     resolve_result: PluginResolveHookResult,
     load_module_type: ModuleType,
@@ -397,6 +458,10 @@ impl Compiler {
     call_and_catch_error!(finalize_module, module, &analyze_deps_result, context);
     // ================ Finalize Module End ===============
 
+    if context.config.partial_bundling.size_metric == PartialBundlingSizeMetric::EstimatedGzip {
+      module.estimated_size = estimate_module_size(module);
+    }
+
     Ok(analyze_deps_result)
   }
 
@@ -430,6 +495,17 @@ impl Compiler {
       match resolve_module_result {
         ResolveModuleResult::Built(module_id) => {
           farm_profile_scope!(format!("module {:?} already exists", module_id));
+          // an entry may be built first as a dependency of another entry, e.g. the entry `index` imports the entry
+          // `a` dynamically. Which one is resolved first depends on the thread scheduling, so the entry must be
+          // recorded here too, otherwise it's missing from the entries of the module graph and no entry resource is
+          // generated for it
+          if let ResolveKind::Entry(name) = &resolve_param.kind {
+            context
+              .module_graph
+              .write()
+              .entries
+              .insert(module_id.clone(), name.to_string());
+          }
           // add edge to the graph
           Self::add_edge(&resolve_param, module_id, order, &context);
         }
@@ -467,11 +543,11 @@ impl Compiler {
             return;
           }
 
-          match Self::build_module(
-            resolve_module_id_result.resolve_result,
-            &mut module,
-            &context,
-          ) {
+          let resolve_result = resolve_module_id_result.resolve_result;
+
+          match Self::build_module(resolve_result.clone(), &mut module, &context)
+            .or_else(|e| Self::build_error_module(e, resolve_result, None, &mut module, &context))
+          {
             Err(e) => {
               err_sender.send(e).unwrap();
             }
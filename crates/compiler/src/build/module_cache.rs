@@ -7,6 +7,7 @@ use std::{
 
 use farmfe_core::{
   cache::module_cache::{CachedModule, CachedModuleDependency, CachedWatchDependency},
+  config::partial_bundling::PartialBundlingSizeMetric,
   context::CompilationContext,
   dashmap::DashMap,
   farm_profile_function,
@@ -14,6 +15,8 @@ use farmfe_core::{
   rayon::prelude::*,
 };
 
+use super::estimate_module_size::estimate_module_size;
+
 pub fn get_timestamp_of_module(module_id: &ModuleId, root: &str) -> u128 {
   farm_profile_function!(format!("get_timestamp_of_module: {:?}", module_id));
   let resolved_path = module_id.resolved_path(root);
@@ -166,7 +169,8 @@ pub fn set_module_graph_cache(module_ids: Vec<ModuleId>, context: &Arc<Compilati
   let modules = module_ids
     .iter()
     .map(|id| module_graph.module(id).unwrap())
-    .filter(|m| !m.external)
+    // error modules are rebuilt next time
+    .filter(|m| !m.external && !m.is_errored())
     .collect::<Vec<_>>();
 
   for module in &modules {
@@ -226,6 +230,13 @@ pub fn set_module_graph_cache(module_ids: Vec<ModuleId>, context: &Arc<Compilati
             }
           })
           .collect(),
+        watch_globs: context
+          .watch_graph
+          .read()
+          .globs(&module.id)
+          .into_iter()
+          .cloned()
+          .collect(),
       };
 
       context
@@ -253,12 +264,26 @@ pub fn handle_cached_modules(
     }
   };
 
+  // the module may be cached before `partialBundling.sizeMetric` is changed
+  if context.config.partial_bundling.size_metric == PartialBundlingSizeMetric::EstimatedGzip
+    && cached_module.module.estimated_size == 0
+  {
+    cached_module.module.estimated_size = estimate_module_size(&cached_module.module);
+  }
+
   handle_relation_roots(
     &cached_module.module.id,
     &cached_module.watch_dependencies,
     context,
   )?;
 
+  if !cached_module.watch_globs.is_empty() {
+    context.set_watch_globs(
+      cached_module.module.id.clone(),
+      cached_module.watch_globs.clone(),
+    );
+  }
+
   Ok(())
 }
 
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use farmfe_core::{
   context::CompilationContext,
-  error::{CompilationError, Result},
+  error::{CompilationError, ErrorLocation, Result},
   module::ModuleMetaData,
   plugin::{PluginHookContext, PluginParseHookParam},
 };
@@ -27,11 +27,19 @@ pub fn parse(
           "No plugins handle this kind of module: {:?}",
           parse_param.module_type
         ),
+        loc: None,
       }),
     },
     Err(e) => Err(CompilationError::ParseError {
       resolved_path: parse_param.module_id.to_string(),
       msg: e.to_string(),
+      loc: e.loc().map(|loc| {
+        // the plugins parse the module by its id, editors need the real path
+        Box::new(ErrorLocation {
+          file: parse_param.resolved_path.clone(),
+          ..loc.clone()
+        })
+      }),
     }),
   }
 }
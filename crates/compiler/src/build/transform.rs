@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use farmfe_core::{
   context::CompilationContext,
-  error::{CompilationError, Result},
+  error::{CompilationError, ErrorLocation, Result},
   plugin::{plugin_driver::PluginDriverTransformHookResult, PluginTransformHookParam},
 };
 
@@ -13,12 +13,19 @@ pub fn transform(
   #[cfg(feature = "profile")]
   farmfe_core::puffin::profile_function!();
   let module_id = transform_param.module_id.to_string();
+  let resolved_path = transform_param.resolved_path.to_string();
   let transformed = context
     .plugin_driver
     .transform(transform_param, context)
     .map_err(|e| CompilationError::TransformError {
       resolved_path: module_id,
       msg: e.to_string(),
+      loc: e.loc().map(|loc| {
+        Box::new(ErrorLocation {
+          file: resolved_path,
+          ..loc.clone()
+        })
+      }),
     })?;
 
   Ok(transformed)
@@ -0,0 +1,140 @@
+//! Emit gzip and brotli compressed variants of the final resources, so servers can serve them without compressing on the fly.
+use std::{io::Write, sync::Arc};
+
+use farmfe_core::{
+  config::compression::{CompressionAlgorithm, CompressionConfig},
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  rayon::{
+    prelude::{IntoParallelIterator, ParallelIterator},
+    ThreadPool,
+  },
+  resource::{Resource, ResourceOrigin, ResourceType},
+  stats::{CompressedResourceStats, CompressionStats},
+};
+
+const COMPRESSIBLE_ASSETS: [&str; 2] = ["svg", "json"];
+
+pub fn compress_resources(
+  context: &Arc<CompilationContext>,
+  thread_pool: &ThreadPool,
+) -> Result<()> {
+  let Some(config) = context
+    .config
+    .compression
+    .as_ref()
+    .clone()
+    .unwrap_as_option(|enabled| enabled.and_then(|e| e.then(Default::default)))
+  else {
+    return Ok(());
+  };
+
+  let mut resources_map = context.resources_map.lock();
+  // the compressed variants of the resources that are removed or renamed when updating are stale
+  let stale = resources_map
+    .values()
+    .filter(|resource| {
+      matches!(&resource.origin, ResourceOrigin::Resource(origin) if !resources_map.contains_key(origin))
+    })
+    .map(|resource| resource.name.clone())
+    .collect::<Vec<_>>();
+
+  for name in stale {
+    resources_map.remove(&name);
+  }
+
+  let candidates = resources_map
+    .values()
+    .filter(|resource| {
+      !resource.emitted && is_compressible(resource) && resource.bytes.len() > config.threshold
+    })
+    .collect::<Vec<_>>();
+
+  let compressed = thread_pool.install(|| {
+    candidates
+      .into_par_iter()
+      .map(|resource| {
+        let variants = config
+          .algorithms
+          .iter()
+          .map(|algorithm| Ok((*algorithm, compress(&resource.bytes, *algorithm, &config)?)))
+          .collect::<Result<Vec<_>>>()?;
+
+        Ok((resource, variants))
+      })
+      .collect::<Result<Vec<_>>>()
+  })?;
+
+  let mut stats = CompressionStats::default();
+  let mut compressed_resources = vec![];
+
+  for (resource, variants) in compressed {
+    let resource_stats = stats
+      .resources
+      .entry(resource.name.clone())
+      .or_insert_with(|| CompressedResourceStats {
+        size: resource.bytes.len(),
+        ..Default::default()
+      });
+
+    for (algorithm, bytes) in variants {
+      match algorithm {
+        CompressionAlgorithm::Gzip => resource_stats.gzip_size = Some(bytes.len()),
+        CompressionAlgorithm::Brotli => resource_stats.brotli_size = Some(bytes.len()),
+      }
+
+      compressed_resources.push(Resource {
+        name: format!("{}.{}", resource.name, algorithm.ext()),
+        bytes,
+        emitted: false,
+        resource_type: ResourceType::Asset(algorithm.ext().to_string()),
+        origin: ResourceOrigin::Resource(resource.name.clone()),
+        info: None,
+      });
+    }
+  }
+
+  for resource in compressed_resources {
+    resources_map.insert(resource.name.clone(), resource);
+  }
+
+  context.record_manager.set_compression_stats(stats);
+
+  Ok(())
+}
+
+fn is_compressible(resource: &Resource) -> bool {
+  match &resource.resource_type {
+    ResourceType::Runtime | ResourceType::Js | ResourceType::Css | ResourceType::Html => true,
+    ResourceType::Asset(ext) => COMPRESSIBLE_ASSETS.contains(&ext.as_str()),
+    _ => false,
+  }
+}
+
+fn compress(
+  bytes: &[u8],
+  algorithm: CompressionAlgorithm,
+  config: &CompressionConfig,
+) -> Result<Vec<u8>> {
+  let map_err =
+    |e: std::io::Error| CompilationError::GenericError(format!("failed to compress resource: {e}"));
+
+  match algorithm {
+    CompressionAlgorithm::Gzip => {
+      let mut encoder =
+        flate2::write::GzEncoder::new(vec![], flate2::Compression::new(config.gzip_level.min(9)));
+      encoder.write_all(bytes).map_err(map_err)?;
+      encoder.finish().map_err(map_err)
+    }
+    CompressionAlgorithm::Brotli => {
+      let mut result = vec![];
+      let params = brotli::enc::BrotliEncoderParams {
+        quality: config.brotli_level.min(11) as i32,
+        ..Default::default()
+      };
+      brotli::BrotliCompress(&mut &bytes[..], &mut result, &params).map_err(map_err)?;
+
+      Ok(result)
+    }
+  }
+}
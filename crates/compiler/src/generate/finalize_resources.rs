@@ -27,7 +27,8 @@ pub fn finalize_resources(context: &Arc<CompilationContext>) -> farmfe_core::err
 
       for resource in resources_map.values() {
         match &resource.origin {
-          farmfe_core::resource::ResourceOrigin::ResourcePot(_) => { /* do nothing for resource pot */
+          farmfe_core::resource::ResourceOrigin::ResourcePot(_)
+          | farmfe_core::resource::ResourceOrigin::Resource(_) => { /* do nothing for resource pot */
           }
           farmfe_core::resource::ResourceOrigin::Module(m) => {
             if !module_graph.has_module(m) {
@@ -4,13 +4,16 @@ use farmfe_core::{error::Result, plugin::PluginHookContext};
 
 use crate::{
   generate::{
-    finalize_resources::finalize_resources, partial_bundling::partial_bundling,
+    compress_resources::compress_resources, finalize_resources::finalize_resources,
+    partial_bundling::partial_bundling,
     render_resource_pots::render_resource_pots_and_generate_resources,
   },
   Compiler,
 };
 
+pub(crate) mod compress_resources;
 pub(crate) mod finalize_resources;
+pub(crate) mod output_variants;
 pub(crate) mod partial_bundling;
 pub(crate) mod render_resource_pots;
 pub(crate) mod resource_cache;
@@ -35,6 +38,8 @@ impl Compiler {
 
     finalize_resources(&self.context)?;
 
+    compress_resources(&self.context, &self.thread_pool)?;
+
     self.context.plugin_driver.generate_end(&self.context)
   }
 
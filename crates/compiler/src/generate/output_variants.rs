@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use farmfe_core::{
+  config::{Config, OutputConfig},
+  error::{CompilationError, Result},
+  module::Module,
+};
+use farmfe_plugin_script::{
+  is_library_cjs, is_replace_import_meta_url_when_building, replace_import_meta_url,
+};
+
+use crate::Compiler;
+
+impl Compiler {
+  /// Generate `output.variants` from the module graph built for the primary output.
+  /// The resources of the variants are merged into the resources of the primary output, the resources of the last generation are replaced
+  pub(crate) fn generate_output_variants(&self) -> Result<()> {
+    let module_graph = self.variants_module_graph.read();
+    let inlined_assets = self.context.inlined_assets();
+
+    for (index, variant) in self.variants.iter().enumerate() {
+      let mut variant_module_graph = module_graph.clone();
+      replace_import_meta_url_for_output(
+        &self.context.config,
+        &variant.context.config.output,
+        variant_module_graph.modules_mut(),
+      );
+      *variant.context.module_graph.write() = variant_module_graph;
+
+      for (module_id, data_uri) in &inlined_assets {
+        variant
+          .context
+          .set_inlined_asset(module_id, data_uri.clone());
+      }
+
+      let previous_resources = variant
+        .context
+        .resources_map
+        .lock()
+        .drain()
+        .map(|(name, _)| name)
+        .collect::<HashSet<_>>();
+
+      variant.generate()?;
+
+      self
+        .context
+        .record_manager
+        .merge_output_variant_stats(&variant.context.record_manager);
+
+      let mut resources_map = self.context.resources_map.lock();
+      let variant_resources_map = variant.context.resources_map.lock();
+
+      for name in &previous_resources {
+        if !variant_resources_map.contains_key(name) {
+          resources_map.remove(name);
+        }
+      }
+
+      for (name, resource) in variant_resources_map.iter() {
+        // assets shared by the outputs are generated with the same content
+        if let Some(existing) = resources_map.get(name) {
+          if !previous_resources.contains(name) && existing.bytes != resource.bytes {
+            return Err(CompilationError::OutputVariantFilenameConflict {
+              name: name.clone(),
+              index,
+            });
+          }
+        }
+
+        resources_map.insert(name.clone(), resource.clone());
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// `import.meta.url` is kept when building if the outputs mix cjs and other formats, replace it in the modules of the cjs output
+pub(crate) fn replace_import_meta_url_for_output<'a>(
+  config: &Config,
+  output: &OutputConfig,
+  modules: impl IntoIterator<Item = &'a mut Module>,
+) {
+  if !is_library_cjs(output) || is_replace_import_meta_url_when_building(config) {
+    return;
+  }
+
+  for module in modules {
+    if module.module_type.is_script() && !module.external {
+      replace_import_meta_url(&mut module.meta.as_script_mut().ast);
+    }
+  }
+}
@@ -11,6 +11,7 @@ use farmfe_core::{
     resource_pot::{ResourcePot, ResourcePotType},
     resource_pot_map::ResourcePotMap,
   },
+  stats::{PartialBundlingMergeStep, PartialBundlingModuleStats, PartialBundlingResourcePotStats},
 };
 
 pub fn partial_bundling(
@@ -57,6 +58,11 @@ pub fn generate_resource_pot_map(
   let (enforce_resource_pots, modules) = generate_enforce_resource_pots(context);
 
   let mut resources_pots = call_partial_bundling_hook(&modules, context, hook_context)?;
+
+  if context.config.partial_bundling.report.enabled() {
+    record_enforce_resource_pots_stats(&enforce_resource_pots, context);
+  }
+
   // extends enforce resource pots
   resources_pots.extend(enforce_resource_pots);
   fill_necessary_fields_for_resource_pot(resources_pots.iter_mut().collect(), context);
@@ -64,6 +70,13 @@ pub fn generate_resource_pot_map(
   let mut resource_pot_map = ResourcePotMap::new();
 
   for resource_pot in resources_pots {
+    // a resource pot with the same id would silently replace the previous one and drop its modules
+    if resource_pot_map.has_resource_pot(&resource_pot.id) {
+      return Err(CompilationError::ResourcePotConflict {
+        id: resource_pot.id.clone(),
+      });
+    }
+
     resource_pot_map.add_resource_pot(resource_pot);
   }
 
@@ -206,6 +219,54 @@ fn generate_enforce_resource_pots(
   (enforce_resource_pot_map.take_resource_pots(), modules)
 }
 
+/// The modules of the enforce resource pots are not handled by the partial bundling hook, record them separately
+fn record_enforce_resource_pots_stats(
+  enforce_resource_pots: &[ResourcePot],
+  context: &Arc<CompilationContext>,
+) {
+  let module_graph = context.module_graph.read();
+  let config = &context.config.partial_bundling;
+
+  context
+    .record_manager
+    .update_partial_bundling_stats(|stats| {
+      for resource_pot in enforce_resource_pots {
+        let mut size = 0;
+        let mut enforce_resource = None;
+
+        for module_id in resource_pot.modules() {
+          let module = module_graph.module(module_id).unwrap();
+          let name = get_enforce_resource_name_for_module(module_id, &config.enforce_resources);
+          let module_size = config.module_size(module);
+          size += module_size;
+
+          stats.modules.insert(
+            module_id.clone(),
+            PartialBundlingModuleStats {
+              resource_pot: resource_pot.id.clone(),
+              size: module_size,
+              enforce_resource: name.clone(),
+              ..Default::default()
+            },
+          );
+          enforce_resource = enforce_resource.or(name);
+        }
+
+        stats.resource_pots.insert(
+          resource_pot.id.clone(),
+          PartialBundlingResourcePotStats {
+            module_group: None,
+            size,
+            merge_history: enforce_resource
+              .map(|name| vec![PartialBundlingMergeStep::EnforceResource { name }])
+              .unwrap_or_default(),
+            resources: vec![],
+          },
+        );
+      }
+    });
+}
+
 #[cfg(test)]
 mod tests {
   use std::collections::HashSet;
@@ -5,7 +5,8 @@ use farmfe_core::{
   error::{CompilationError, Result},
   parking_lot::Mutex,
   plugin::{
-    PluginGenerateResourcesHookResult, PluginHookContext, PluginRenderResourcePotHookParam,
+    OutputFilenameKind, PluginGenerateResourcesHookResult, PluginHookContext,
+    PluginOutputFilenameHookParams, PluginRenderResourcePotHookParam,
   },
   rayon::prelude::{IntoParallelIterator, ParallelIterator},
   resource::{
@@ -13,10 +14,7 @@ use farmfe_core::{
     ResourceType,
   },
 };
-use farmfe_toolkit::{
-  common::append_source_map_comment,
-  fs::{transform_output_entry_filename, transform_output_filename},
-};
+use farmfe_toolkit::{common::append_source_map_comment, fs::transform_output_filename};
 
 use crate::generate::resource_cache::{set_resource_cache, try_get_resource_cache};
 
@@ -94,23 +92,35 @@ pub fn render_resource_pots_and_generate_resources(
           augment_resource_hash.unwrap_or_default().as_bytes(),
         ]
         .concat();
-        if let Some(name) = resource_pot.entry_module.as_ref() {
-          let entry_name = entries.get(name).unwrap();
-          r.name = transform_output_entry_filename(
-            context.config.output.entry_filename.clone(),
-            resource_pot.id.to_string().as_str(),
-            entry_name,
-            content_with_extra_content_hash,
-            &r.resource_type.to_ext(),
+        let ext = r.resource_type.to_ext();
+        let (filename_config, params) = if let Some(name) = resource_pot.entry_module.as_ref() {
+          let mut params = PluginOutputFilenameHookParams::new(
+            OutputFilenameKind::Entry,
+            &resource_pot.id,
+            &ext,
+            &context.config,
           );
+          params.entry_name = entries.get(name).cloned();
+
+          (&context.config.output.entry_filename, params)
         } else {
-          r.name = transform_output_filename(
-            context.config.output.filename.clone(),
-            &r.name,
-            content_with_extra_content_hash,
-            &r.resource_type.to_ext(),
-          );
-        }
+          (
+            &context.config.output.filename,
+            PluginOutputFilenameHookParams::new(
+              OutputFilenameKind::Chunk,
+              &r.name,
+              &ext,
+              &context.config,
+            ),
+          )
+        };
+
+        r.name = transform_output_filename(
+          filename_config,
+          &params,
+          content_with_extra_content_hash,
+          context,
+        )?;
       }
 
       // process generated resources after rendering
@@ -155,13 +165,22 @@ pub fn render_resource_pots_and_generate_resources(
       Ok::<(), CompilationError>(())
     })?;
 
-  let mut resources_map: farmfe_core::parking_lot::lock_api::MutexGuard<
-    '_,
-    farmfe_core::parking_lot::RawMutex,
-    std::collections::HashMap<String, farmfe_core::resource::Resource>,
-  > = context.resources_map.lock();
+  let mut resources_map = context.resources_map.lock();
 
   for resource in resources.lock().drain(..) {
+    // a filename template without `[name]` or `[hash]` may generate the same filename for different resources
+    if let Some(existing) = resources_map.get(&resource.name) {
+      let (first, second) = (existing.origin.to_string(), resource.origin.to_string());
+
+      if first != second {
+        return Err(CompilationError::OutputFilenameConflict {
+          name: resource.name,
+          first,
+          second,
+        });
+      }
+    }
+
     resources_map.insert(resource.name.clone(), resource);
   }
 
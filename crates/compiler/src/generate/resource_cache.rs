@@ -5,7 +5,17 @@ use farmfe_core::{
   plugin::PluginGenerateResourcesHookResult, resource::resource_pot::ResourcePot,
 };
 
+/// Id of the resource pot in the resource cache, resource pots of `output.variants` have the same ids as the primary output.
+/// The cache files are named by the cache key, so the id is part of the cache key too
+fn resource_cache_id(resource_pot: &ResourcePot, context: &Arc<CompilationContext>) -> String {
+  match context.output_variant {
+    Some(index) => format!("{}@output.variants[{index}]", resource_pot.id),
+    None => resource_pot.id.clone(),
+  }
+}
+
 /// Cache key of resource is consist of:
+/// - resource cache id
 /// - modules id
 /// - used_exports of modules
 pub fn get_resource_cache_key(
@@ -13,7 +23,7 @@ pub fn get_resource_cache_key(
   context: &Arc<CompilationContext>,
 ) -> String {
   let module_graph = context.module_graph.read();
-  let mut code = resource_pot.id.to_string();
+  let mut code = resource_cache_id(resource_pot, context);
 
   for module_id in &resource_pot.modules() {
     let module = module_graph
@@ -40,11 +50,10 @@ pub fn try_get_resource_cache(
   resource_pot: &ResourcePot,
   context: &Arc<CompilationContext>,
 ) -> farmfe_core::error::Result<Option<CachedResourcePot>> {
+  let cache_id = resource_cache_id(resource_pot, context);
+
   if !context.config.persistent_cache.enabled()
-    || !context
-      .cache_manager
-      .resource_cache
-      .has_cache(&resource_pot.id)
+    || !context.cache_manager.resource_cache.has_cache(&cache_id)
   {
     // println!("cache not found : {:?}", resource_pot.id);
     return Ok(None);
@@ -55,12 +64,12 @@ pub fn try_get_resource_cache(
   if !context
     .cache_manager
     .resource_cache
-    .is_cache_changed(resource_pot.id.clone(), hash)
+    .is_cache_changed(cache_id.clone(), hash)
   {
     let cached_resource_pot = context
       .cache_manager
       .resource_cache
-      .get_cache(&resource_pot.id)
+      .get_cache(&cache_id)
       .unwrap();
     return Ok(Some(cached_resource_pot));
   } else {
@@ -81,7 +90,7 @@ pub fn set_resource_cache(
   let cache_key = get_resource_cache_key(resource_pot, context);
 
   context.cache_manager.resource_cache.set_cache(
-    &resource_pot.id,
+    &resource_cache_id(resource_pot, context),
     CachedResourcePot {
       resources: resource.clone(),
       meta: resource_pot.meta.clone(),
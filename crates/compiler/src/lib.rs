@@ -12,10 +12,14 @@ use farmfe_core::{
   context::CompilationContext,
   error::Result,
   farm_profile_function,
-  plugin::Plugin,
+  module::module_graph::ModuleGraph,
+  parking_lot::RwLock,
+  plugin::{ErroredModule, Plugin},
   rayon::{ThreadPool, ThreadPoolBuilder},
 };
 
+use crate::generate::output_variants::replace_import_meta_url_for_output;
+
 pub use farmfe_plugin_css::FARM_CSS_MODULES_SUFFIX;
 pub use farmfe_plugin_lazy_compilation::DYNAMIC_VIRTUAL_SUFFIX;
 pub use farmfe_plugin_runtime::RUNTIME_SUFFIX;
@@ -27,59 +31,110 @@ pub mod update;
 
 pub struct Compiler {
   context: Arc<CompilationContext>,
+  /// compilers that generate `output.variants` from the module graph built by this compiler
+  variants: Vec<Compiler>,
+  /// module graph of the build stage that the variants are generated from, it's patched when updating
+  variants_module_graph: RwLock<ModuleGraph>,
   pub thread_pool: Arc<ThreadPool>,
 }
 
 impl Compiler {
   /// The params are [farmfe_core::config::Config] and dynamic load rust plugins and js plugins [farmfe_core::plugin::Plugin]
-  pub fn new(config: Config, mut plugin_adapters: Vec<Arc<dyn Plugin>>) -> Result<Self> {
+  pub fn new(config: Config, plugin_adapters: Vec<Arc<dyn Plugin>>) -> Result<Self> {
+    let variant_configs = config
+      .output
+      .variants
+      .iter()
+      .map(|variant| Config {
+        output: Box::new(config.output.variant(variant)),
+        ..config.clone()
+      })
+      .collect::<Vec<_>>();
+
+    let mut plugins = Self::internal_plugins(&config);
+    plugins.extend(plugin_adapters.iter().cloned());
+
+    let mut compiler = Self::new_without_internal_plugins(config, plugins)?;
+
+    for (index, variant_config) in variant_configs.into_iter().enumerate() {
+      let mut plugins = Self::internal_plugins(&variant_config);
+      plugins.extend(plugin_adapters.iter().cloned());
+      plugins.sort_by_key(|b| std::cmp::Reverse(b.priority()));
+
+      let mut context = compiler
+        .context
+        .new_output_variant(index, variant_config, plugins);
+      context.plugin_driver.config(&mut context.config)?;
+
+      compiler.variants.push(Self {
+        context: Arc::new(context),
+        variants: vec![],
+        variants_module_graph: RwLock::new(ModuleGraph::new()),
+        thread_pool: compiler.thread_pool.clone(),
+      });
+    }
+
+    Ok(compiler)
+  }
+
+  fn internal_plugins(config: &Config) -> Vec<Arc<dyn Plugin>> {
     let mut plugins = vec![
-      Arc::new(farmfe_plugin_runtime::FarmPluginRuntime::new(&config)) as _,
+      Arc::new(farmfe_plugin_runtime::FarmPluginRuntime::new(config)) as _,
       Arc::new(farmfe_plugin_bundle::FarmPluginBundle::new()) as _,
       // register internal core plugins
-      Arc::new(farmfe_plugin_script::FarmPluginScript::new(&config)) as _,
-      Arc::new(farmfe_plugin_partial_bundling::FarmPluginPartialBundling::new(&config)) as _,
-      Arc::new(farmfe_plugin_html::FarmPluginHtml::new(&config)) as _,
-      Arc::new(farmfe_plugin_html::FarmPluginTransformHtml::new(&config)) as _,
-      Arc::new(farmfe_plugin_css::FarmPluginCssResolve::new(&config)) as _,
-      Arc::new(farmfe_plugin_css::FarmPluginCss::new(&config)) as _,
+      Arc::new(farmfe_plugin_script::FarmPluginScript::new(config)) as _,
+      Arc::new(farmfe_plugin_partial_bundling::FarmPluginPartialBundling::new(config)) as _,
+      Arc::new(farmfe_plugin_html::FarmPluginHtml::new(config)) as _,
+      Arc::new(farmfe_plugin_html::FarmPluginTransformHtml::new(config)) as _,
+      Arc::new(farmfe_plugin_css::FarmPluginCssResolve::new(config)) as _,
+      Arc::new(farmfe_plugin_css::FarmPluginCss::new(config)) as _,
       Arc::new(farmfe_plugin_static_assets::FarmPluginStaticAssets::new(
-        &config,
+        config,
       )) as _,
-      Arc::new(farmfe_plugin_static_assets::FarmPluginRaw::new(&config)) as _,
-      Arc::new(farmfe_plugin_json::FarmPluginJson::new(&config)) as _,
-      Arc::new(farmfe_plugin_define::FarmPluginDefine::new(&config)) as _,
+      Arc::new(farmfe_plugin_static_assets::FarmPluginRaw::new(config)) as _,
+      Arc::new(farmfe_plugin_json::FarmPluginJson::new(config)) as _,
+      Arc::new(farmfe_plugin_define::FarmPluginDefine::new(config)) as _,
     ];
 
     if config.progress {
-      plugins.push(Arc::new(farmfe_plugin_progress::FarmPluginProgress::new(&config)) as _);
+      plugins.push(Arc::new(farmfe_plugin_progress::FarmPluginProgress::new(config)) as _);
     }
 
     if config.lazy_compilation {
       plugins.push(
-        Arc::new(farmfe_plugin_lazy_compilation::FarmPluginLazyCompilation::new(&config)) as _,
+        Arc::new(farmfe_plugin_lazy_compilation::FarmPluginLazyCompilation::new(config)) as _,
       );
     }
 
     if config.tree_shaking.enabled() {
-      plugins.push(Arc::new(farmfe_plugin_tree_shake::FarmPluginTreeShake::new(&config)) as _);
+      plugins.push(Arc::new(farmfe_plugin_tree_shake::FarmPluginTreeShake::new(config)) as _);
     }
 
     if config.minify.enabled() {
-      plugins.push(Arc::new(farmfe_plugin_minify::FarmPluginMinify::new(&config)) as _);
-      plugins.push(Arc::new(farmfe_plugin_html::FarmPluginMinifyHtml::new(&config)) as _);
+      plugins.push(Arc::new(farmfe_plugin_minify::FarmPluginMinify::new(config)) as _);
+      plugins.push(Arc::new(farmfe_plugin_html::FarmPluginMinifyHtml::new(config)) as _);
+    }
+
+    if config.html.integrity.is_some() {
+      plugins.push(Arc::new(farmfe_plugin_html::FarmPluginHtmlIntegrity::new(config)) as _);
+    }
+
+    if config.html.csp_hashes.is_some() {
+      plugins.push(Arc::new(farmfe_plugin_html::FarmPluginHtmlCspHashes::new(config)) as _);
+    }
+
+    if config.output.manifest.enabled() {
+      plugins.push(Arc::new(farmfe_plugin_manifest::FarmPluginManifest::new(config)) as _);
     }
 
     if config.preset_env.enabled() {
-      plugins.push(Arc::new(farmfe_plugin_polyfill::FarmPluginPolyfill::new(&config)) as _);
+      plugins.push(Arc::new(farmfe_plugin_polyfill::FarmPluginPolyfill::new(config)) as _);
     }
     // default resolve will be executed at last within internal plugins
     // but it will be executed earlier than external plugins
-    plugins.push(Arc::new(farmfe_plugin_resolve::FarmPluginResolve::new(&config)) as _);
-
-    plugins.append(&mut plugin_adapters);
+    plugins.push(Arc::new(farmfe_plugin_resolve::FarmPluginResolve::new(config)) as _);
 
-    Self::new_without_internal_plugins(config, plugins)
+    plugins
   }
 
   pub fn new_without_internal_plugins(
@@ -94,6 +149,8 @@ impl Compiler {
 
     Ok(Self {
       context: Arc::new(context),
+      variants: vec![],
+      variants_module_graph: RwLock::new(ModuleGraph::new()),
       thread_pool: Arc::new(
         ThreadPoolBuilder::new()
           .num_threads(num_cpus::get())
@@ -144,7 +201,19 @@ impl Compiler {
     {
       #[cfg(feature = "profile")]
       farmfe_core::puffin::profile_scope!("Generate Stage");
+      // the generate stage modifies the module graph, so the variants are generated from the graph of the build stage
+      if !self.variants.is_empty() {
+        let mut module_graph = self.context.module_graph.write();
+        *self.variants_module_graph.write() = module_graph.clone();
+        replace_import_meta_url_for_output(
+          &self.context.config,
+          &self.context.config.output,
+          module_graph.modules_mut(),
+        );
+      }
+
       self.generate()?;
+      self.generate_output_variants()?;
     }
 
     self
@@ -179,6 +248,26 @@ impl Compiler {
   pub fn context(&self) -> &Arc<CompilationContext> {
     &self.context
   }
+
+  /// Modules that failed to build and are kept in the module graph as error modules, sorted by module id.
+  /// Always empty unless `compilation.errorTolerant` is enabled
+  pub fn errored_modules(&self) -> Vec<ErroredModule> {
+    let module_graph = self.context.module_graph.read();
+    let mut errored_modules = module_graph
+      .modules()
+      .into_iter()
+      .filter_map(|module| {
+        module.error.as_ref().map(|error| ErroredModule {
+          module_id: module.id.clone(),
+          message: error.to_string(),
+          loc: error.loc().cloned(),
+        })
+      })
+      .collect::<Vec<_>>();
+    errored_modules.sort_by(|a, b| a.module_id.cmp(&b.module_id));
+
+    errored_modules
+  }
 }
 
 fn write_cache(context: Arc<CompilationContext>) {
@@ -202,6 +202,42 @@ fn resolve_watch_graph_paths(
   paths
     .into_iter()
     .flat_map(|(path, update_type)| {
+      // a file matched by the glob patterns of modules is created or deleted, update the modules instead
+      if matches!(update_type, UpdateType::Added | UpdateType::Removed) {
+        let r: Vec<(String, UpdateType)> = watch_graph
+          .glob_roots(&path)
+          .into_iter()
+          .map(|item| {
+            (
+              item.resolved_path_with_query(&context.config.root),
+              UpdateType::Updated,
+            )
+          })
+          .collect();
+
+        if !r.is_empty() {
+          return r;
+        }
+      }
+
+      // a file that was missing when resolving the imports of modules is created, re-resolve the imports
+      if matches!(update_type, UpdateType::Added | UpdateType::Updated) {
+        let r: Vec<(String, UpdateType)> = watch_graph
+          .missing_file_roots(&path)
+          .into_iter()
+          .map(|item| {
+            (
+              item.resolved_path_with_query(&context.config.root),
+              UpdateType::Updated,
+            )
+          })
+          .collect();
+
+        if !r.is_empty() {
+          return r;
+        }
+      }
+
       let id = ModuleId::new(&path, "", &context.config.root);
 
       if watch_graph.has_module(&id) {
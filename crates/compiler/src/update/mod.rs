@@ -4,14 +4,16 @@ use std::{
 };
 
 use farmfe_core::{
-  cache::module_cache::CachedModule,
+  cache::module_cache::{CachedModule, CachedModuleDependency},
+  config::Mode,
   context::CompilationContext,
   error::CompilationError,
   module::{module_graph::ModuleGraphEdgeDataItem, module_group::ModuleGroupId, Module, ModuleId},
   plugin::{PluginResolveHookParam, ResolveKind, UpdateResult, UpdateType},
+  rayon::ThreadPool,
   resource::ResourceType,
   serde::Serialize,
-  serde_json::{self, json},
+  serde_json,
   stats::CompilationPluginHookStats,
 };
 
@@ -20,9 +22,12 @@ use farmfe_toolkit::get_dynamic_resources_map::get_dynamic_resources_map;
 use crate::{
   build::{
     module_cache::handle_cached_modules, BuildModuleGraphThreadedParams, HandleDependenciesParams,
-    ResolvedModuleInfo,
+    LastBuiltModule, ResolvedModuleInfo,
+  },
+  generate::{
+    compress_resources::compress_resources, finalize_resources::finalize_resources,
+    output_variants::replace_import_meta_url_for_output,
   },
-  generate::finalize_resources::finalize_resources,
   Compiler,
 };
 use farmfe_core::error::Result;
@@ -46,6 +51,9 @@ mod patch_module_group_graph;
 mod regenerate_resources;
 mod update_context;
 
+/// the resources of the dynamic imported modules, keyed by the module id
+type DynamicResourcesMap = HashMap<ModuleId, Vec<(String, ResourceType)>>;
+
 enum ResolveModuleResult {
   Cached(ModuleId),
   /// This module is already in previous module graph before the update, and we met it again when resolving dependencies
@@ -136,6 +144,21 @@ impl Compiler {
     }
   }
 
+  /// watched files and the directories of the watched glob patterns
+  fn watch_extra_resources(&self) -> HashSet<ModuleId> {
+    let watch_graph = self.context.watch_graph.read();
+    let mut resources: HashSet<ModuleId> = watch_graph.modules().into_iter().cloned().collect();
+
+    resources.extend(
+      watch_graph
+        .glob_dirs()
+        .into_iter()
+        .map(|dir| ModuleId::new(dir, "", &self.context.config.root)),
+    );
+
+    resources
+  }
+
   pub fn update<F>(
     &self,
     paths: Vec<(String, UpdateType)>,
@@ -154,14 +177,7 @@ impl Compiler {
     let (err_sender, err_receiver) = Self::create_thread_channel();
     let update_context = Arc::new(UpdateContext::new());
 
-    let mut old_watch_extra_resources: HashSet<ModuleId> = self
-      .context
-      .watch_graph
-      .read()
-      .modules()
-      .into_iter()
-      .cloned()
-      .collect();
+    let mut old_watch_extra_resources = self.watch_extra_resources();
 
     let mut update_result = UpdateResult::default();
     self.context.clear_log_store();
@@ -219,16 +235,16 @@ impl Compiler {
       self.context.record_manager.set_build_end_time();
       self.context.record_manager.set_end_time();
       self.set_update_module_graph_stats(&update_context);
+      self.context.record_manager.set_errors(&errors);
 
-      let mut error_messages = vec![];
-      for error in errors {
-        error_messages.push(error.to_string());
+      if self.context.config.continue_on_error {
+        return Err(CompilationError::multiple(errors));
       }
-      let errors_json = json!(error_messages
-        .iter()
-        .map(|e| e.to_string())
-        .collect::<Vec<_>>());
-      return Err(CompilationError::GenericError(errors_json.to_string()));
+
+      // errors with a location are kept as json objects, see CompilationError::messages_json
+      return Err(CompilationError::GenericError(
+        CompilationError::messages_json(&errors),
+      ));
     }
 
     self.context.record_manager.set_build_end_time();
@@ -243,14 +259,31 @@ impl Compiler {
         .collect::<HashSet<_>>()
     };
 
-    let (affected_module_groups, updated_module_ids, diff_result, removed_modules) =
+    let (affected_module_groups, mut updated_module_ids, diff_result, removed_modules) =
       self.diff_and_patch_context(paths, &update_context);
+    // the client keeps running the last successful build of the error modules, they are reported by errored_modules
+    {
+      let module_graph = self.context.module_graph.read();
+      updated_module_ids.retain(|id| !module_graph.module(id).is_some_and(|m| m.is_errored()));
+    }
     // record graph patch result
     self.set_module_group_graph_stats();
 
     // update cache
     set_updated_modules_cache(&updated_module_ids, &diff_result, &self.context);
 
+    if !self.variants.is_empty() {
+      let mut module_graph = self.context.module_graph.write();
+
+      for module_id in updated_module_ids.iter().chain(&diff_result.added_modules) {
+        replace_import_meta_url_for_output(
+          &self.context.config,
+          &self.context.config.output,
+          module_graph.module_mut(module_id),
+        );
+      }
+    }
+
     // call module graph updated hook
     self.context.plugin_driver.module_graph_updated(
       &farmfe_core::plugin::PluginModuleGraphUpdatedHookParams {
@@ -269,17 +302,16 @@ impl Compiler {
       removed_modules,
       callback,
       sync,
-    );
+    )?;
 
     // after update_module, diff old_resource and new_resource
     {
-      let watch_graph = self.context.watch_graph.read();
-      let module_ids: HashSet<&ModuleId> = watch_graph.modules().into_iter().collect();
+      let module_ids = self.watch_extra_resources();
 
       let watch_diff_result = &mut update_result.extra_watch_result;
 
       for id in module_ids {
-        if !old_watch_extra_resources.remove(id) {
+        if !old_watch_extra_resources.remove(&id) {
           watch_diff_result
             .add
             .push(id.resolved_path(&self.context.config.root));
@@ -323,6 +355,7 @@ impl Compiler {
     update_result.mutable_resources = mutable_resources;
     update_result.boundaries = boundaries;
     update_result.dynamic_resources_map = dynamic_resources_map;
+    update_result.errored_modules = self.errored_modules();
     Ok(update_result)
   }
 
@@ -427,11 +460,15 @@ impl Compiler {
             return;
           }
 
-          match Self::build_module(
-            resolve_module_id_result.resolve_result,
-            &mut module,
-            &context,
-          ) {
+          let resolve_result = resolve_module_id_result.resolve_result;
+
+          let build_result = Self::build_module(resolve_result.clone(), &mut module, &context)
+            .or_else(|e| {
+              let last_module = last_built_module(&module.id, &context);
+              Self::build_error_module(e, resolve_result, last_module, &mut module, &context)
+            });
+
+          match build_result {
             Ok(deps) => {
               let params = HandleUpdateDependenciesParams {
                 handle_dependencies_params: HandleDependenciesParams {
@@ -565,6 +602,24 @@ impl Compiler {
 
     let diff_result = diff_module_graph(start_points.clone(), &module_graph, &update_module_graph);
 
+    // the variants are generated from the module graph of the build stage, which is patched by the same update
+    if !self.variants.is_empty() {
+      let mut variants_module_graph = self.variants_module_graph.write();
+      let mut variants_update_module_graph = update_module_graph.clone();
+      let diff_result = diff_module_graph(
+        start_points.clone(),
+        &variants_module_graph,
+        &variants_update_module_graph,
+      );
+
+      patch_module_graph(
+        start_points.clone(),
+        &diff_result,
+        &mut variants_module_graph,
+        &mut variants_update_module_graph,
+      );
+    }
+
     let removed_modules = patch_module_graph(
       start_points.clone(),
       &diff_result,
@@ -607,7 +662,7 @@ impl Compiler {
     removed_modules: HashMap<ModuleId, Module>,
     callback: F,
     sync: bool,
-  ) -> Option<HashMap<ModuleId, Vec<(String, ResourceType)>>>
+  ) -> Result<Option<DynamicResourcesMap>>
   where
     F: FnOnce() + Send + Sync + 'static,
   {
@@ -615,8 +670,9 @@ impl Compiler {
     let cloned_updated_module_ids = updated_module_ids.clone();
     let cloned_context = self.context.clone();
 
-    // if there are new module groups, we should run the tasks synchronously
+    // if there are new module groups or the variants are generated after the primary output, we should run the tasks synchronously
     if sync
+      || !self.variants.is_empty()
       || affected_module_groups
         .iter()
         .any(|ag| !previous_module_groups.contains(ag))
@@ -631,6 +687,9 @@ impl Compiler {
       .unwrap();
 
       finalize_resources(&cloned_context).unwrap();
+      // the error is returned after the callback and update_finished, so the update is always finished
+      let output_result = compress_updated_resources(&cloned_context, &self.thread_pool)
+        .and_then(|_| self.generate_output_variants());
       let module_group_graph = self.context.module_group_graph.read();
       let resource_pot_map = self.context.resource_pot_map.read();
       let resources_map = self.context.resources_map.lock();
@@ -656,7 +715,11 @@ impl Compiler {
         .update_finished(&self.context)
         .unwrap();
       self.context.record_manager.set_end_time();
+
+      output_result?;
     } else {
+      let thread_pool = self.thread_pool.clone();
+
       std::thread::spawn(move || {
         if let Err(e) = regenerate_resources_for_affected_module_groups(
           affected_module_groups,
@@ -670,6 +733,11 @@ impl Compiler {
         }
 
         finalize_resources(&cloned_context).unwrap();
+
+        if let Err(e) = compress_updated_resources(&cloned_context, &thread_pool) {
+          println!("Failed to compress resources: {e}");
+        }
+
         callback();
         cloned_context
           .plugin_driver
@@ -679,8 +747,41 @@ impl Compiler {
       });
     }
 
-    dynamic_resources_map
+    Ok(dynamic_resources_map)
+  }
+}
+
+/// Compressing, brotli especially, is too slow to run on every hmr update, so the compressed variants are only
+/// refreshed when updating a production build, e.g. `farm watch --mode production`
+fn compress_updated_resources(
+  context: &Arc<CompilationContext>,
+  thread_pool: &ThreadPool,
+) -> Result<()> {
+  if matches!(context.config.mode, Mode::Development) {
+    return Ok(());
   }
+
+  compress_resources(context, thread_pool)
+}
+
+/// The module and its dependencies of the last successful build, the module is kept as an error module if it fails to
+/// build this time
+fn last_built_module(
+  module_id: &ModuleId,
+  context: &Arc<CompilationContext>,
+) -> Option<LastBuiltModule> {
+  let module_graph = context.module_graph.read();
+  let module = module_graph.module(module_id)?.clone();
+  let dependencies = module_graph
+    .dependencies(module_id)
+    .into_iter()
+    .map(|(id, edge)| CachedModuleDependency {
+      dependency: id,
+      edge_info: edge.clone(),
+    })
+    .collect();
+
+  Some((module, CachedModule::dep_sources(dependencies)))
 }
 
 /// Similar to [crate::build::resolve_module], but the resolved module may be existed in both context and update_context
@@ -184,7 +184,10 @@ pub fn regenerate_resources_for_affected_module_groups(
 
   for updated_module_id in updated_module_ids {
     let module = module_graph.module(updated_module_id).unwrap();
-    let resource_pot_id = module.resource_pot.as_ref().unwrap();
+    // e.g. assets referenced by html are not bundled into any resource pot
+    let Some(resource_pot_id) = module.resource_pot.as_ref() else {
+      continue;
+    };
 
     if !affected_resource_pots_ids.contains(resource_pot_id) {
       affected_resource_pots_ids.push(resource_pot_id.clone());
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use farmfe_core::config::config_regex::ConfigRegex;
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::{assert_compiler_result, create_compiler_with_args};
+
+#[test]
+fn assets_inline_limit() {
+  fixture!(
+    "tests/fixtures/assets/inline_limit/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      println!("testing assets inline limit: {cwd:?}");
+
+      let entry_name = "index".to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([(entry_name.clone(), "./index.ts".into())]);
+          config.assets.inline_limit = Some(150);
+          config.assets.inline_exclude = vec![ConfigRegex::new("excluded")];
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+    }
+  );
+}
@@ -96,6 +96,17 @@ fn test(file: String, crate_path: String) {
           if let Some(target_env) = get_config_field(&config_from_file, &["output", "targetEnv"]) {
             config.output.target_env = target_env;
           }
+
+          if let Some(global_name) = get_config_field(&config_from_file, &["output", "globalName"])
+          {
+            config.output.global_name = Some(global_name);
+          }
+
+          if let Some(custom) =
+            get_config_field::<HashMap<String, String>>(&config_from_file, &["custom"])
+          {
+            config.custom.extend(custom);
+          }
         }
 
         (config, plugins)
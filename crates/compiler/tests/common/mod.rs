@@ -1,4 +1,8 @@
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{
+  collections::{BTreeMap, HashMap},
+  path::PathBuf,
+  sync::Arc,
+};
 
 use farmfe_compiler::Compiler;
 use farmfe_core::{
@@ -243,6 +247,24 @@ pub fn get_compiler_result(compiler: &Compiler, config: &AssertCompilerResultCon
   result_file_str
 }
 
+/// Content of the resources that are not emitted yet keyed by resource name, for tests that assert a few resources
+/// instead of the whole snapshot
+#[allow(dead_code)]
+pub fn get_compiler_resources(compiler: &Compiler) -> BTreeMap<String, String> {
+  let resources_map = compiler.context().resources_map.lock();
+
+  resources_map
+    .iter()
+    .filter(|(_, resource)| !resource.emitted)
+    .map(|(name, resource)| {
+      (
+        name.clone(),
+        String::from_utf8_lossy(&resource.bytes).to_string(),
+      )
+    })
+    .collect()
+}
+
 #[allow(dead_code)]
 pub fn load_expected_result(cwd: PathBuf, output_file: &String) -> String {
   std::fs::read_to_string(cwd.join(output_file)).unwrap_or("".to_string())
@@ -0,0 +1,173 @@
+use std::{collections::HashMap, io::Read};
+
+use farmfe_core::{
+  config::{bool_or_obj::BoolOrObj, compression::CompressionConfig, Mode},
+  plugin::UpdateType,
+  resource::{Resource, ResourceOrigin, ResourceType},
+};
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::create_compiler_with_args;
+
+#[test]
+fn compression() {
+  fixture!(
+    "tests/fixtures/compression/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      println!("testing compression: {cwd:?}");
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".into())]);
+          config.compression = Box::new(BoolOrObj::Obj(CompressionConfig {
+            threshold: 16,
+            ..Default::default()
+          }));
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let original = &resources_map.get("index.js").unwrap().bytes;
+
+      let mut gzip_decoded = vec![];
+      flate2::read::GzDecoder::new(&resources_map.get("index.js.gz").unwrap().bytes[..])
+        .read_to_end(&mut gzip_decoded)
+        .unwrap();
+      assert_eq!(&gzip_decoded, original);
+
+      let mut brotli_decoded = vec![];
+      brotli::BrotliDecompress(
+        &mut &resources_map.get("index.js.br").unwrap().bytes[..],
+        &mut brotli_decoded,
+      )
+      .unwrap();
+      assert_eq!(&brotli_decoded, original);
+
+      let stats = compiler
+        .context()
+        .record_manager
+        .initial_compilation_flow_stats
+        .read();
+      let resource_stats = &stats.compression_stats.as_ref().unwrap().resources["index.js"];
+      assert_eq!(resource_stats.size, original.len());
+      assert_eq!(
+        resource_stats.gzip_size,
+        Some(resources_map.get("index.js.gz").unwrap().bytes.len())
+      );
+      assert_eq!(
+        resource_stats.brotli_size,
+        Some(resources_map.get("index.js.br").unwrap().bytes.len())
+      );
+    }
+  );
+}
+
+#[test]
+fn compression_update() {
+  fixture!(
+    "tests/fixtures/compression/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".into())]);
+          config.compression = Box::new(BoolOrObj::Obj(CompressionConfig {
+            threshold: 16,
+            ..Default::default()
+          }));
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      {
+        let mut resources_map = compiler.context().resources_map.lock();
+        resources_map.remove("index.js.gz");
+        // compressed variant of a resource that is removed by the update
+        resources_map.insert(
+          "removed.js.gz".to_string(),
+          Resource {
+            name: "removed.js.gz".to_string(),
+            bytes: vec![],
+            emitted: false,
+            resource_type: ResourceType::Asset("gz".to_string()),
+            origin: ResourceOrigin::Resource("removed.js".to_string()),
+            info: None,
+          },
+        );
+      }
+
+      compiler
+        .update(
+          vec![(file.to_string_lossy().to_string(), UpdateType::Updated)],
+          || {},
+          true,
+          false,
+        )
+        .unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let compressed = resources_map.get("index.js.gz").unwrap();
+      assert!(
+        matches!(&compressed.origin, ResourceOrigin::Resource(name) if name == "index.js"),
+        "{:?}",
+        compressed.origin
+      );
+      assert!(!resources_map.contains_key("removed.js.gz"));
+    }
+  );
+}
+
+#[test]
+fn compression_update_development() {
+  fixture!(
+    "tests/fixtures/compression/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".into())]);
+          config.mode = Mode::Development;
+          config.compression = Box::new(BoolOrObj::Obj(CompressionConfig {
+            threshold: 16,
+            ..Default::default()
+          }));
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+      assert!(compiler
+        .context()
+        .resources_map
+        .lock()
+        .contains_key("index.js.gz"));
+      compiler
+        .context()
+        .resources_map
+        .lock()
+        .remove("index.js.gz");
+
+      compiler
+        .update(
+          vec![(file.to_string_lossy().to_string(), UpdateType::Updated)],
+          || {},
+          true,
+          false,
+        )
+        .unwrap();
+
+      // hmr updates are not compressed in development
+      assert!(!compiler
+        .context()
+        .resources_map
+        .lock()
+        .contains_key("index.js.gz"));
+    }
+  );
+}
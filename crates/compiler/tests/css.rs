@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 
-use farmfe_core::config::{CssConfig, CssModulesConfig, CssPrefixerConfig};
+use farmfe_core::config::{
+  config_regex::ConfigRegex, html::HtmlCriticalCssConfig, CssConfig, CssModulesConfig,
+  CssPrefixerConfig, CssPurgeConfig, Mode,
+};
 use farmfe_testing_helpers::fixture;
 mod common;
 
-use crate::common::{assert_compiler_result, create_css_compiler};
+use crate::common::{assert_compiler_result, create_compiler_with_args, create_css_compiler};
 
 #[test]
 fn css_modules() {
@@ -85,3 +88,116 @@ fn css_url_replacer() {
     }
   );
 }
+
+#[test]
+fn css_purge() {
+  fixture!("tests/fixtures/css/purge/**/*.ts", |file, crate_path| {
+    let cwd = file.parent().unwrap();
+
+    let entry_name = "index".to_string();
+
+    let compiler = create_css_compiler(
+      HashMap::from([(entry_name.clone(), "./index.ts".into())]),
+      cwd.to_path_buf(),
+      crate_path,
+      CssConfig {
+        purge: Some(CssPurgeConfig {
+          safelist: vec![ConfigRegex::new("^safe-")],
+          ..Default::default()
+        }),
+        ..Default::default()
+      },
+    );
+
+    compiler.compile().unwrap();
+
+    let stats = compiler
+      .context()
+      .record_manager
+      .initial_compilation_flow_stats
+      .read();
+    let css_purge_stats = stats.css_purge_stats.as_ref().unwrap();
+    assert_eq!(css_purge_stats.removed_rules, 4);
+    assert!(css_purge_stats.removed_bytes > 0);
+    drop(stats);
+
+    assert_compiler_result(&compiler, Some(&entry_name));
+  });
+}
+
+#[test]
+fn css_purge_development() {
+  fixture!("tests/fixtures/css/purge/**/*.ts", |file, crate_path| {
+    let cwd = file.parent().unwrap();
+
+    let compiler =
+      create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+        config.input = HashMap::from([("index".to_string(), "./index.ts".into())]);
+        config.mode = Mode::Development;
+        config.css.purge = Some(CssPurgeConfig::default());
+        (config, plugins)
+      });
+
+    compiler.compile().unwrap();
+
+    let stats = compiler
+      .context()
+      .record_manager
+      .initial_compilation_flow_stats
+      .read();
+    assert!(stats.css_purge_stats.is_none());
+    drop(stats);
+
+    // unused rules must be kept so that selectors added by hmr later still match
+    let resources_map = compiler.context().resources_map.lock();
+    assert!(resources_map
+      .values()
+      .any(|resource| String::from_utf8_lossy(&resource.bytes).contains(".unused")));
+  });
+}
+
+#[test]
+fn css_critical() {
+  fixture!(
+    "tests/fixtures/css/critical/**/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let entry_name = "index".to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([(entry_name.clone(), "./index.html".into())]);
+          config.html.critical_css = Some(HtmlCriticalCssConfig { threshold: Some(0) });
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+    }
+  );
+}
+
+#[test]
+fn css_critical_inline_all() {
+  fixture!(
+    "tests/fixtures/css/critical_inline_all/**/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let entry_name = "index".to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([(entry_name.clone(), "./index.html".into())]);
+          config.html.critical_css = Some(HtmlCriticalCssConfig { threshold: None });
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+    }
+  );
+}
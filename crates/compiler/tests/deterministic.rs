@@ -0,0 +1,117 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  path::{Path, PathBuf},
+};
+
+use farmfe_core::config::{bool_or_obj::BoolOrObj, RuntimeConfig, SourcemapConfig};
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::create_compiler_with_args;
+
+fn copy_dir(from: &Path, to: &Path) {
+  std::fs::create_dir_all(to).unwrap();
+
+  for entry in std::fs::read_dir(from).unwrap() {
+    let entry = entry.unwrap();
+    let target = to.join(entry.file_name());
+
+    if entry.file_type().unwrap().is_dir() {
+      copy_dir(&entry.path(), &target);
+    } else {
+      std::fs::copy(entry.path(), target).unwrap();
+    }
+  }
+}
+
+/// Build the project under `root` and return the emitted resources, the runtime is copied into the root
+/// so that nothing outside the root is involved.
+fn build(root: &Path, crate_path: PathBuf) -> BTreeMap<String, Vec<u8>> {
+  let internal = root.join("node_modules").join("_internal");
+  copy_dir(
+    &crate_path.join("tests").join("fixtures").join("_internal"),
+    &internal,
+  );
+
+  let compiler =
+    create_compiler_with_args(root.to_path_buf(), crate_path, |mut config, plugins| {
+      let entry = if root.join("index.html").exists() {
+        "./index.html"
+      } else {
+        "./index.ts"
+      };
+      config.input = HashMap::from([("index".to_string(), entry.into())]);
+      config.runtime = Box::new(RuntimeConfig {
+        path: internal
+          .join("runtime")
+          .join("index.js")
+          .to_string_lossy()
+          .to_string(),
+        swc_helpers_path: internal.join("swc_helpers").to_string_lossy().to_string(),
+        ..Default::default()
+      });
+      config.output.filename = "[resourceName].[contentHash].[ext]".to_string();
+      config.output.assets_filename = "[resourceName].[contentHash].[ext]".to_string();
+      config.sourcemap = Box::new(SourcemapConfig::Bool(true));
+      config.minify = Box::new(BoolOrObj::Bool(true));
+      config.output.manifest = BoolOrObj::Bool(true);
+      (config, plugins)
+    });
+
+  compiler.compile().unwrap();
+
+  let resources_map = compiler.context().resources_map.lock();
+
+  resources_map
+    .iter()
+    .filter(|(_, resource)| !resource.emitted)
+    .map(|(name, resource)| (name.clone(), resource.bytes.clone()))
+    .collect()
+}
+
+/// The same sources built from different directories should generate byte-for-byte identical outputs,
+/// otherwise the long-term caching of the emitted files breaks across machines.
+#[test]
+fn deterministic_output_across_roots() {
+  fixture!(
+    "tests/fixtures/deterministic/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      println!("testing deterministic output: {cwd:?}");
+
+      let tmp = std::env::temp_dir().join(format!(
+        "farm-deterministic-{}-{}",
+        std::process::id(),
+        cwd.file_name().unwrap().to_string_lossy()
+      ));
+      let roots = [
+        tmp.join("a").join("project"),
+        tmp.join("b").join("nested").join("checkout"),
+      ];
+
+      let outputs = roots
+        .iter()
+        .map(|root| {
+          copy_dir(cwd, root);
+          build(root, crate_path.clone())
+        })
+        .collect::<Vec<_>>();
+
+      std::fs::remove_dir_all(&tmp).unwrap();
+
+      assert_eq!(
+        outputs[0].keys().collect::<Vec<_>>(),
+        outputs[1].keys().collect::<Vec<_>>()
+      );
+
+      for (name, bytes) in &outputs[0] {
+        assert!(
+          bytes == &outputs[1][name],
+          "resource {name} is different when built from different roots:\n{}\n----\n{}",
+          String::from_utf8_lossy(bytes),
+          String::from_utf8_lossy(&outputs[1][name])
+        );
+      }
+    }
+  );
+}
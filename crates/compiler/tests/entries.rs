@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use farmfe_core::module::ModuleId;
+use farmfe_testing_helpers::fixture;
+
+use crate::common::create_compiler_with_args;
+
+mod common;
+
+#[test]
+fn entry_imported_by_another_entry() {
+  fixture!(
+    "tests/fixtures/entries/imported_entry/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      // a.ts may be built as a dependency of index.ts before it's resolved as an entry, build several times to
+      // cover both orders
+      for _ in 0..10 {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([
+              ("index".to_string(), "./index.ts".to_string()),
+              ("a".to_string(), "./a.ts".to_string()),
+            ]);
+            (config, plugins)
+          },
+        );
+        compiler.compile().unwrap();
+
+        let context = compiler.context();
+        let module_graph = context.module_graph.read();
+        assert_eq!(
+          module_graph.entries.get(&ModuleId::from("a.ts")),
+          Some(&"a".to_string())
+        );
+        assert_eq!(
+          module_graph.entries.get(&ModuleId::from("index.ts")),
+          Some(&"index".to_string())
+        );
+        assert!(context.resources_map.lock().contains_key("a.js"));
+      }
+    }
+  );
+}
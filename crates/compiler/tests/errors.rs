@@ -0,0 +1,87 @@
+mod common;
+use crate::common::create_compiler_with_args;
+use std::{collections::HashMap, path::PathBuf};
+
+use farmfe_core::{
+  error::{CompilationError, SerializedCompilationError},
+  serde_json,
+};
+use farmfe_testing_helpers::fixture;
+
+#[test]
+fn continue_on_error_collects_all_errors() {
+  fixture!(
+    "tests/fixtures/errors/multiple/index.ts",
+    |file: PathBuf, crate_path: PathBuf| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let compile = || {
+        let compiler =
+          create_compiler_with_args(cwd.clone(), crate_path.clone(), |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.continue_on_error = true;
+            (config, plugins)
+          });
+
+        compiler.compile().unwrap_err()
+      };
+
+      let CompilationError::Multiple(errors) = compile() else {
+        panic!("the errors should be aggregated");
+      };
+      let module_paths = errors
+        .iter()
+        .map(|e| e.module_path().map(|p| p.to_string()))
+        .collect::<Vec<_>>();
+      assert_eq!(
+        module_paths,
+        vec![
+          Some("a.ts".to_string()),
+          Some("b.ts".to_string()),
+          Some("index.ts".to_string()),
+        ]
+      );
+      assert!(matches!(errors[0], CompilationError::ParseError { .. }));
+      assert!(errors[1].to_string().contains("./missing-b"));
+      assert!(errors[2].to_string().contains("./missing-c"));
+
+      // the order does not depend on the order in which the modules are built
+      assert_eq!(
+        compile().to_string(),
+        CompilationError::Multiple(errors).to_string()
+      );
+    }
+  );
+}
+
+#[test]
+fn parse_error_location() {
+  fixture!(
+    "tests/fixtures/errors/multiple/a.ts",
+    |file: PathBuf, crate_path: PathBuf| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let compiler = create_compiler_with_args(cwd.clone(), crate_path, |mut config, plugins| {
+        config.input = HashMap::from([("index".to_string(), "./a.ts".to_string())]);
+        (config, plugins)
+      });
+
+      let error = compiler.compile().unwrap_err();
+      let messages: Vec<String> = serde_json::from_str(&error.to_string()).unwrap();
+      assert_eq!(messages.len(), 1);
+      let error: SerializedCompilationError = serde_json::from_str(&messages[0]).unwrap();
+      let loc = error.loc.unwrap();
+
+      assert_eq!(error.id, Some("a.ts".to_string()));
+      assert_eq!(loc.file, file.to_string_lossy().to_string());
+      assert_eq!((loc.line, loc.column), (1, 19));
+      assert_eq!(loc.code, Some("TS1109".to_string()));
+      assert_eq!(error.frame, Some(loc.frame.clone()));
+      assert!(loc.frame.starts_with("> 1 | export const a = (;"));
+
+      // the errors are recorded in the stats
+      let stats: serde_json::Value =
+        serde_json::from_str(&compiler.context().record_manager.to_string()).unwrap();
+      let errors = &stats["initialCompilationFlowStats"]["errors"];
+      assert_eq!(errors[0]["loc"]["line"], 1);
+    }
+  );
+}
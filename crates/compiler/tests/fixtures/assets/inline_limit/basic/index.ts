@@ -0,0 +1,5 @@
+import small from './small.svg';
+import big from './big.png';
+import './index.css';
+
+console.log(small, big);
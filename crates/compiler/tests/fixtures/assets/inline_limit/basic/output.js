@@ -0,0 +1,104 @@
+//big-85e42d.png:
+ �PNG00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000
+
+//excluded-b331e2.png:
+ small png
+
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+index_js_cjs();
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_3b70.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"6e65e3b5":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    exports.default = "/excluded-b331e2.png";
+}
+,
+"6ec01e34":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    exports.default = "data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'%20viewBox='0%200%2010%2010'%3E%20%3Crect%20width='10'%20height='10'%20fill='%23f00'/%3E%20%3C/svg%3E";
+}
+,
+"858cad0c":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    exports.default = "/big-85e42d.png";
+}
+,
+"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_small = module.i(farmRequire("6ec01e34"));
+    var _f_big = module.i(farmRequire("858cad0c"));
+    "";
+    console.log(module.f(_f_small), module.f(_f_big));
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
+
+//index_337c.css:
+ .small {
+  background: url("data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'%20viewBox='0%200%2010%2010'%3E%20%3Crect%20width='10'%20height='10'%20fill='%23f00'/%3E%20%3C/svg%3E");
+}
+.excluded {
+  background: url("/excluded-b331e2.png");
+}
\ No newline at end of file
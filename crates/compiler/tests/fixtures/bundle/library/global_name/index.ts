@@ -0,0 +1,8 @@
+import $ from 'jquery';
+import { format } from './format';
+
+export function render(selector: string, name: string) {
+  return $(selector).text(format(name));
+}
+
+export default render;
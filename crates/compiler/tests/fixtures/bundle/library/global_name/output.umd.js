@@ -0,0 +1,67 @@
+//index.js:
+ (function (root, factory) {
+if (typeof exports === "object" && typeof module !== "undefined") module.exports = factory(require);
+else if (typeof define === "function" && define.amd) define(["require","jquery"], factory);
+else { root = typeof globalThis !== "undefined" ? globalThis : root || self; root["MyLib"] = factory(function (id) { switch (id) { case "jquery": return root["$"]; } throw new Error("Cannot find external module " + id); }); }
+})(this, (function (require) {
+var module = { exports: {} }, exports = module.exports;
+function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}var jquery_ns = require("jquery");
+var $ = _interop_require_default(jquery_ns).default;
+function format(name) {
+    return `Hello, ${name}`;
+}
+
+function render(selector, name) {
+    return $(selector).text(format(name));
+}
+module.exports.render = render;
+module.exports.default = render;
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+return module.exports;
+}));
\ No newline at end of file
@@ -0,0 +1,147 @@
+//index.html:
+ <!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="UTF-8">
+  <title>Critical</title>
+<script>
+window['__farm_default_namespace__'] = {};
+window['__farm_default_namespace__'] = {
+  __FARM_TARGET_ENV__: 'browser',
+};</script><script>function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+index_js_cjs();
+})());</script><style>body {
+  margin: 0;
+}
+#app.header {
+  display: -webkit-box;
+  display: -webkit-flex;
+  display: -moz-box;
+  display: -ms-flexbox;
+  display: flex;
+}
+.title {
+  font-size: 24px;
+}
+@media (max-width: 600px) {
+  .title {
+    font-size: 18px;
+  }
+  .modal {
+    width: 100%;
+  }
+}
+@font-face{
+  font-family: foo;
+}</style><link rel="stylesheet" href="/index_337c.css" media="print" onload="this.media='all'"><noscript><link rel="stylesheet" href="/index_337c.css"></noscript></head>
+<body>
+  <div id="app" class="header">
+    <h1 class="title">Hello</h1>
+  </div>
+  
+
+
+<script src="/index_dcdc.js" data-farm-resource="true"></script><script>window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['index_337c.css','index_dcdc.js']);</script><script>window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });</script><script>window['__farm_default_namespace__'].__farm_module_system__.setPublicPaths(['/']);</script><script>window['__farm_default_namespace__'].__farm_module_system__.bootstrap();</script><script>window['__farm_default_namespace__'].__farm_module_system__.require("b5d64806")</script></body></html>
+
+//index_337c.css:
+ body {
+  margin: 0;
+}
+#app.header {
+  display: -webkit-box;
+  display: -webkit-flex;
+  display: -moz-box;
+  display: -ms-flexbox;
+  display: flex;
+}
+.title {
+  font-size: 24px;
+}
+.modal {
+  position: fixed;
+}
+@media (max-width: 600px) {
+  .title {
+    font-size: 18px;
+  }
+  .modal {
+    width: 100%;
+  }
+}
+@font-face{
+  font-family: foo;
+}
+
+//index_dcdc.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    "";
+    const modal = document.createElement('div');
+    modal.className = 'modal';
+    document.body.appendChild(modal);
+}
+,});
\ No newline at end of file
@@ -0,0 +1,5 @@
+import './index.css';
+
+const modal = document.createElement('div');
+modal.className = 'modal';
+document.body.appendChild(modal);
@@ -0,0 +1,6 @@
+import './index.css';
+
+const root = document.createElement('div');
+root.className = `container ${Math.random() > 0.5 ? 'active' : ''}`;
+root.id = 'app';
+document.body.appendChild(root);
@@ -0,0 +1,8 @@
+import logo from './logo.png';
+import styles from './index.module.css';
+import { format } from './utils';
+import './index.css';
+
+console.log(format(logo), styles.container);
+
+import('./lazy').then(({ lazy }) => lazy());
@@ -0,0 +1,5 @@
+import { format } from './utils';
+
+export function lazy() {
+  console.log(format('lazy'));
+}
@@ -0,0 +1,3 @@
+export function format(url: string) {
+  return `url(${url})`;
+}
@@ -0,0 +1,12 @@
+import { createStore } from './src/shared/store';
+import config from './src/config.json';
+import './src/index.css';
+
+const store = createStore(config);
+const pages = {
+  home: () => import('./src/pages/home'),
+  about: () => import('./src/pages/about'),
+  settings: () => import('./src/pages/settings')
+};
+
+pages[store.get('page') as keyof typeof pages]().then((page) => page.render(store));
@@ -0,0 +1,7 @@
+import { view } from '../shared/view';
+import styles from './about.module.css';
+
+export function render(store) {
+  document.body.innerHTML = view('about', store);
+  document.body.className = styles.about;
+}
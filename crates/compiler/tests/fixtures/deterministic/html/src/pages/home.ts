@@ -0,0 +1,7 @@
+import { view } from '../shared/view';
+import styles from './home.module.css';
+
+export function render(store) {
+  document.body.innerHTML = view('home', store);
+  document.body.className = styles.home;
+}
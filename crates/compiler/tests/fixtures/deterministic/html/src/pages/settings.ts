@@ -0,0 +1,7 @@
+import { view } from '../shared/view';
+import styles from './settings.module.css';
+
+export function render(store) {
+  document.body.innerHTML = view('settings', store);
+  document.body.className = styles.settings;
+}
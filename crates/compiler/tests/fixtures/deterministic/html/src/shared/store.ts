@@ -0,0 +1,10 @@
+export function createStore(initial: Record<string, unknown>) {
+  const state = new Map(Object.entries(initial));
+
+  return {
+    get: (key: string) => state.get(key),
+    set: (key: string, value: unknown) => state.set(key, value)
+  };
+}
+
+export type Store = ReturnType<typeof createStore>;
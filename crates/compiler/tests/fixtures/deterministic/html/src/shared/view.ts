@@ -0,0 +1,5 @@
+import type { Store } from './store';
+
+export function view(name: string, store: Store) {
+  return `<h1>${name}: ${store.get('title')}</h1>`;
+}
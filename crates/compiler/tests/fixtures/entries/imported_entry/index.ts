@@ -0,0 +1 @@
+import('./a').then(({ a }) => console.log(a()));
@@ -0,0 +1,3 @@
+import { missing } from './missing-b';
+
+export const b = missing;
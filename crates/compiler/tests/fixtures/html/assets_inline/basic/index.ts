@@ -0,0 +1 @@
+console.log('assets inline');
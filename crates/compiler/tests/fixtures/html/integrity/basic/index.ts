@@ -0,0 +1,3 @@
+import './index.css';
+
+import('./lazy').then(({ lazy }) => lazy());
@@ -0,0 +1,108 @@
+//index.html:
+ <!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="UTF-8">
+  <title>Integrity</title>
+<script>
+window['__farm_default_namespace__'] = {};
+window['__farm_default_namespace__'] = {
+  __FARM_TARGET_ENV__: 'browser',
+};</script><script>function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+index_js_cjs();
+})());</script><link rel="stylesheet" href="/index_337c.css" integrity="sha384-nBH/g6/ZHvUWyIQvCNj4zhHjID6T0hYVGdI4FfyGSXm3qihs/+3X2csekirwuRUa" crossorigin="anonymous"></head>
+<body>
+  <div id="app"></div>
+  
+
+
+<script src="/index_dcdc.js" data-farm-resource="true" integrity="sha384-hUThEsiynMHTsmMLPVKym7lxunjD4Gqcln9mmMkMPeajghwgt966wgau2ElDwt/a" crossorigin="anonymous"></script><script>window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['index_337c.css','index_dcdc.js']);</script><script>window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bd40.js', type: 0, integrity: 'sha384-PdhaVlawUGgoYBilSE/e8CHx929cBjMR/AqN2YmW7Tjh0TUuYSgciTxImId87TF2' }],{ '8bb3fc6d': [0] });</script><script>window['__farm_default_namespace__'].__farm_module_system__.setPublicPaths(['/']);</script><script>window['__farm_default_namespace__'].__farm_module_system__.bootstrap();</script><script>window['__farm_default_namespace__'].__farm_module_system__.require("b5d64806")</script></body></html>
+
+//index_337c.css:
+ #app {
+  color: red;
+}
+
+//index_dcdc.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    "";
+    farmDynamicRequire("8bb3fc6d").then(({ lazy })=>lazy());
+}
+,});
+
+//lazy_bd40.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bd40.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"8bb3fc6d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "lazy", function() {
+        return lazy;
+    });
+    function lazy() {
+        console.log('lazy');
+    }
+}
+,});
\ No newline at end of file
@@ -0,0 +1,4 @@
+import './index.css';
+
+import('./lazy').then(({ lazy }) => lazy());
+import(/* farmPrefetch: false */ './no-prefetch').then(({ noPrefetch }) => noPrefetch());
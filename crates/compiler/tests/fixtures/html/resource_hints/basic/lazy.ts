@@ -0,0 +1 @@
+export function lazy() { console.log('lazy'); }
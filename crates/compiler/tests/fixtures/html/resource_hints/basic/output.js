@@ -0,0 +1,133 @@
+//font-c516a8.woff2:
+ wOF2fakefont
+
+//index.html:
+ <!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="UTF-8">
+  <title>Resource Hints</title>
+<script>
+window['__farm_default_namespace__'] = {};
+window['__farm_default_namespace__'] = {
+  __FARM_TARGET_ENV__: 'browser',
+};</script><script>function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+index_js_cjs();
+})());</script><link rel="stylesheet" href="/index_337c.css"><link rel="preload" href="/index_c83f.js" as="script"><link rel="preload" href="/font-c516a8.woff2" as="font" crossorigin><link rel="prefetch" href="/lazy_bd40.js"></head>
+<body>
+  <div id="app"></div>
+  
+
+
+<script src="/index_c83f.js" data-farm-resource="true"></script><script>window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['index_337c.css','index_c83f.js']);</script><script>window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bd40.js', type: 0 },{ path: 'no-prefetch_ac68.js', type: 0 }],{ '8bb3fc6d': [0],'415db88d': [1] });</script><script>window['__farm_default_namespace__'].__farm_module_system__.setPublicPaths(['/']);</script><script>window['__farm_default_namespace__'].__farm_module_system__.bootstrap();</script><script>window['__farm_default_namespace__'].__farm_module_system__.require("b5d64806")</script></body></html>
+
+//index_337c.css:
+ @font-face{
+  font-family: foo;
+  src: url("/font-c516a8.woff2");
+}
+#app {
+  font-family: foo;
+}
+
+//index_c83f.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='index_c83f.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"622e0db0":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    exports.default = "/font-c516a8.woff2";
+}
+,
+"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    "";
+    farmDynamicRequire("8bb3fc6d").then(({ lazy })=>lazy());
+    farmDynamicRequire("415db88d").then(({ noPrefetch })=>noPrefetch());
+}
+,});
+
+//lazy_bd40.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bd40.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"8bb3fc6d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "lazy", function() {
+        return lazy;
+    });
+    function lazy() {
+        console.log('lazy');
+    }
+}
+,});
+
+//no-prefetch_ac68.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='no-prefetch_ac68.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"415db88d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "noPrefetch", function() {
+        return noPrefetch;
+    });
+    function noPrefetch() {
+        console.log('no-prefetch');
+    }
+}
+,});
\ No newline at end of file
@@ -0,0 +1 @@
+export function admin() { console.log('admin'); }
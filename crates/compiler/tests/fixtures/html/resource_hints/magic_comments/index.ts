@@ -0,0 +1,3 @@
+import(/* webpackChunkName: "admin", webpackPreload: true */ './admin').then(({ admin }) => admin());
+import(/* webpackChunkName: "admin" */ './settings').then(({ settings }) => settings());
+import(/* webpackPrefetch: false */ './no-prefetch').then(({ noPrefetch }) => noPrefetch());
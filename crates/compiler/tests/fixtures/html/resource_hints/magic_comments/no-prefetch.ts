@@ -0,0 +1 @@
+export function noPrefetch() { console.log('no prefetch'); }
@@ -0,0 +1,11 @@
+import { greet } from './shared';
+
+console.log(greet('index'));
+
+export function loadA() {
+  return import('./a').then((m) => m.a());
+}
+
+export function loadB() {
+  return import('./b').then((m) => m.b());
+}
@@ -0,0 +1,5 @@
+import { greet } from './shared';
+
+export function a() {
+  return greet('a');
+}
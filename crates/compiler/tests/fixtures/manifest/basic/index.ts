@@ -0,0 +1,6 @@
+import logo from './logo.png';
+import './index.css';
+
+console.log(logo);
+
+import('./lazy').then((m) => m.default());
@@ -0,0 +1,132 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+index_js_cjs();
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_6bf0.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    var _f_logo = module.i(farmRequire("efdf26ee"));
+    "";
+    console.log(module.f(_f_logo));
+    farmDynamicRequire("8bb3fc6d").then((m)=>m.default());
+}
+,
+"efdf26ee":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    exports.default = "/logo.2323e0e7-ab2112.png";
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([{ path: 'lazy_bd40.7a414d62.js', type: 0 }],{ '8bb3fc6d': [0] });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
+
+//index_337c.41b3a521.css:
+ .app {
+  color: red;
+}
+
+//lazy_bd40.7a414d62.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='lazy_bd40.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"8bb3fc6d":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "default", function() {
+        return lazy;
+    });
+    function lazy() {
+        console.log('lazy');
+    }
+}
+,});
+
+//logo.2323e0e7-ab2112.png:
+ �PNG logo
+
+//manifest.json:
+ {
+  "index.css": {
+    "file": "index_337c.41b3a521.css",
+    "src": "index.css"
+  },
+  "index.ts": {
+    "file": "index.js",
+    "src": "index.ts",
+    "name": "index",
+    "isEntry": true,
+    "dynamicImports": [
+      "lazy.ts"
+    ],
+    "css": [
+      "index_337c.41b3a521.css"
+    ],
+    "assets": [
+      "logo.2323e0e7-ab2112.png"
+    ]
+  },
+  "lazy.ts": {
+    "file": "lazy_bd40.7a414d62.js",
+    "src": "lazy.ts",
+    "isDynamicEntry": true
+  },
+  "logo.png": {
+    "file": "logo.2323e0e7-ab2112.png",
+    "src": "logo.png"
+  }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+import logo from './assets/logo.png';
+// outside of the root, must not collide with `./assets/logo.png`
+import externalLogo from '../assets/logo.png';
+
+console.log(logo, externalLogo);
+
+import('./lazy').then(({ lazy }) => lazy());
@@ -0,0 +1,3 @@
+export function lazy() {
+  console.log('lazy');
+}
@@ -0,0 +1,3 @@
+export function format(message: string) {
+  return `[lib] ${message}`;
+}
@@ -0,0 +1,7 @@
+import { format } from './format';
+
+export const url = import.meta.url;
+
+export function greet(name: string) {
+  return format(`hello ${name}`);
+}
@@ -0,0 +1,6 @@
+import { vendor } from './vendor';
+
+import('./pages/admin').then(({ admin }) => admin());
+import(/* webpackChunkName: "admin" */ './pages/settings').then(({ settings }) => settings());
+
+console.log(vendor);
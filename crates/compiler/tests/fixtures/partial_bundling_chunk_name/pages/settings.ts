@@ -0,0 +1 @@
+export function settings() { console.log('settings'); }
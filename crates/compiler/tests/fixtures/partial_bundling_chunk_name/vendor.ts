@@ -0,0 +1 @@
+export const vendor = 'vendor';
@@ -0,0 +1,4 @@
+import { add } from './utils';
+import { vendor } from './vendor';
+
+console.log(add(1, 2), vendor);
@@ -0,0 +1,66 @@
+/**
+ * This comment line 0 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 1 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 2 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 3 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 4 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 5 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 6 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 7 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 8 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 9 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 10 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 11 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 12 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 13 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 14 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 15 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 16 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 17 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 18 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 19 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 20 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 21 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 22 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 23 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 24 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 25 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 26 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 27 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 28 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 29 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 30 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 31 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 32 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 33 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 34 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 35 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 36 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 37 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 38 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 39 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 40 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 41 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 42 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 43 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 44 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 45 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 46 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 47 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 48 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 49 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 50 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 51 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 52 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 53 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 54 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 55 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 56 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 57 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 58 is removed by minify, so it should not be counted by the estimated size.
+ * This comment line 59 is removed by minify, so it should not be counted by the estimated size.
+ */
+export function describe(name: string): string {
+  // a long comment that is removed by minify as well
+  return `${name} is a bundler`;
+}
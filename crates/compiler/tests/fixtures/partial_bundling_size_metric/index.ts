@@ -0,0 +1,3 @@
+import { describe } from './commented';
+
+console.log(describe('farm'));
@@ -0,0 +1,11 @@
+import { format } from '../utils/format';
+
+export class Button {
+  constructor(public label: string) {}
+
+  render() {
+    return format(this.label);
+  }
+}
+
+export default Button;
@@ -0,0 +1,6 @@
+export { Button } from './components/button';
+export * from './utils/format';
+export { VERSION } from '../shared/version';
+// has the same path relative to `src` as `../shared/version` relative to the project root
+export { SRC_VERSION } from './shared/version';
+export const loadLazy = () => import('./lazy');
@@ -0,0 +1 @@
+export const SRC_VERSION = 'src';
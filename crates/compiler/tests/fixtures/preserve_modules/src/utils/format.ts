@@ -0,0 +1,3 @@
+export function format(label: string) {
+  return label.toUpperCase();
+}
@@ -0,0 +1,3 @@
+const locales = require.context('./locales', false, /\.ts$/);
+
+export const messages = locales.keys().map((key) => locales(key).default);
@@ -0,0 +1,112 @@
+//index.js:
+ window['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};function _interop_require_default(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}function _export_star(from, to) {
+    Object.keys(from).forEach(function(k) {
+        if (k !== "default" && !Object.prototype.hasOwnProperty.call(to, k)) {
+            Object.defineProperty(to, k, {
+                enumerable: true,
+                get: function() {
+                    return from[k];
+                }
+            });
+        }
+    });
+    return from;
+}function _interop_require_wildcard(obj, nodeInterop) {
+    if (!nodeInterop && obj && obj.__esModule) return obj;
+    if (obj === null || typeof obj !== "object" && typeof obj !== "function") return {
+        default: obj
+    };
+    var cache = _getRequireWildcardCache(nodeInterop);
+    if (cache && cache.has(obj)) return cache.get(obj);
+    var newObj = {
+        __proto__: null
+    };
+    var hasPropertyDescriptor = Object.defineProperty && Object.getOwnPropertyDescriptor;
+    for(var key in obj){
+        if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+            var desc = hasPropertyDescriptor ? Object.getOwnPropertyDescriptor(obj, key) : null;
+            if (desc && (desc.get || desc.set)) Object.defineProperty(newObj, key, desc);
+            else newObj[key] = obj[key];
+        }
+    }
+    newObj.default = obj;
+    if (cache) cache.set(obj, newObj);
+    return newObj;
+}function _getRequireWildcardCache(nodeInterop) {
+    if (typeof WeakMap !== "function") return null;
+    var cacheBabelInterop = new WeakMap();
+    var cacheNodeInterop = new WeakMap();
+    return (_getRequireWildcardCache = function(nodeInterop) {
+        return nodeInterop ? cacheNodeInterop : cacheBabelInterop;
+    })(nodeInterop);
+}function __commonJs(mod) {
+  var module;
+  return () => {
+    if (module) {
+      return module.exports;
+    }
+    module = {
+      exports: {},
+    };
+    if(typeof mod === "function") {
+      mod(module, module.exports);
+    }else {
+      mod[Object.keys(mod)[0]](module, module.exports);
+    }
+    return module.exports;
+  };
+}((function(){var index_js_cjs = __commonJs((module, exports)=>{
+    "use strict";
+    console.log('runtime/index.js');
+    window['__farm_default_namespace__'].__farm_module_system__.setPlugins([]);
+});
+index_js_cjs();
+})());(function(_){for(var r in _){_[r].__farm_resource_pot__='index_18fe.js';window['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    module.o(exports, "messages", function() {
+        return messages;
+    });
+    const locales = function() {
+        var map = {
+            "./en.ts": function() {
+                return farmRequire("c44a1d70", true);
+            },
+            "./fr.ts": function() {
+                return farmRequire("c158a21f", true);
+            }
+        };
+        function webpackContextResolve(req) {
+            if (!Object.prototype.hasOwnProperty.call(map, req)) {
+                var e = new Error("Cannot find module '" + req + "'");
+                e.code = "MODULE_NOT_FOUND";
+                throw e;
+            }
+            return req;
+        }
+        function webpackContext(req) {
+            return map[webpackContextResolve(req)]();
+        }
+        webpackContext.keys = function() {
+            return Object.keys(map);
+        };
+        webpackContext.resolve = webpackContextResolve;
+        webpackContext.id = "./locales";
+        return webpackContext;
+    }();
+    var messages = locales.keys().map((key)=>locales(key).default);
+}
+,
+"c158a21f":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    exports.default = 'bonjour';
+}
+,
+"c44a1d70":function  (module, exports, farmRequire, farmDynamicRequire) {
+    module._m(exports);
+    exports.default = 'hello';
+}
+,});window['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);window['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap([],{  });var farmModuleSystem = window['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var messages=entry.messages;export { messages };
\ No newline at end of file
@@ -0,0 +1,3 @@
+import { render } from './app';
+
+render();
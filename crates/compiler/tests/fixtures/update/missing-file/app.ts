@@ -0,0 +1,3 @@
+export function render() {
+  console.log('app');
+}
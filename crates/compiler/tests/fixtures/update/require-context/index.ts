@@ -0,0 +1,3 @@
+const locales = require.context('./locales', false, /\.ts$/);
+
+console.log(locales.keys().map((key) => locales(key)));
@@ -0,0 +1,281 @@
+use std::{collections::HashMap, sync::Arc};
+
+use farmfe_core::{
+  config::html::{HtmlResourceHintsConfig, IntegrityAlgorithm},
+  context::CompilationContext,
+  error::Result,
+  plugin::{Plugin, PluginFinalizeResourcesHookParams},
+  resource::ResourceType,
+};
+use farmfe_testing_helpers::fixture;
+use farmfe_toolkit::hash::integrity;
+mod common;
+
+use crate::common::{assert_compiler_result, create_compiler_with_args};
+
+#[test]
+fn html_resource_hints() {
+  fixture!(
+    "tests/fixtures/html/resource_hints/**/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      println!("testing resource hints: {cwd:?}");
+
+      let entry_name = "index".to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([(entry_name.clone(), "./index.html".into())]);
+          config.html.resource_hints = Some(HtmlResourceHintsConfig::default());
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+    }
+  );
+}
+
+#[test]
+fn html_resource_hints_module_preload() {
+  fixture!(
+    "tests/fixtures/html/resource_hints/basic/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.html".into())]);
+          config.html.resource_hints = Some(HtmlResourceHintsConfig {
+            module_preload: true,
+            ..Default::default()
+          });
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let html = String::from_utf8_lossy(&resources_map["index.html"].bytes).to_string();
+      let initial_script = resources_map
+        .values()
+        .find(|r| {
+          matches!(r.resource_type, ResourceType::Js)
+            && html.contains(&format!("<script src=\"/{}\"", r.name))
+        })
+        .unwrap();
+      let initial_css = resources_map
+        .values()
+        .find(|r| matches!(r.resource_type, ResourceType::Css))
+        .unwrap();
+
+      // initial scripts are injected at the end of the body, so they are still preloaded
+      assert!(html.contains(&format!(
+        "<link rel=\"modulepreload\" href=\"/{}\">",
+        initial_script.name
+      )));
+      // the stylesheet is already loaded by a blocking <link>
+      assert!(!html.contains(&format!(
+        "<link rel=\"preload\" href=\"/{}\"",
+        initial_css.name
+      )));
+    }
+  );
+}
+
+#[test]
+fn html_integrity() {
+  fixture!(
+    "tests/fixtures/html/integrity/**/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      println!("testing integrity: {cwd:?}");
+
+      let entry_name = "index".to_string();
+
+      let compiler = create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+        config.input = HashMap::from([(entry_name.clone(), "./index.html".into())]);
+        config.html.integrity = Some(IntegrityAlgorithm::Sha384);
+        (config, plugins)
+      });
+
+      compiler.compile().unwrap();
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+    }
+  );
+}
+
+/// Changes the scripts after the html is transformed, like a user plugin that adds a banner
+struct BannerPlugin;
+
+impl Plugin for BannerPlugin {
+  fn name(&self) -> &str {
+    "BannerPlugin"
+  }
+
+  fn finalize_resources(
+    &self,
+    params: &mut PluginFinalizeResourcesHookParams,
+    _context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    for resource in params.resources_map.values_mut() {
+      if matches!(resource.resource_type, ResourceType::Js) {
+        resource
+          .bytes
+          .splice(0..0, b"/* banner */\n".iter().cloned());
+      }
+    }
+
+    Ok(Some(()))
+  }
+}
+
+#[test]
+fn html_integrity_of_finalized_resources() {
+  fixture!(
+    "tests/fixtures/html/integrity/**/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.html".into())]);
+          config.html.integrity = Some(IntegrityAlgorithm::Sha384);
+          plugins.push(Arc::new(BannerPlugin) as _);
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let html = String::from_utf8_lossy(&resources_map["index.html"].bytes).to_string();
+      let scripts = resources_map
+        .values()
+        .filter(|r| matches!(r.resource_type, ResourceType::Js))
+        .collect::<Vec<_>>();
+      assert!(!scripts.is_empty());
+
+      for script in scripts {
+        assert!(script.bytes.starts_with(b"/* banner */"));
+        assert!(
+          html.contains(&integrity(&script.bytes, IntegrityAlgorithm::Sha384)),
+          "integrity of {} is not the integrity of the finalized bytes",
+          script.name
+        );
+      }
+    }
+  );
+}
+
+/// Removes the css resources after the html is transformed
+struct RemoveCssPlugin;
+
+impl Plugin for RemoveCssPlugin {
+  fn name(&self) -> &str {
+    "RemoveCssPlugin"
+  }
+
+  fn finalize_resources(
+    &self,
+    params: &mut PluginFinalizeResourcesHookParams,
+    _context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    params
+      .resources_map
+      .retain(|_, resource| !matches!(resource.resource_type, ResourceType::Css));
+
+    Ok(Some(()))
+  }
+}
+
+#[test]
+fn html_integrity_of_missing_resource() {
+  fixture!(
+    "tests/fixtures/html/integrity/**/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.html".into())]);
+          config.html.integrity = Some(IntegrityAlgorithm::Sha384);
+          plugins.push(Arc::new(RemoveCssPlugin) as _);
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err().to_string();
+      assert!(
+        err.contains("integrity of resource index")
+          && err.contains(".css referenced by index.html"),
+        "{err}"
+      );
+    }
+  );
+}
+
+#[test]
+fn html_csp() {
+  fixture!("tests/fixtures/html/csp/**/index.html", |file, crate_path| {
+    let cwd = file.parent().unwrap();
+    println!("testing csp: {cwd:?}");
+
+    let entry_name = "index".to_string();
+
+    let compiler = create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+      config.input = HashMap::from([(entry_name.clone(), "./index.html".into())]);
+      config.html.csp_nonce = Some("{{nonce}}".to_string());
+      config.html.csp_hashes = Some(IntegrityAlgorithm::Sha256);
+      (config, plugins)
+    });
+
+    compiler.compile().unwrap();
+
+    assert_compiler_result(&compiler, Some(&entry_name));
+  });
+}
+
+#[test]
+fn html_assets() {
+  fixture!("tests/fixtures/html/assets/**/index.html", |file, crate_path| {
+    let cwd = file.parent().unwrap();
+    println!("testing html assets: {cwd:?}");
+
+    let entry_name = "index".to_string();
+
+    let compiler = create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+      config.input = HashMap::from([(entry_name.clone(), "./index.html".into())]);
+      config.output.assets_filename = "assets/[resourceName].[hash].[ext]".to_string();
+      (config, plugins)
+    });
+
+    compiler.compile().unwrap();
+
+    assert_compiler_result(&compiler, Some(&entry_name));
+  });
+}
+
+#[test]
+fn html_assets_inline() {
+  fixture!(
+    "tests/fixtures/html/assets_inline/**/index.html",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      println!("testing html inlined assets: {cwd:?}");
+
+      let entry_name = "index".to_string();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([(entry_name.clone(), "./index.html".into())]);
+          config.assets.inline_limit = Some(4096);
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+    }
+  );
+}
@@ -0,0 +1,194 @@
+use std::{
+  collections::{BTreeMap, BTreeSet, HashMap},
+  path::{Path, PathBuf},
+};
+
+use farmfe_core::config::{
+  bool_or_obj::BoolOrObj, OutputImportMapConfig, SourcemapConfig, TargetEnv,
+};
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::{create_compiler_with_args, get_compiler_resources};
+
+/// Build a copy of the fixture, change `b.ts` and build again, return the resources of both builds
+fn build_before_and_after_change(
+  cwd: &Path,
+  crate_path: PathBuf,
+  entry: &str,
+  target_env: TargetEnv,
+) -> (BTreeMap<String, String>, BTreeMap<String, String>) {
+  let fixture_name = cwd.file_name().unwrap().to_string_lossy().to_string();
+  let root = std::env::temp_dir().join(format!(
+    "farm-import-map-{}-{fixture_name}",
+    std::process::id()
+  ));
+  std::fs::create_dir_all(&root).unwrap();
+
+  for entry in std::fs::read_dir(cwd).unwrap() {
+    let entry = entry.unwrap();
+    std::fs::copy(entry.path(), root.join(entry.file_name())).unwrap();
+  }
+
+  let build = || {
+    let compiler =
+      create_compiler_with_args(root.clone(), crate_path.clone(), |mut config, plugins| {
+        config.input = HashMap::from([("index".to_string(), entry.to_string())]);
+        config.output.target_env = target_env.clone();
+        config.output.filename = "[resourceName].[contentHash].[ext]".to_string();
+        config.output.import_map = BoolOrObj::Bool(true);
+        config.sourcemap = Box::new(SourcemapConfig::Bool(false));
+        (config, plugins)
+      });
+    compiler.compile().unwrap();
+
+    get_compiler_resources(&compiler)
+  };
+
+  let before = build();
+  std::fs::write(
+    root.join("b.ts"),
+    "export function b() {\n  return 'b changed';\n}\n",
+  )
+  .unwrap();
+  let after = build();
+
+  std::fs::remove_dir_all(&root).unwrap();
+
+  (before, after)
+}
+
+/// names of the resources that are added, removed or changed
+fn changed_resources(
+  before: &BTreeMap<String, String>,
+  after: &BTreeMap<String, String>,
+) -> BTreeSet<String> {
+  before
+    .keys()
+    .chain(after.keys())
+    .filter(|name| before.get(*name) != after.get(*name))
+    .cloned()
+    .collect()
+}
+
+#[test]
+fn import_map_node_entry() {
+  fixture!(
+    "tests/fixtures/import_map/node/index.ts",
+    |file, crate_path| {
+      let (before, after) = build_before_and_after_change(
+        file.parent().unwrap(),
+        crate_path,
+        "./index.ts",
+        TargetEnv::Node,
+      );
+
+      assert!(before["index.import-map.js"].contains(".setImportMap({"));
+
+      let entry = before
+        .iter()
+        .find(|(name, _)| {
+          name.starts_with("index") && name.ends_with(".js") && !name.contains("import-map")
+        })
+        .map(|(_, content)| content)
+        .unwrap();
+      assert!(entry.contains("import \"./index.import-map.js\";"));
+
+      let changed = changed_resources(&before, &after);
+      // the old and new chunk of b.ts, and the import map
+      assert_eq!(changed.len(), 3, "{changed:?}");
+      assert!(changed.contains("index.import-map.js"));
+      assert_eq!(
+        changed
+          .iter()
+          .filter(|name| name.starts_with("b_") || name.starts_with("b."))
+          .count(),
+        2,
+        "{changed:?}"
+      );
+    }
+  );
+}
+
+#[test]
+fn import_map_html_entry() {
+  fixture!(
+    "tests/fixtures/import_map/html/index.html",
+    |file, crate_path| {
+      let (before, after) = build_before_and_after_change(
+        file.parent().unwrap(),
+        crate_path,
+        "./index.html",
+        TargetEnv::Browser,
+      );
+
+      assert!(before["index.html"].contains("<script type=\"importmap\">{\"imports\":{"));
+
+      let changed = changed_resources(&before, &after);
+      // the old and new chunk of b.ts, and the html which contains the import map
+      assert_eq!(changed.len(), 3, "{changed:?}");
+      assert!(changed.contains("index.html"));
+    }
+  );
+}
+
+#[test]
+fn import_map_relative_to_entry() {
+  fixture!(
+    "tests/fixtures/import_map/node/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.output.target_env = TargetEnv::Node;
+          config.output.entry_filename = "entries/[entryName].[ext]".to_string();
+          config.output.import_map = BoolOrObj::Bool(true);
+          config.sourcemap = Box::new(SourcemapConfig::Bool(false));
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      let resources = get_compiler_resources(&compiler);
+      assert!(resources["entries/index.js"].contains("import \"../index.import-map.js\";"));
+
+      // the import map resource belongs to the resource pot of the entry
+      let resources_map = compiler.context().resources_map.lock();
+      assert_eq!(
+        resources_map["index.import-map.js"]
+          .origin
+          .as_resource_pot(),
+        resources_map["entries/index.js"].origin.as_resource_pot()
+      );
+    }
+  );
+}
+
+#[test]
+fn import_map_filename_conflict() {
+  fixture!(
+    "tests/fixtures/import_map/node/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([
+            ("index".to_string(), "./index.ts".to_string()),
+            ("a".to_string(), "./a.ts".to_string()),
+          ]);
+          config.output.target_env = TargetEnv::Node;
+          config.output.import_map = BoolOrObj::Obj(OutputImportMapConfig {
+            filename: "import-map.js".to_string(),
+          });
+          config.sourcemap = Box::new(SourcemapConfig::Bool(false));
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err().to_string();
+      assert!(err.contains("must contain `[entryName]`"), "{err}");
+    }
+  );
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use farmfe_core::config::{ModuleFormat, SourcemapConfig, TargetEnv};
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::{create_compiler_with_args, create_config};
+
+#[test]
+fn library_formats_reject_multiple_chunks() {
+  fixture!(
+    "tests/fixtures/library_formats/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      for format in [ModuleFormat::Iife, ModuleFormat::Umd] {
+        let compiler = create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.output.target_env = TargetEnv::Custom("library-browser".to_string());
+            config.output.format = format;
+            config.output.global_name = Some("MyLib".to_string());
+            config.sourcemap = Box::new(SourcemapConfig::Bool(false));
+            (config, plugins)
+          },
+        );
+
+        let err = compiler.compile().unwrap_err().to_string();
+        assert!(err.contains("single chunk"), "{err}");
+      }
+    }
+  );
+}
+
+#[test]
+fn library_formats_invalid_global_name() {
+  fixture!(
+    "tests/fixtures/library_formats/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      // `var class = ...` or `var default = ...` is a syntax error
+      for global_name in ["My.Lib", "my-lib", "1lib", "", "class", "default", "let"] {
+        let mut config = create_config(cwd.to_path_buf(), crate_path.clone());
+        config.output.target_env = TargetEnv::Custom("library-browser".to_string());
+        config.output.format = ModuleFormat::Iife;
+        config.output.global_name = Some(global_name.to_string());
+
+        assert!(
+          farmfe_compiler::Compiler::new(config, vec![]).is_err(),
+          "{global_name}"
+        );
+      }
+
+      let mut config = create_config(cwd.to_path_buf(), crate_path.clone());
+      config.output.target_env = TargetEnv::Custom("library-browser".to_string());
+      config.output.format = ModuleFormat::Iife;
+      config.output.global_name = Some("MyLib".to_string());
+      assert!(farmfe_compiler::Compiler::new(config, vec![]).is_ok());
+    }
+  );
+}
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+use farmfe_core::config::bool_or_obj::BoolOrObj;
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::{assert_compiler_result, create_compiler_with_args};
+
+#[test]
+fn manifest() {
+  fixture!("tests/fixtures/manifest/**/index.ts", |file, crate_path| {
+    let cwd = file.parent().unwrap();
+    println!("testing manifest: {cwd:?}");
+
+    let entry_name = "index".to_string();
+
+    let compiler = create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+      config.input = HashMap::from([(entry_name.clone(), "./index.ts".into())]);
+      config.output.filename = "[resourceName].[contentHash].[ext]".to_string();
+      config.output.assets_filename = "[resourceName].[contentHash].[ext]".to_string();
+      config.output.manifest = BoolOrObj::Bool(true);
+      (config, plugins)
+    });
+
+    compiler.compile().unwrap();
+
+    assert_compiler_result(&compiler, Some(&entry_name));
+  });
+}
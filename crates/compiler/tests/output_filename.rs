@@ -0,0 +1,193 @@
+use std::{collections::HashMap, sync::Arc};
+
+use farmfe_core::{
+  context::{CompilationContext, EmitFileParams},
+  error::{CompilationError, Result},
+  plugin::{
+    OutputFilenameKind, Plugin, PluginOutputFilenameHookParams, PluginTransformHookParam,
+    PluginTransformHookResult,
+  },
+  resource::ResourceType,
+};
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::create_compiler_with_args;
+
+fn resource_names(compiler: &farmfe_compiler::Compiler) -> Vec<String> {
+  let mut names = compiler
+    .context()
+    .resources_map
+    .lock()
+    .keys()
+    .cloned()
+    .collect::<Vec<_>>();
+  names.sort();
+  names
+}
+
+#[test]
+fn output_filename_templates() {
+  fixture!(
+    "tests/fixtures/output_filename/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      println!("testing output filename templates: {cwd:?}");
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".into())]);
+          config.output.entry_filename = "[entryName].[format].[ext]".to_string();
+          config.output.filename = "[target]/[name].[hash:12].[ext]".to_string();
+          config.output.assets_filename = "[path][name].[hash:4].[ext]".to_string();
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      let names = resource_names(&compiler);
+
+      assert!(names.contains(&"index.esm.js".to_string()), "{names:?}");
+      assert!(
+        names.iter().any(|n| n.starts_with("assets/logo.")),
+        "{names:?}"
+      );
+      assert!(
+        names
+          .iter()
+          .any(|n| n.starts_with("_external/_/assets/logo.")),
+        "{names:?}"
+      );
+      // [target]/[name].[hash:12].[ext]
+      assert!(
+        names.iter().any(|n| {
+          n.strip_prefix("browser/")
+            .and_then(|n| n.strip_suffix(".js"))
+            .and_then(|n| n.rsplit_once('.'))
+            .is_some_and(|(_, hash)| hash.len() == 12)
+        }),
+        "{names:?}"
+      );
+    }
+  );
+}
+
+#[test]
+fn output_filename_conflict() {
+  fixture!(
+    "tests/fixtures/output_filename/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".into())]);
+          config.output.entry_filename = "bundle.js".to_string();
+          config.output.filename = "bundle.js".to_string();
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err();
+      assert!(
+        matches!(err, CompilationError::OutputFilenameConflict { ref name, .. } if name == "bundle.js"),
+        "{err}"
+      );
+    }
+  );
+}
+
+/// Emits a file with the same name for every script module, like a plugin that forgets to add `[name]` to the filename
+struct EmitFilePlugin;
+
+impl Plugin for EmitFilePlugin {
+  fn name(&self) -> &str {
+    "EmitFilePlugin"
+  }
+
+  fn transform(
+    &self,
+    param: &PluginTransformHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<PluginTransformHookResult>> {
+    if param.module_type.is_script() {
+      context.emit_file(EmitFileParams {
+        resolved_path: param.module_id.clone(),
+        name: "meta.json".to_string(),
+        content: b"{}".to_vec(),
+        resource_type: ResourceType::Asset("json".to_string()),
+      })?;
+    }
+
+    Ok(None)
+  }
+}
+
+#[test]
+fn output_filename_asset_conflict() {
+  fixture!(
+    "tests/fixtures/output_filename/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".into())]);
+          plugins.push(Arc::new(EmitFilePlugin) as _);
+          (config, plugins)
+        });
+
+      let err = compiler.compile().unwrap_err();
+      assert!(
+        err
+          .to_string()
+          .contains("Output filename `meta.json` is generated by both"),
+        "{err}"
+      );
+    }
+  );
+}
+
+/// Same as a function-style `output.filename` passed from the js config
+struct OutputFilenamePlugin;
+
+impl Plugin for OutputFilenamePlugin {
+  fn name(&self) -> &str {
+    "OutputFilenamePlugin"
+  }
+
+  fn output_filename(
+    &self,
+    params: &PluginOutputFilenameHookParams,
+    _context: &Arc<CompilationContext>,
+  ) -> Result<Option<String>> {
+    Ok(match params.kind {
+      OutputFilenameKind::Asset => Some(format!("static/{}.[hash:6].[ext]", params.name)),
+      _ => None,
+    })
+  }
+}
+
+#[test]
+fn output_filename_hook() {
+  fixture!(
+    "tests/fixtures/output_filename/**/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".into())]);
+          plugins.push(Arc::new(OutputFilenamePlugin) as _);
+          (config, plugins)
+        });
+
+      compiler.compile().unwrap();
+
+      let names = resource_names(&compiler);
+      assert!(
+        names.iter().any(|n| n.starts_with("static/logo.")),
+        "{names:?}"
+      );
+    }
+  );
+}
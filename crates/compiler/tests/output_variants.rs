@@ -0,0 +1,290 @@
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+use farmfe_compiler::Compiler;
+use farmfe_core::{
+  config::{
+    bool_or_obj::BoolOrObj,
+    persistent_cache::{PersistentCacheConfig, PersistentCacheConfigObj},
+    Config, Mode, ModuleFormat, OutputVariantConfig, SourcemapConfig, TargetEnv,
+  },
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  plugin::{
+    Plugin, PluginRenderResourcePotHookParam, PluginRenderResourcePotHookResult, UpdateType,
+  },
+};
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::{create_compiler_with_args, create_config, get_compiler_resources};
+
+fn create_variants_compiler(
+  cwd: &Path,
+  crate_path: PathBuf,
+  update_config: impl FnOnce(&mut Config, &mut Vec<Arc<dyn Plugin>>),
+) -> Compiler {
+  create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, mut plugins| {
+    config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+    config.output.target_env = TargetEnv::Custom("library-browser".to_string());
+    config.output.format = ModuleFormat::EsModule;
+    config.output.entry_filename = "[entryName].[format].js".to_string();
+    config.output.variants = vec![OutputVariantConfig {
+      format: Some(ModuleFormat::CommonJs),
+      ..Default::default()
+    }];
+    config.tree_shaking = Box::new(BoolOrObj::Bool(false));
+    config.sourcemap = Box::new(SourcemapConfig::Bool(false));
+    update_config(&mut config, &mut plugins);
+    (config, plugins)
+  })
+}
+
+/// Copy the fixture to a temp dir, so the tests can change the modules
+fn copy_fixture(cwd: &Path, name: &str) -> PathBuf {
+  let root = std::env::temp_dir().join(format!(
+    "farm-output-variants-{}-{name}",
+    std::process::id()
+  ));
+  std::fs::create_dir_all(&root).unwrap();
+
+  for entry in std::fs::read_dir(cwd).unwrap() {
+    let entry = entry.unwrap();
+    std::fs::copy(entry.path(), root.join(entry.file_name())).unwrap();
+  }
+
+  root
+}
+
+#[test]
+fn output_variants_esm_and_cjs() {
+  fixture!(
+    "tests/fixtures/output_variants/index.ts",
+    |file, crate_path| {
+      let compiler = create_variants_compiler(file.parent().unwrap(), crate_path, |_, _| {});
+      compiler.compile().unwrap();
+      let resources = get_compiler_resources(&compiler);
+
+      assert_eq!(
+        resources.keys().collect::<Vec<_>>(),
+        vec!["index.cjs.js", "index.esm.js"]
+      );
+
+      let esm = &resources["index.esm.js"];
+      assert!(esm.contains("export { greet"), "{esm}");
+      assert!(esm.contains("import.meta.url"), "{esm}");
+
+      let cjs = &resources["index.cjs.js"];
+      assert!(cjs.contains("module.exports.greet = greet"), "{cjs}");
+      assert!(!cjs.contains("import.meta"), "{cjs}");
+      assert!(cjs.contains("__filename"), "{cjs}");
+    }
+  );
+}
+
+#[test]
+fn output_variants_cjs_primary() {
+  fixture!(
+    "tests/fixtures/output_variants/index.ts",
+    |file, crate_path| {
+      let compiler = create_variants_compiler(file.parent().unwrap(), crate_path, |config, _| {
+        config.output.format = ModuleFormat::CommonJs;
+        config.output.variants = vec![OutputVariantConfig {
+          format: Some(ModuleFormat::EsModule),
+          ..Default::default()
+        }];
+      });
+      compiler.compile().unwrap();
+      let resources = get_compiler_resources(&compiler);
+
+      let cjs = &resources["index.cjs.js"];
+      assert!(!cjs.contains("import.meta"), "{cjs}");
+      assert!(cjs.contains("__filename"), "{cjs}");
+
+      let esm = &resources["index.esm.js"];
+      assert!(esm.contains("import.meta.url"), "{esm}");
+      assert!(!esm.contains("__filename"), "{esm}");
+    }
+  );
+}
+
+#[test]
+fn output_variants_filename_conflict() {
+  fixture!(
+    "tests/fixtures/output_variants/index.ts",
+    |file, crate_path| {
+      let compiler = create_variants_compiler(file.parent().unwrap(), crate_path, |config, _| {
+        config.output.variants[0].entry_filename = Some("[entryName].esm.js".to_string());
+      });
+      let err = compiler.compile().unwrap_err();
+
+      assert!(
+        matches!(err, CompilationError::OutputVariantFilenameConflict { ref name, index: 0 } if name == "index.esm.js"),
+        "{err:?}"
+      );
+    }
+  );
+}
+
+#[test]
+fn output_variants_only_for_library() {
+  fixture!(
+    "tests/fixtures/output_variants/index.ts",
+    |file, crate_path| {
+      let mut config = create_config(file.parent().unwrap().to_path_buf(), crate_path);
+      config.output.target_env = TargetEnv::Custom("library-browser".to_string());
+      config.output.variants = vec![OutputVariantConfig {
+        target_env: Some(TargetEnv::Browser),
+        ..Default::default()
+      }];
+
+      assert!(Compiler::new(config, vec![]).is_err());
+    }
+  );
+}
+
+#[test]
+fn output_variants_stats() {
+  fixture!(
+    "tests/fixtures/output_variants/index.ts",
+    |file, crate_path| {
+      let render_stats_count = |variants: Vec<OutputVariantConfig>| {
+        let compiler =
+          create_variants_compiler(file.parent().unwrap(), crate_path.clone(), |config, _| {
+            config.record = true;
+            config.output.variants = variants;
+          });
+        compiler.compile().unwrap();
+
+        let stats = compiler
+          .context()
+          .record_manager
+          .initial_compilation_flow_stats
+          .read();
+        stats.hook_stats_map["generate_resources"].len()
+      };
+
+      let primary_count = render_stats_count(vec![]);
+      let variants_count = render_stats_count(vec![OutputVariantConfig {
+        format: Some(ModuleFormat::CommonJs),
+        ..Default::default()
+      }]);
+
+      assert!(primary_count > 0);
+      // the resource pots of the variant are recorded in the stats of the compilation too
+      assert_eq!(variants_count, primary_count * 2);
+    }
+  );
+}
+
+/// Records the `output.variants` index of every rendered resource pot
+#[derive(Default)]
+struct RenderRecordPlugin {
+  rendered: Mutex<Vec<Option<usize>>>,
+}
+
+impl Plugin for RenderRecordPlugin {
+  fn name(&self) -> &str {
+    "RenderRecordPlugin"
+  }
+
+  fn render_resource_pot(
+    &self,
+    _resource_pot: &PluginRenderResourcePotHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<PluginRenderResourcePotHookResult>> {
+    self.rendered.lock().unwrap().push(context.output_variant);
+
+    Ok(None)
+  }
+}
+
+#[test]
+fn output_variants_persistent_cache() {
+  fixture!(
+    "tests/fixtures/output_variants/index.ts",
+    |file, crate_path| {
+      let root = copy_fixture(file.parent().unwrap(), "persistent-cache");
+
+      let build = || {
+        let plugin = Arc::new(RenderRecordPlugin::default());
+        let compiler = create_variants_compiler(&root, crate_path.clone(), |config, plugins| {
+          config.persistent_cache =
+            Box::new(PersistentCacheConfig::Obj(PersistentCacheConfigObj {
+              namespace: "output-variants".to_string(),
+              cache_dir: root.join("cache").to_string_lossy().to_string(),
+              module_cache_key_strategy: Default::default(),
+              build_dependencies: vec![],
+              envs: HashMap::new(),
+            }));
+          plugins.push(plugin.clone() as _);
+        });
+        compiler.compile().unwrap();
+
+        let rendered = plugin.rendered.lock().unwrap().clone();
+        (get_compiler_resources(&compiler), rendered)
+      };
+
+      let (resources, rendered) = build();
+      assert!(rendered.contains(&None), "{rendered:?}");
+      assert!(rendered.contains(&Some(0)), "{rendered:?}");
+
+      let (cached_resources, cached_rendered) = build();
+      std::fs::remove_dir_all(&root).unwrap();
+
+      // the resource pots of both outputs are read from the cache
+      assert!(cached_rendered.is_empty(), "{cached_rendered:?}");
+      assert_eq!(cached_resources, resources);
+    }
+  );
+}
+
+#[test]
+fn output_variants_update() {
+  fixture!(
+    "tests/fixtures/output_variants/index.ts",
+    |file, crate_path| {
+      let root = copy_fixture(file.parent().unwrap(), "update");
+      let compiler = create_variants_compiler(&root, crate_path, |config, _| {
+        config.mode = Mode::Development;
+      });
+      compiler.compile().unwrap();
+
+      let format_file = root.join("format.ts");
+      std::fs::write(
+        &format_file,
+        "export function format(message: string) {\n  return `[updated] ${message}`;\n}\n",
+      )
+      .unwrap();
+      compiler
+        .update(
+          vec![(
+            format_file.to_string_lossy().to_string(),
+            UpdateType::Updated,
+          )],
+          || {},
+          true,
+          false,
+        )
+        .unwrap();
+      std::fs::remove_dir_all(&root).unwrap();
+
+      let resources = get_compiler_resources(&compiler);
+      assert_eq!(
+        resources.keys().collect::<Vec<_>>(),
+        vec!["index.cjs.js", "index.esm.js"]
+      );
+
+      let esm = &resources["index.esm.js"];
+      assert!(esm.contains("[updated]"), "{esm}");
+      assert!(esm.contains("import.meta.url"), "{esm}");
+
+      let cjs = &resources["index.cjs.js"];
+      assert!(cjs.contains("[updated]"), "{cjs}");
+      assert!(!cjs.contains("import.meta"), "{cjs}");
+    }
+  );
+}
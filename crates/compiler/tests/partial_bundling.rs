@@ -1,8 +1,22 @@
 use std::collections::HashMap;
 
+use farmfe_core::{
+  config::{
+    bool_or_obj::BoolOrObj,
+    config_regex::ConfigRegex,
+    partial_bundling::{
+      PartialBundlingEnforceResourceConfig, PartialBundlingGroupConfig, PartialBundlingSizeMetric,
+    },
+  },
+  error::CompilationError,
+  module::ModuleId,
+  resource::resource_pot::{ResourcePot, ResourcePotType},
+  serde_json,
+  stats::{PartialBundlingMergeStep, PartialBundlingStats},
+};
 use farmfe_testing_helpers::fixture;
 
-use crate::common::{assert_compiler_result, create_compiler};
+use crate::common::{assert_compiler_result, create_compiler, create_compiler_with_args};
 
 mod common;
 
@@ -27,3 +41,172 @@ fn partial_bundling_test() {
     }
   );
 }
+
+#[test]
+fn partial_bundling_estimated_gzip_size_metric() {
+  fixture!(
+    "tests/fixtures/partial_bundling_size_metric/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let compiler =
+        create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+          config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+          config.partial_bundling.size_metric = PartialBundlingSizeMetric::EstimatedGzip;
+          (config, plugins)
+        });
+      compiler.compile().unwrap();
+
+      let context = compiler.context();
+      let module_graph = context.module_graph.read();
+      let module = module_graph
+        .module(&ModuleId::new("commented.ts", "", &context.config.root))
+        .unwrap();
+
+      // comments, whitespaces and types are removed, and the rest is compressed
+      assert!(module.estimated_size > 0);
+      assert!(
+        module.estimated_size * 10 < module.size,
+        "{} {}",
+        module.estimated_size,
+        module.size
+      );
+      assert_eq!(
+        context.config.partial_bundling.module_size(module),
+        module.estimated_size
+      );
+    }
+  );
+}
+
+#[test]
+fn partial_bundling_report() {
+  fixture!(
+    "tests/fixtures/partial_bundling_report/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let create_report_compiler = |report: bool| {
+        create_compiler_with_args(
+          cwd.to_path_buf(),
+          crate_path.clone(),
+          |mut config, plugins| {
+            config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+            config.partial_bundling.groups = vec![PartialBundlingGroupConfig {
+              name: "utils".to_string(),
+              test: vec![ConfigRegex::new("utils\\.ts$")],
+              ..Default::default()
+            }];
+            config.partial_bundling.enforce_resources =
+              vec![PartialBundlingEnforceResourceConfig {
+                name: "vendor".to_string(),
+                test: vec![ConfigRegex::new("vendor\\.ts$")],
+              }];
+            config.partial_bundling.report = BoolOrObj::Bool(report);
+            (config, plugins)
+          },
+        )
+      };
+
+      // nothing is recorded when the report is disabled
+      let compiler = create_report_compiler(false);
+      compiler.compile().unwrap();
+      assert!(compiler
+        .context()
+        .record_manager
+        .partial_bundling_stats()
+        .is_none());
+
+      let compiler = create_report_compiler(true);
+      compiler.compile().unwrap();
+
+      let context = compiler.context();
+      let stats = context.record_manager.partial_bundling_stats().unwrap();
+
+      let utils = &stats.modules[&ModuleId::from("utils.ts")];
+      assert_eq!(utils.group.as_deref(), Some("utils"));
+      assert_eq!(utils.module_pot.as_deref(), Some("utils"));
+      assert!(utils.module_bucket.is_some());
+      assert!(matches!(
+        stats.resource_pots[&utils.resource_pot].merge_history[0],
+        PartialBundlingMergeStep::TargetSize { .. }
+      ));
+
+      let vendor = &stats.modules[&ModuleId::from("vendor.ts")];
+      assert_eq!(vendor.enforce_resource.as_deref(), Some("vendor"));
+      let vendor_resource_pot = &stats.resource_pots[&vendor.resource_pot];
+      assert_eq!(vendor_resource_pot.module_group, None);
+      assert_eq!(
+        vendor_resource_pot.merge_history,
+        vec![PartialBundlingMergeStep::EnforceResource {
+          name: "vendor".to_string()
+        }]
+      );
+      assert!(!vendor_resource_pot.resources.is_empty());
+
+      // the report is emitted with the names of the generated resources
+      let resources_map = context.resources_map.lock();
+      let report: PartialBundlingStats =
+        serde_json::from_slice(&resources_map["partial-bundling-report.json"].bytes).unwrap();
+      assert_eq!(report.modules.len(), stats.modules.len());
+      assert!(report.resource_pots[&vendor.resource_pot]
+        .resources
+        .iter()
+        .all(|name| resources_map.contains_key(name)));
+    }
+  );
+}
+
+#[test]
+fn partial_bundling_chunk_name() {
+  fixture!(
+    "tests/fixtures/partial_bundling_chunk_name/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let create_chunk_name_compiler =
+        |enforce_resources: Vec<PartialBundlingEnforceResourceConfig>| {
+          create_compiler_with_args(
+            cwd.to_path_buf(),
+            crate_path.clone(),
+            |mut config, plugins| {
+              config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+              config.partial_bundling.enforce_resources = enforce_resources;
+              (config, plugins)
+            },
+          )
+        };
+      let admin_resource_pot_id = ResourcePot::gen_id("admin", ResourcePotType::Js);
+
+      // the resource pot of pages/admin.ts is not named `admin`, which is reserved by the chunk name
+      let compiler = create_chunk_name_compiler(vec![]);
+      compiler.compile().unwrap();
+
+      let context = compiler.context();
+      let resource_pot_map = context.resource_pot_map.read();
+      let admin_resource_pot = resource_pot_map
+        .resource_pot(&admin_resource_pot_id)
+        .unwrap();
+      assert_eq!(
+        admin_resource_pot.modules(),
+        vec![&ModuleId::from("pages/settings.ts")]
+      );
+      let module_graph = context.module_graph.read();
+      let admin_module = module_graph
+        .module(&ModuleId::from("pages/admin.ts"))
+        .unwrap();
+      assert_ne!(
+        admin_module.resource_pot.as_ref(),
+        Some(&admin_resource_pot_id)
+      );
+
+      // an enforce resource with the same name as the chunk name is reported instead of being replaced
+      let compiler = create_chunk_name_compiler(vec![PartialBundlingEnforceResourceConfig {
+        name: "admin".to_string(),
+        test: vec![ConfigRegex::new("vendor\\.ts$")],
+      }]);
+      let err = compiler.compile().unwrap_err();
+      assert!(
+        matches!(err, CompilationError::ResourcePotConflict { ref id } if id == &admin_resource_pot_id),
+        "{err}"
+      );
+    }
+  );
+}
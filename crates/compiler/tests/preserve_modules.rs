@@ -0,0 +1,159 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  path::PathBuf,
+};
+
+use farmfe_core::config::{
+  bool_or_obj::BoolOrObj, Config, ModuleFormat, OutputPreserveModulesConfig, SourcemapConfig,
+  TargetEnv,
+};
+use farmfe_testing_helpers::fixture;
+mod common;
+
+use crate::common::{create_compiler_with_args, get_compiler_resources};
+
+fn build_preserved_modules(
+  file: PathBuf,
+  crate_path: PathBuf,
+  format: ModuleFormat,
+  update_config: impl FnOnce(&mut Config),
+) -> BTreeMap<String, String> {
+  // the project root is the parent of `src`, `../shared` is inside the root but outside of `preserveModules.root`
+  let cwd = file.parent().unwrap().parent().unwrap();
+
+  let compiler = create_compiler_with_args(cwd.to_path_buf(), crate_path, |mut config, plugins| {
+    config.input = HashMap::from([("index".to_string(), "./src/index.ts".to_string())]);
+    config.output.target_env = TargetEnv::Custom("library-browser".to_string());
+    config.output.format = format;
+    config.output.preserve_modules = BoolOrObj::Obj(OutputPreserveModulesConfig {
+      root: Some("src".to_string()),
+    });
+    config.minify = Box::new(BoolOrObj::Bool(false));
+    config.tree_shaking = Box::new(BoolOrObj::Bool(false));
+    config.sourcemap = Box::new(SourcemapConfig::Bool(false));
+    update_config(&mut config);
+    (config, plugins)
+  });
+
+  compiler.compile().unwrap();
+
+  get_compiler_resources(&compiler)
+}
+
+#[test]
+fn preserve_modules_esm() {
+  fixture!(
+    "tests/fixtures/preserve_modules/src/index.ts",
+    |file, crate_path| {
+      let resources = build_preserved_modules(file, crate_path, ModuleFormat::EsModule, |_| {});
+
+      assert_eq!(
+        resources.keys().collect::<Vec<_>>(),
+        vec![
+          "_external/shared/version.js",
+          "components/button.js",
+          "index.js",
+          "lazy.js",
+          "shared/version.js",
+          "utils/format.js"
+        ]
+      );
+
+      let index = &resources["index.js"];
+      assert!(index.contains("from \"./components/button.js\""), "{index}");
+      assert!(index.contains("from \"./utils/format.js\""), "{index}");
+      // `../shared/version.ts` is outside of the root, it must not collide with `src/shared/version.ts`
+      assert!(
+        index.contains("from \"./_external/shared/version.js\""),
+        "{index}"
+      );
+      assert!(index.contains("from \"./shared/version.js\""), "{index}");
+      assert!(resources["_external/shared/version.js"].contains("VERSION"));
+      assert!(resources["shared/version.js"].contains("SRC_VERSION"));
+
+      // dynamic imports between preserved modules point to the emitted file too
+      assert!(index.contains("import(\"./lazy.js\")"), "{index}");
+      assert!(
+        resources["lazy.js"].contains("from \"./utils/format.js\""),
+        "{}",
+        resources["lazy.js"]
+      );
+
+      let button = &resources["components/button.js"];
+      assert!(button.contains("from \"../utils/format.js\""), "{button}");
+      assert!(button.contains("export { Button"), "{button}");
+
+      let format = &resources["utils/format.js"];
+      assert!(format.contains("export { format"), "{format}");
+    }
+  );
+}
+
+#[test]
+fn preserve_modules_cjs() {
+  fixture!(
+    "tests/fixtures/preserve_modules/src/index.ts",
+    |file, crate_path| {
+      let resources = build_preserved_modules(file, crate_path, ModuleFormat::CommonJs, |_| {});
+
+      let index = &resources["index.js"];
+      assert!(
+        index.contains("require(\"./components/button.js\")"),
+        "{index}"
+      );
+      assert!(
+        index.contains("require(\"./_external/shared/version.js\")"),
+        "{index}"
+      );
+      assert!(
+        index.contains("require(\"./shared/version.js\")"),
+        "{index}"
+      );
+
+      let button = &resources["components/button.js"];
+      assert!(
+        button.contains("require(\"../utils/format.js\")"),
+        "{button}"
+      );
+      assert!(
+        button.contains("module.exports.Button = Button"),
+        "{button}"
+      );
+    }
+  );
+}
+
+#[test]
+fn preserve_modules_configured_ext() {
+  fixture!(
+    "tests/fixtures/preserve_modules/src/index.ts",
+    |file, crate_path| {
+      let resources = build_preserved_modules(file, crate_path, ModuleFormat::EsModule, |config| {
+        config.output.entry_filename = "[entryName].mjs".to_string();
+        config.output.filename = "[name].mjs".to_string();
+      });
+
+      assert_eq!(
+        resources.keys().collect::<Vec<_>>(),
+        vec![
+          "_external/shared/version.mjs",
+          "components/button.mjs",
+          "index.mjs",
+          "lazy.mjs",
+          "shared/version.mjs",
+          "utils/format.mjs"
+        ]
+      );
+
+      let index = &resources["index.mjs"];
+      assert!(
+        index.contains("from \"./components/button.mjs\""),
+        "{index}"
+      );
+      assert!(index.contains("import(\"./lazy.mjs\")"), "{index}");
+
+      let button = &resources["components/button.mjs"];
+      assert!(button.contains("from \"../utils/format.mjs\""), "{button}");
+    }
+  );
+}
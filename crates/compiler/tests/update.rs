@@ -414,3 +414,219 @@ fn update_lazy_compilation_node() {
     }
   );
 }
+
+#[test]
+fn update_glob_added_and_removed() {
+  fixture!("tests/fixtures/update/glob/index.ts", |file, crate_path| {
+    let cwd = file.parent().unwrap().to_path_buf();
+    let added_file = cwd.join("pages").join("about.ts");
+    let _ = std::fs::remove_file(&added_file);
+
+    let compiler = create_update_compiler(
+      HashMap::from([("index".to_string(), "./index.ts".to_string())]),
+      cwd.clone(),
+      crate_path,
+      false,
+    );
+
+    compiler.compile().unwrap();
+
+    std::fs::write(&added_file, "export const name = 'about';").unwrap();
+    let added_path = added_file.to_string_lossy().to_string();
+
+    let result = compiler.update(
+      vec![(added_path.clone(), UpdateType::Added)],
+      || {},
+      true,
+      true,
+    );
+    std::fs::remove_file(&added_file).unwrap();
+    let result = result.unwrap();
+
+    assert_eq!(result.added_module_ids, vec!["pages/about.ts".into()]);
+    assert_eq!(result.updated_module_ids, vec!["index.ts".into()]);
+
+    let result = compiler
+      .update(vec![(added_path, UpdateType::Removed)], || {}, true, true)
+      .unwrap();
+
+    assert_eq!(result.removed_module_ids, vec!["pages/about.ts".into()]);
+    assert_eq!(result.updated_module_ids, vec!["index.ts".into()]);
+
+    // files that are not matched by the glob are ignored
+    let result = compiler
+      .update(
+        vec![(
+          cwd.join("about.ts").to_string_lossy().to_string(),
+          UpdateType::Added,
+        )],
+        || {},
+        true,
+        true,
+      )
+      .unwrap();
+    assert!(result.updated_module_ids.is_empty());
+  });
+}
+
+#[test]
+fn update_require_context_added_and_removed() {
+  fixture!(
+    "tests/fixtures/update/require-context/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let added_file = cwd.join("locales").join("fr.ts");
+      let _ = std::fs::remove_file(&added_file);
+
+      let compiler = create_update_compiler(
+        HashMap::from([("index".to_string(), "./index.ts".to_string())]),
+        cwd.clone(),
+        crate_path,
+        false,
+      );
+
+      compiler.compile().unwrap();
+
+      std::fs::write(&added_file, "export const name = 'fr';").unwrap();
+      let added_path = added_file.to_string_lossy().to_string();
+
+      let result = compiler.update(
+        vec![(added_path.clone(), UpdateType::Added)],
+        || {},
+        true,
+        true,
+      );
+      std::fs::remove_file(&added_file).unwrap();
+      let result = result.unwrap();
+
+      assert_eq!(result.added_module_ids, vec!["locales/fr.ts".into()]);
+      assert_eq!(result.updated_module_ids, vec!["index.ts".into()]);
+
+      let result = compiler
+        .update(vec![(added_path, UpdateType::Removed)], || {}, true, true)
+        .unwrap();
+
+      assert_eq!(result.removed_module_ids, vec!["locales/fr.ts".into()]);
+      assert_eq!(result.updated_module_ids, vec!["index.ts".into()]);
+    }
+  );
+}
+
+#[test]
+fn update_missing_file_created() {
+  fixture!(
+    "tests/fixtures/update/missing-file/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let app_file = cwd.join("app.ts");
+      let missing_file = cwd.join("Foo.ts");
+      let _ = std::fs::remove_file(&missing_file);
+      let app_content = std::fs::read_to_string(&app_file).unwrap();
+
+      let compiler = create_update_compiler(
+        HashMap::from([("index".to_string(), "./index.ts".to_string())]),
+        cwd.clone(),
+        crate_path,
+        false,
+      );
+
+      compiler.compile().unwrap();
+
+      std::fs::write(
+        &app_file,
+        "import { name } from './Foo';\nexport function render() {\n  console.log(name);\n}\n",
+      )
+      .unwrap();
+      let failed_result = compiler.update(
+        vec![(app_file.to_string_lossy().to_string(), UpdateType::Updated)],
+        || {},
+        true,
+        true,
+      );
+
+      std::fs::write(&missing_file, "export const name = 'foo';").unwrap();
+      let result = compiler.update(
+        vec![(
+          missing_file.to_string_lossy().to_string(),
+          UpdateType::Added,
+        )],
+        || {},
+        true,
+        true,
+      );
+
+      std::fs::write(&app_file, app_content).unwrap();
+      std::fs::remove_file(&missing_file).unwrap();
+
+      assert!(failed_result.is_err());
+      let result = result.unwrap();
+      assert_eq!(result.added_module_ids, vec!["Foo.ts".into()]);
+      assert_eq!(result.updated_module_ids, vec!["app.ts".into()]);
+    }
+  );
+}
+
+#[test]
+fn update_error_tolerant() {
+  fixture!(
+    "tests/fixtures/update/error-tolerant/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let app_file = cwd.join("app.ts");
+      let app_content = std::fs::read_to_string(&app_file).unwrap();
+
+      let compiler = Compiler::new(
+        Config {
+          input: HashMap::from([("index".to_string(), "./index.ts".to_string())]),
+          root: cwd.to_string_lossy().to_string(),
+          runtime: generate_runtime(crate_path),
+          mode: Mode::Development,
+          sourcemap: Box::new(SourcemapConfig::Bool(false)),
+          progress: false,
+          preset_env: Box::new(PresetEnvConfig::Bool(false)),
+          persistent_cache: Box::new(PersistentCacheConfig::Bool(false)),
+          error_tolerant: true,
+          ..Default::default()
+        },
+        vec![],
+      )
+      .unwrap();
+
+      compiler.compile().unwrap();
+
+      std::fs::write(&app_file, "export function render() {\n  console.log(\n").unwrap();
+      let errored_result = compiler.update(
+        vec![(app_file.to_string_lossy().to_string(), UpdateType::Updated)],
+        || {},
+        true,
+        true,
+      );
+      let errored_app = compiler
+        .context()
+        .module_graph
+        .read()
+        .module(&"app.ts".into())
+        .map(|m| (m.is_errored(), m.content.contains("console.log('app')")));
+
+      std::fs::write(&app_file, app_content).unwrap();
+      let fixed_result = compiler.update(
+        vec![(app_file.to_string_lossy().to_string(), UpdateType::Updated)],
+        || {},
+        true,
+        true,
+      );
+
+      let errored_result = errored_result.unwrap();
+      assert!(errored_result.updated_module_ids.is_empty());
+      assert_eq!(errored_result.errored_modules.len(), 1);
+      assert_eq!(errored_result.errored_modules[0].module_id, "app.ts".into());
+      // the last successful build of the module is kept
+      assert_eq!(errored_app, Some((true, true)));
+
+      let fixed_result = fixed_result.unwrap();
+      assert_eq!(fixed_result.updated_module_ids, vec!["app.ts".into()]);
+      assert!(fixed_result.errored_modules.is_empty());
+      assert!(compiler.errored_modules().is_empty());
+    }
+  );
+}
@@ -4,6 +4,7 @@ use farmfe_macro_cache_item::cache_item;
 
 use crate::config::Mode;
 use crate::module::module_graph::ModuleGraphEdge;
+use crate::module::watch_graph::WatchGlob;
 use crate::module::{Module, ModuleId};
 use crate::plugin::PluginAnalyzeDepsHookResultEntry;
 
@@ -42,6 +43,8 @@ pub struct CachedModule {
   pub module: Module,
   pub dependencies: Vec<CachedModuleDependency>,
   pub watch_dependencies: Vec<CachedWatchDependency>,
+  /// glob patterns watched by the module, see [WatchGlob]
+  pub watch_globs: Vec<WatchGlob>,
 }
 
 impl CachedModule {
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::TargetEnv;
+use super::{config_regex::ConfigRegex, TargetEnv};
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,6 +25,12 @@ pub struct AssetsConfig {
   pub include: Vec<String>,
   /// Used internally, this option will be not exposed to user.
   pub public_dir: Option<String>,
+  /// Assets smaller than this size in bytes are inlined as data uris instead of being emitted. Disabled by default
+  pub inline_limit: Option<usize>,
+  /// Only inline assets whose path matches these regexes, all assets under the limit are inlined if empty
+  pub inline_include: Vec<ConfigRegex>,
+  /// Never inline assets whose path matches these regexes
+  pub inline_exclude: Vec<ConfigRegex>,
   // TODO: v2
   // for ssr mode, should specify asset path format, default from `output.targetEnv`
   // pub mode: Option<AssetFormatMode>,
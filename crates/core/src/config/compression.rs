@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionAlgorithm {
+  Gzip,
+  Brotli,
+}
+
+impl CompressionAlgorithm {
+  /// extension appended to the name of the compressed resource
+  pub fn ext(&self) -> &'static str {
+    match self {
+      CompressionAlgorithm::Gzip => "gz",
+      CompressionAlgorithm::Brotli => "br",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CompressionConfig {
+  pub algorithms: Vec<CompressionAlgorithm>,
+  /// Only resources larger than this size in bytes are compressed
+  pub threshold: usize,
+  /// 0-9
+  pub gzip_level: u32,
+  /// 0-11
+  pub brotli_level: u32,
+}
+
+impl Default for CompressionConfig {
+  fn default() -> Self {
+    Self {
+      algorithms: vec![CompressionAlgorithm::Gzip, CompressionAlgorithm::Brotli],
+      threshold: 1024,
+      gzip_level: 9,
+      brotli_level: 11,
+    }
+  }
+}
@@ -4,4 +4,79 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase", default)]
 pub struct HtmlConfig {
   pub base: Option<String>,
+  /// Inline the css of the initial resource pots into a `<style>` in `<head>` instead of injecting `<link>`
+  pub critical_css: Option<HtmlCriticalCssConfig>,
+  /// Inject `<link rel="preload">` for initial resources and `<link rel="prefetch">` for dynamic resources
+  pub resource_hints: Option<HtmlResourceHintsConfig>,
+  /// Add subresource integrity to injected `<script>` and `<link>`, and to the resources loaded by the runtime dynamically
+  pub integrity: Option<IntegrityAlgorithm>,
+  /// Placeholder of the csp nonce, for example `{{nonce}}`, the server should replace it with a random nonce for each request.
+  /// The nonce is added to all injected inline `<script>` and `<style>`, and exposed by `<meta property="csp-nonce">` for
+  /// the `<style>` created by the runtime
+  pub csp_nonce: Option<String>,
+  /// Emit `[htmlName].csp.json` that contains the hashes of the inline scripts and styles of the html
+  pub csp_hashes: Option<IntegrityAlgorithm>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HtmlCriticalCssConfig {
+  /// When the initial css is larger than this size in bytes, only the rules used by the html document are inlined,
+  /// the whole css is still loaded non-blocking to keep the cascade order. The whole css is always inlined if it's [None]
+  pub threshold: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HtmlResourceHintsConfig {
+  /// Types of the initial resources to preload. Stylesheets that are already loaded by a blocking
+  /// `<link rel="stylesheet">` are not preloaded
+  pub preload: Vec<HtmlPreloadResourceType>,
+  /// Use `modulepreload` instead of `preload` for scripts, only enable it when scripts are loaded as module scripts
+  pub module_preload: bool,
+  /// Prefetch the resources of dynamic imports. It can be overridden for a single import by
+  /// `import(/* farmPrefetch: false */ './lazy')` or `webpackPrefetch`, and `import(/* farmPreload: true */ './lazy')`
+  /// or `webpackPreload` preloads the resources of the import instead
+  pub prefetch: bool,
+}
+
+impl Default for HtmlResourceHintsConfig {
+  fn default() -> Self {
+    Self {
+      preload: vec![
+        HtmlPreloadResourceType::Script,
+        HtmlPreloadResourceType::Css,
+        HtmlPreloadResourceType::Font,
+      ],
+      module_preload: false,
+      prefetch: true,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HtmlPreloadResourceType {
+  Script,
+  Css,
+  Font,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IntegrityAlgorithm {
+  Sha256,
+  Sha384,
+  Sha512,
+}
+
+impl IntegrityAlgorithm {
+  /// prefix of the integrity value, e.g. `sha384`
+  pub fn prefix(&self) -> &'static str {
+    match self {
+      IntegrityAlgorithm::Sha256 => "sha256",
+      IntegrityAlgorithm::Sha384 => "sha384",
+      IntegrityAlgorithm::Sha512 => "sha512",
+    }
+  }
 }
@@ -21,6 +21,7 @@ pub const FARM_MODULE_EXPORT: &str = "exports";
 pub mod asset;
 pub mod bool_or_obj;
 pub mod comments;
+pub mod compression;
 pub mod config_regex;
 pub mod css;
 pub mod custom;
@@ -63,12 +64,22 @@ pub struct Config {
   /// whether to record the compilation flow stats, default is false.
   pub record: bool,
   pub progress: bool,
+  /// Keep the modules that fail to load, transform or parse in the module graph as error modules instead of failing the
+  /// compilation, an error module keeps the result of its last successful build. Default is false, it's enabled for the
+  /// dev server by default
+  pub error_tolerant: bool,
+  /// Keep building the other modules after module level errors in the build stage and report all the errors at once
+  /// as [crate::error::CompilationError::Multiple] in a deterministic order. Default is false
+  pub continue_on_error: bool,
   pub persistent_cache: Box<persistent_cache::PersistentCacheConfig>,
   /// concatenateModules is used to concatenate modules into a single file. And it will not be wrapped by the runtime.
   /// Note: all runtime options will be ignored if concatenateModules is true. Which means you can't use `runtime.plugins`, `path` and so on.
   pub concatenate_modules: bool,
   /// comments config for script, css and html
   pub comments: Box<CommentsConfig>,
+  /// emit gzip and brotli compressed variants of the resources, default is false. HMR updates in development are not
+  /// compressed.
+  pub compression: Box<BoolOrObj<compression::CompressionConfig>>,
   /// preserved for future compatibility usage when there are more config options
   pub custom: Box<HashMap<String, String>>,
 }
@@ -102,12 +113,15 @@ impl Default for Config {
       preset_env: Box::<PresetEnvConfig>::default(),
       record: false,
       progress: true,
+      error_tolerant: false,
+      continue_on_error: false,
       persistent_cache: Box::<persistent_cache::PersistentCacheConfig>::new(
         // the config file path will be set after the Config is initialized
         persistent_cache::PersistentCacheConfig::get_default_config(&root),
       ),
       concatenate_modules: false,
       comments: Box::default(),
+      compression: Box::new(BoolOrObj::Bool(false)),
       custom: Box::<HashMap<String, String>>::default(),
     }
   }
@@ -142,6 +156,15 @@ impl TargetEnv {
     matches!(self, TargetEnv::Library)
       || matches!(self, TargetEnv::Custom(custom) if custom == "library-browser" || custom == "library-node")
   }
+
+  pub fn as_str(&self) -> &str {
+    match self {
+      TargetEnv::Browser => "browser",
+      TargetEnv::Node => "node",
+      TargetEnv::Library => "library",
+      TargetEnv::Custom(custom) => custom,
+    }
+  }
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, Default)]
@@ -151,6 +174,31 @@ pub enum ModuleFormat {
   EsModule,
   #[serde(rename = "cjs")]
   CommonJs,
+  /// only supported by library targets
+  #[serde(rename = "iife")]
+  Iife,
+  /// only supported by library targets
+  #[serde(rename = "umd")]
+  Umd,
+}
+
+impl ModuleFormat {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ModuleFormat::EsModule => "esm",
+      ModuleFormat::CommonJs => "cjs",
+      ModuleFormat::Iife => "iife",
+      ModuleFormat::Umd => "umd",
+    }
+  }
+
+  /// iife and umd bundles are generated as commonjs, then wrapped by a function that provides `module`, `exports` and `require`
+  pub fn is_commonjs_like(&self) -> bool {
+    matches!(
+      self,
+      ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd
+    )
+  }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -200,11 +248,24 @@ pub struct CssPrefixerConfig {
   pub targets: Option<Targets>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CssPurgeConfig {
+  /// Selectors whose class, id or tag name matches any of these regexes are always kept
+  pub safelist: Vec<ConfigRegex>,
+  /// Only purge css modules whose path matches these regexes, all css modules are purged if empty
+  pub include: Vec<ConfigRegex>,
+  /// Never purge css modules whose path matches these regexes
+  pub exclude: Vec<ConfigRegex>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct CssConfig {
   pub modules: Option<CssModulesConfig>,
   pub prefixer: Option<CssPrefixerConfig>,
+  /// Remove rules whose selectors are not used by any script or html module. Disabled by default.
+  pub purge: Option<CssPurgeConfig>,
 }
 
 impl Default for CssConfig {
@@ -212,6 +273,7 @@ impl Default for CssConfig {
     Self {
       modules: Some(Default::default()),
       prefixer: Some(Default::default()),
+      purge: None,
     }
   }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::{ModuleFormat, TargetEnv};
+use super::{bool_or_obj::BoolOrObj, ModuleFormat, TargetEnv};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase", default)]
@@ -12,6 +12,96 @@ pub struct OutputConfig {
   pub assets_filename: String,
   pub target_env: TargetEnv,
   pub format: ModuleFormat,
+  /// the global variable that the exports of the library are assigned to when `format` is iife or umd, must be a valid identifier that is not a reserved word
+  pub global_name: Option<String>,
+  /// Emit a manifest that maps entries and source modules to their emitted files, for backend integration. Disabled by default
+  pub manifest: BoolOrObj<OutputManifestConfig>,
+  /// Load chunks through stable logical names resolved by a generated import map, so a changed module only changes its own chunk and the import map. Disabled by default
+  pub import_map: BoolOrObj<OutputImportMapConfig>,
+  /// Emit one file per module instead of the resource pots generated by partial bundling, keeping the directory structure of the modules.
+  /// Only supported by library targets with `esm` or `cjs` format. Disabled by default
+  pub preserve_modules: BoolOrObj<OutputPreserveModulesConfig>,
+  /// Additional outputs generated from the same module graph, e.g. a `cjs` output besides the `esm` one.
+  /// Modules are resolved and transformed once for this output, then each variant runs its own generate stage,
+  /// which is run again after every update in watch mode. Only supported by library targets
+  pub variants: Vec<OutputVariantConfig>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutputManifestConfig {
+  /// name of the manifest resource, relative to `output.path`
+  pub filename: String,
+}
+
+impl Default for OutputManifestConfig {
+  fn default() -> Self {
+    Self {
+      filename: "manifest.json".to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutputImportMapConfig {
+  /// name of the import map resource emitted for entries that are not loaded by html, `[entryName]` is replaced by the name of the entry.
+  /// The name must not contain a content hash, it's the only resource that is referenced by a stable name
+  pub filename: String,
+}
+
+impl Default for OutputImportMapConfig {
+  fn default() -> Self {
+    Self {
+      filename: "[entryName].import-map.js".to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutputPreserveModulesConfig {
+  /// the emitted files keep the paths of the modules relative to this directory, defaults to `root`.
+  /// Modules outside of it are placed under `_external` by their paths relative to `root`
+  pub root: Option<String>,
+}
+
+/// An additional output of `output.variants`, the fields that are not set are inherited from `output`
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutputVariantConfig {
+  pub entry_filename: Option<String>,
+  pub filename: Option<String>,
+  pub assets_filename: Option<String>,
+  pub target_env: Option<TargetEnv>,
+  pub format: Option<ModuleFormat>,
+}
+
+impl OutputConfig {
+  /// The output config of the variant, the fields that are not set by the variant are inherited from this config
+  pub fn variant(&self, variant: &OutputVariantConfig) -> OutputConfig {
+    OutputConfig {
+      entry_filename: variant
+        .entry_filename
+        .clone()
+        .unwrap_or_else(|| self.entry_filename.clone()),
+      filename: variant
+        .filename
+        .clone()
+        .unwrap_or_else(|| self.filename.clone()),
+      assets_filename: variant
+        .assets_filename
+        .clone()
+        .unwrap_or_else(|| self.assets_filename.clone()),
+      target_env: variant
+        .target_env
+        .clone()
+        .unwrap_or_else(|| self.target_env.clone()),
+      format: variant.format.unwrap_or(self.format),
+      variants: vec![],
+      ..self.clone()
+    }
+  }
 }
 
 impl Default for OutputConfig {
@@ -26,6 +116,11 @@ impl Default for OutputConfig {
       path: "dist".to_string(),
       target_env: TargetEnv::default(),
       format: ModuleFormat::default(),
+      global_name: None,
+      manifest: BoolOrObj::Bool(false),
+      import_map: BoolOrObj::Bool(false),
+      preserve_modules: BoolOrObj::Bool(false),
+      variants: vec![],
     }
   }
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use super::config_regex::ConfigRegex;
+use crate::module::Module;
+
+use super::{bool_or_obj::BoolOrObj, config_regex::ConfigRegex};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
@@ -11,6 +13,8 @@ pub struct PartialBundlingConfig {
   pub target_min_size: usize,
   /// target max size for every resource loading
   pub target_max_size: usize,
+  /// The size of modules that `targetMinSize` and `targetMaxSize` are compared with, default to `source`
+  pub size_metric: PartialBundlingSizeMetric,
   /// A group of modules that should be placed together.
   /// Note that this group config is only a hit to the compiler that these modules should be placed together,
   /// it may produce multiple resources, if you want to enforce modules in only one resource, you should use `enforceResources`.
@@ -29,6 +33,9 @@ pub struct PartialBundlingConfig {
   /// Default to 0.8, immutable module will have 80% request numbers.
   /// TODO check if it is between 0 and 1
   pub immutable_modules_weight: f32,
+  /// Emit a json report that explains why the modules are placed in their resource pots, the report is also recorded in the stats.
+  /// Disabled by default
+  pub report: BoolOrObj<PartialBundlingReportConfig>,
 }
 
 impl Default for PartialBundlingConfig {
@@ -39,16 +46,59 @@ impl Default for PartialBundlingConfig {
       target_min_size: 1024 * 100,
       // 1.5 MB before minimize and gzip
       target_max_size: 1024 * 1500,
+      size_metric: PartialBundlingSizeMetric::default(),
       groups: vec![],
       enforce_resources: vec![],
       enforce_target_concurrent_requests: false,
       enforce_target_min_size: false,
       immutable_modules: vec![ConfigRegex::default()],
       immutable_modules_weight: 0.8,
+      report: BoolOrObj::Bool(false),
     }
   }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PartialBundlingReportConfig {
+  /// name of the report resource, relative to `output.path`
+  pub filename: String,
+}
+
+impl Default for PartialBundlingReportConfig {
+  fn default() -> Self {
+    Self {
+      filename: "partial-bundling-report.json".to_string(),
+    }
+  }
+}
+
+impl PartialBundlingConfig {
+  /// Size of the module measured by `size_metric`
+  pub fn module_size(&self, module: &Module) -> usize {
+    match self.size_metric {
+      PartialBundlingSizeMetric::Source => module.size,
+      // modules that are not built by the build stage, e.g. external modules, do not have an estimated size
+      PartialBundlingSizeMetric::EstimatedGzip if module.estimated_size > 0 => {
+        module.estimated_size
+      }
+      PartialBundlingSizeMetric::EstimatedGzip => module.size,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PartialBundlingSizeMetric {
+  /// size of the source content before transform and minify
+  #[default]
+  #[serde(rename = "source")]
+  Source,
+  /// estimated size after minify and gzip, computed after the module is transformed.
+  /// It's closer to the size of the generated resources, but costs a codegen and a compression for every module
+  #[serde(rename = "estimatedGzip")]
+  EstimatedGzip,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase", default)]
 pub struct PartialBundlingGroupConfig {
@@ -9,9 +9,12 @@ use swc_common::Globals;
 use crate::{
   cache::CacheManager,
   config::{persistent_cache::PersistentCacheConfig, Config},
-  error::Result,
+  error::{CompilationError, Result},
   module::{
-    module_graph::ModuleGraph, module_group::ModuleGroupGraph, watch_graph::WatchGraph, ModuleId,
+    module_graph::ModuleGraph,
+    module_group::ModuleGroupGraph,
+    watch_graph::{WatchGlob, WatchGraph},
+    ModuleId,
   },
   plugin::{plugin_driver::PluginDriver, Plugin, PluginResolveHookParam, PluginResolveHookResult},
   resource::{resource_pot_map::ResourcePotMap, Resource, ResourceOrigin, ResourceType},
@@ -33,13 +36,17 @@ pub struct CompilationContext {
   pub plugin_driver: Box<PluginDriver>,
   pub resource_pot_map: Box<RwLock<ResourcePotMap>>,
   pub resources_map: Box<Mutex<HashMap<String, Resource>>>,
-  pub cache_manager: Box<CacheManager>,
-  pub meta: Box<ContextMetaData>,
+  pub cache_manager: Arc<CacheManager>,
+  pub meta: Arc<ContextMetaData>,
   /// Record stats for the compilation, for example, compilation time, plugin hook time, etc.
   pub record_manager: Box<Stats>,
   pub log_store: Box<Mutex<LogStore>>,
   pub resolve_cache: Box<Mutex<HashMap<PluginResolveHookParam, PluginResolveHookResult>>>,
   pub custom: Box<DashMap<String, Box<dyn Any + Send + Sync>>>,
+  /// Data uris of the asset modules that are inlined instead of emitted, see `assets.inlineLimit`
+  pub inlined_assets: Box<DashMap<ModuleId, String>>,
+  /// Index of the `output.variants` item that this context generates, `None` for the primary output
+  pub output_variant: Option<usize>,
 }
 
 impl CompilationContext {
@@ -53,20 +60,51 @@ impl CompilationContext {
       resource_pot_map: Box::new(RwLock::new(ResourcePotMap::new())),
       resources_map: Box::new(Mutex::new(HashMap::new())),
       plugin_driver: Box::new(Self::create_plugin_driver(plugins, config.record)),
-      cache_manager: Box::new(CacheManager::new(
+      cache_manager: Arc::new(CacheManager::new(
         &cache_dir,
         &namespace,
         config.mode.clone(),
       )),
       config: Box::new(config),
-      meta: Box::new(ContextMetaData::new()),
+      meta: Arc::new(ContextMetaData::new()),
       record_manager: Box::new(Stats::new()),
       log_store: Box::new(Mutex::new(LogStore::new())),
       resolve_cache: Box::new(Mutex::new(HashMap::new())),
       custom: Box::new(DashMap::new()),
+      inlined_assets: Box::new(DashMap::new()),
+      output_variant: None,
     })
   }
 
+  /// Create the context that generates the `index`th item of `output.variants`.
+  /// The swc globals and the persistent cache are shared with this context, so the module graph built by this context can be generated by the variant
+  pub fn new_output_variant(
+    &self,
+    index: usize,
+    mut config: Config,
+    plugins: Vec<Arc<dyn Plugin>>,
+  ) -> Self {
+    Self::normalize_persistent_cache_config(&mut config);
+
+    Self {
+      watch_graph: Box::new(RwLock::new(WatchGraph::new())),
+      module_graph: Box::new(RwLock::new(ModuleGraph::new())),
+      module_group_graph: Box::new(RwLock::new(ModuleGroupGraph::new())),
+      resource_pot_map: Box::new(RwLock::new(ResourcePotMap::new())),
+      resources_map: Box::new(Mutex::new(HashMap::new())),
+      plugin_driver: Box::new(Self::create_plugin_driver(plugins, config.record)),
+      cache_manager: self.cache_manager.clone(),
+      config: Box::new(config),
+      meta: self.meta.clone(),
+      record_manager: Box::new(Stats::new()),
+      log_store: Box::new(Mutex::new(LogStore::new())),
+      resolve_cache: Box::new(Mutex::new(HashMap::new())),
+      custom: Box::new(DashMap::new()),
+      inlined_assets: Box::new(DashMap::new()),
+      output_variant: Some(index),
+    }
+  }
+
   pub fn set_update(&self) {
     self.custom.insert(IS_UPDATE.to_string(), Box::new(true));
   }
@@ -75,6 +113,32 @@ impl CompilationContext {
     self.custom.contains_key(IS_UPDATE)
   }
 
+  /// Record the data uri of an asset module that is inlined instead of emitted, see `assets.inlineLimit`
+  pub fn set_inlined_asset(&self, module_id: &ModuleId, data_uri: String) {
+    self.inlined_assets.insert(module_id.clone(), data_uri);
+  }
+
+  pub fn remove_inlined_asset(&self, module_id: &ModuleId) {
+    self.inlined_assets.remove(module_id);
+  }
+
+  /// Get the data uri of the asset module if it's inlined
+  pub fn inlined_asset(&self, module_id: &ModuleId) -> Option<String> {
+    self
+      .inlined_assets
+      .get(module_id)
+      .map(|data_uri| data_uri.clone())
+  }
+
+  /// All inlined assets, `(module id, data uri)`
+  pub fn inlined_assets(&self) -> Vec<(ModuleId, String)> {
+    self
+      .inlined_assets
+      .iter()
+      .map(|item| (item.key().clone(), item.value().clone()))
+      .collect()
+  }
+
   pub fn create_plugin_driver(plugins: Vec<Arc<dyn Plugin>>, record: bool) -> PluginDriver {
     PluginDriver::new(plugins, record)
   }
@@ -109,6 +173,19 @@ impl CompilationContext {
     Ok(())
   }
 
+  /// Watch the glob patterns of the module, e.g. `import.meta.glob('./dir/*.ts')`, creating or deleting a matched file
+  /// updates the module
+  pub fn set_watch_globs(&self, module_id: ModuleId, globs: Vec<WatchGlob>) {
+    let mut watch_graph = self.watch_graph.write();
+    watch_graph.set_globs(module_id, globs);
+  }
+
+  /// Watch the candidate files tried for a failed resolution of the importer, creating any of them updates the importer
+  pub fn add_missing_watch_files(&self, importer: ModuleId, files: Vec<String>) {
+    let mut watch_graph = self.watch_graph.write();
+    watch_graph.add_missing_files(importer, files);
+  }
+
   /// get module id from string
   /// 1. if resolved_path is a absolute path, try generate module id from it
   /// 2. if resolved_path is a relative path, treat it as module id
@@ -122,10 +199,25 @@ impl CompilationContext {
     }
   }
 
-  pub fn emit_file(&self, params: EmitFileParams) {
+  /// Emit a resource of the module, fails if a resource of another module or resource pot already has the same name
+  pub fn emit_file(&self, params: EmitFileParams) -> Result<()> {
     let mut resources_map = self.resources_map.lock();
 
     let module_id = self.str_to_module_id(&params.resolved_path);
+    let origin = ResourceOrigin::Module(module_id);
+
+    // a filename template without `[name]` or `[hash]` may generate the same filename for different assets
+    if let Some(existing) = resources_map.get(&params.name) {
+      let (first, second) = (existing.origin.to_string(), origin.to_string());
+
+      if first != second {
+        return Err(CompilationError::OutputFilenameConflict {
+          name: params.name,
+          first,
+          second,
+        });
+      }
+    }
 
     resources_map.insert(
       params.name.clone(),
@@ -134,10 +226,12 @@ impl CompilationContext {
         bytes: params.content,
         emitted: false,
         resource_type: params.resource_type,
-        origin: ResourceOrigin::Module(module_id),
+        origin,
         info: None,
       },
     );
+
+    Ok(())
   }
 
   pub fn sourcemap_enabled(&self, id: &str) -> bool {
@@ -1,5 +1,7 @@
 use std::{error::Error, sync::LockResult};
 
+use serde::{Deserialize, Serialize};
+use swc_common::{SourceMap, Span};
 use thiserror::Error;
 
 use crate::resource::resource_pot::ResourcePotType;
@@ -22,10 +24,18 @@ pub enum CompilationError {
   },
 
   #[error("Transform `{resolved_path}` failed.\nError: {msg}")]
-  TransformError { resolved_path: String, msg: String },
+  TransformError {
+    resolved_path: String,
+    msg: String,
+    loc: Option<Box<ErrorLocation>>,
+  },
   // TODO, give the specific recommended plugin of this kind of module
   #[error("Parse `{resolved_path}` failed.\n Error: {msg}\nPotential Causes:\n1.The module have syntax error.\n2.This kind of module is not supported, you may need plugins to support it\n")]
-  ParseError { resolved_path: String, msg: String },
+  ParseError {
+    resolved_path: String,
+    msg: String,
+    loc: Option<Box<ErrorLocation>>,
+  },
 
   #[error("Hook `process_module` execute failed for module `{resolved_path}`.\nOriginal error: {source:?}.")]
   ProcessModuleError {
@@ -83,12 +93,236 @@ pub enum CompilationError {
     source: Option<Box<dyn Error + Send + Sync>>,
   },
 
+  #[error("Invalid output filename template `{template}`: {msg}")]
+  InvalidOutputFilenameTemplate { template: String, msg: String },
+
+  #[error("Output filename `{name}` is generated by both `{first}` and `{second}`. Make sure the filename templates(`output.filename`, `output.entryFilename` or `output.assetsFilename`) contain `[name]` or `[hash]` so that each resource gets a unique filename")]
+  OutputFilenameConflict {
+    name: String,
+    first: String,
+    second: String,
+  },
+
+  #[error("Output filename `{name}` of `output.variants[{index}]` is already generated by another output. Make sure the filename templates of the variants contain `[format]` or `[target]`, or use different extensions")]
+  OutputVariantFilenameConflict { name: String, index: usize },
+
+  #[error("Resource pot `{id}` is generated more than once. Make sure the entry names, the names of `partialBundling.enforceResources` and the chunk names of the dynamic imports(`webpackChunkName` or `farmChunkName`) are different")]
+  ResourcePotConflict { id: String },
+
   #[error("generate sourcemap for module `{id}` failed")]
   GenerateSourceMapError {
     id: String,
     // #[source]
     // source: Option<Box<dyn Error + Send + Sync>>,
   },
+
+  /// Errors of the build stage reported at once when `compilation.continueOnError` is enabled, see
+  /// [CompilationError::multiple]. Displayed as a json array of the messages like the errors of the fail-fast build
+  #[error("{}", CompilationError::messages_json(.0))]
+  Multiple(Vec<CompilationError>),
+}
+
+impl CompilationError {
+  /// Aggregate the errors into [CompilationError::Multiple], nested [CompilationError::Multiple] are flattened.
+  /// The errors are sorted by the module they belong to and then by the message, so the order does not depend on
+  /// the order in which the modules are built
+  pub fn multiple(errors: Vec<CompilationError>) -> Self {
+    let mut errors = errors
+      .into_iter()
+      .flat_map(|error| match error {
+        CompilationError::Multiple(errors) => errors,
+        error => vec![error],
+      })
+      .map(|error| {
+        let key = (
+          error.module_path().is_none(),
+          error.module_path().unwrap_or_default().to_string(),
+          error.to_string(),
+        );
+        (key, error)
+      })
+      .collect::<Vec<_>>();
+    errors.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    CompilationError::Multiple(errors.into_iter().map(|(_, error)| error).collect())
+  }
+
+  /// Json array of the messages of the errors. The message of an error that has a location is the json string of
+  /// [SerializedCompilationError] so the location and the code frame are kept through the node bindings
+  pub fn messages_json(errors: &[CompilationError]) -> String {
+    let messages = errors
+      .iter()
+      .map(|e| {
+        if e.loc().is_some() {
+          serde_json::to_string(&e.to_serialized()).unwrap()
+        } else {
+          e.to_string()
+        }
+      })
+      .collect::<Vec<_>>();
+    serde_json::to_string(&messages).unwrap()
+  }
+
+  /// Structured location of parse and transform errors
+  pub fn loc(&self) -> Option<&ErrorLocation> {
+    match self {
+      CompilationError::TransformError { loc, .. } | CompilationError::ParseError { loc, .. } => {
+        loc.as_deref()
+      }
+      _ => None,
+    }
+  }
+
+  /// See [SerializedCompilationError]
+  pub fn to_serialized(&self) -> SerializedCompilationError {
+    let loc = self.loc().cloned();
+
+    SerializedCompilationError {
+      message: self.to_string(),
+      id: self.module_path().map(|p| p.to_string()),
+      frame: loc.as_ref().map(|loc| loc.frame.clone()),
+      loc,
+    }
+  }
+
+  /// The path or id of the module that the error belongs to. For resolve errors it's the importer
+  pub fn module_path(&self) -> Option<&str> {
+    match self {
+      CompilationError::ResolveError { importer, .. } => Some(importer),
+      CompilationError::LoadError { resolved_path, .. }
+      | CompilationError::TransformError { resolved_path, .. }
+      | CompilationError::ParseError { resolved_path, .. }
+      | CompilationError::ProcessModuleError { resolved_path, .. }
+      | CompilationError::AnalyzeDepsError { resolved_path, .. } => Some(resolved_path),
+      CompilationError::RenderScriptModuleError { id, .. }
+      | CompilationError::GenerateSourceMapError { id } => Some(id),
+      _ => None,
+    }
+  }
+}
+
+/// Line and column in a source file, both are 1-based like editors
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorPosition {
+  pub line: usize,
+  pub column: usize,
+}
+
+/// Location of an error in the source file, converted from the swc span of the error
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorLocation {
+  pub file: String,
+  pub line: usize,
+  pub column: usize,
+  /// end of the span, exclusive
+  pub end: ErrorPosition,
+  /// kind of the error, e.g. `TS1005` or `Expected` for script syntax errors
+  pub code: Option<String>,
+  /// lines around the error, the error lines are marked by `>` and the span is underlined by `^`
+  pub frame: String,
+}
+
+impl ErrorLocation {
+  /// Returns [None] if the span is dummy or not in `cm`
+  pub fn from_span(cm: &SourceMap, span: Span, code: Option<String>) -> Option<Self> {
+    if span.is_dummy() {
+      return None;
+    }
+
+    let start = cm.try_lookup_char_pos(span.lo).ok()?;
+    let end = cm.try_lookup_char_pos(span.hi).ok()?;
+    let start_position = ErrorPosition {
+      line: start.line,
+      column: start.col.0 + 1,
+    };
+    let end_position = ErrorPosition {
+      line: end.line,
+      column: end.col.0 + 1,
+    };
+    let frame = render_code_frame(&start.file.src, &start_position, &end_position);
+
+    Some(Self {
+      file: start.file.name.to_string(),
+      line: start_position.line,
+      column: start_position.column,
+      end: end_position,
+      code,
+      frame,
+    })
+  }
+}
+
+/// Lines of code frame rendered before and after the error lines
+const CODE_FRAME_CONTEXT_LINES: usize = 2;
+
+/// Render the code frame like babel, for example:
+/// ```text
+///   1 | import { a } from './a';
+/// > 2 | export const b = (;
+///     |                   ^
+///   3 | console.log(a);
+/// ```
+fn render_code_frame(src: &str, start: &ErrorPosition, end: &ErrorPosition) -> String {
+  let lines = src.lines().collect::<Vec<_>>();
+  let first_line = start.line.saturating_sub(CODE_FRAME_CONTEXT_LINES).max(1);
+  let last_line = (end.line + CODE_FRAME_CONTEXT_LINES).min(lines.len());
+  let gutter_width = last_line.to_string().len();
+  let mut frame = vec![];
+
+  for (index, line) in lines
+    .iter()
+    .enumerate()
+    .take(last_line)
+    .skip(first_line - 1)
+  {
+    let line_number = index + 1;
+    let is_error_line = line_number >= start.line && line_number <= end.line;
+    let marker = if is_error_line { ">" } else { " " };
+    frame.push(
+      format!("{marker} {line_number:>gutter_width$} | {line}")
+        .trim_end()
+        .to_string(),
+    );
+
+    if is_error_line {
+      let chars = line.chars().collect::<Vec<_>>();
+      let start_column = if line_number == start.line {
+        start.column
+      } else {
+        1
+      };
+      let end_column = if line_number == end.line {
+        end.column
+      } else {
+        chars.len() + 1
+      };
+      // keep the tabs so that the underline is aligned with the code
+      let padding = chars
+        .iter()
+        .take(start_column - 1)
+        .map(|c| if *c == '\t' { '\t' } else { ' ' })
+        .collect::<String>();
+      let underline = "^".repeat(end_column.saturating_sub(start_column).max(1));
+      frame.push(format!("  {:gutter_width$} | {padding}{underline}", ""));
+    }
+  }
+
+  frame.join("\n")
+}
+
+/// Json representation of a [CompilationError] for the node bindings and the stats. It's compatible with the rollup
+/// error that the logger and the error overlay of `@farmfe/core` understand
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerializedCompilationError {
+  pub message: String,
+  /// path or id of the module that the error belongs to
+  pub id: Option<String>,
+  pub loc: Option<ErrorLocation>,
+  /// same as the frame of `loc`
+  pub frame: Option<String>,
 }
 
 pub type Result<T> = core::result::Result<T, CompilationError>;
@@ -132,3 +366,46 @@ impl<T> MapCompletionError<T> for LockResult<T> {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use swc_common::{BytePos, FileName, SourceMap, Span};
+
+  use super::{ErrorLocation, ErrorPosition};
+
+  #[test]
+  fn error_location_from_span() {
+    let cm = SourceMap::default();
+    let src = "import { a } from './a';\nexport const b = (;\nconsole.log(a);\n";
+    let file = cm.new_source_file(FileName::Real(PathBuf::from("/root/b.ts")), src.to_string());
+    let lo = file.start_pos + BytePos(src.find('(').unwrap() as u32);
+    let span = Span::new(lo, lo + BytePos(2), Default::default());
+
+    let loc = ErrorLocation::from_span(&cm, span, Some("Expected".to_string())).unwrap();
+
+    assert_eq!(loc.file, "/root/b.ts");
+    assert_eq!((loc.line, loc.column), (2, 18));
+    assert_eq!(
+      loc.end,
+      ErrorPosition {
+        line: 2,
+        column: 20
+      }
+    );
+    assert_eq!(loc.code, Some("Expected".to_string()));
+    assert_eq!(
+      loc.frame,
+      [
+        "  1 | import { a } from './a';",
+        "> 2 | export const b = (;",
+        "    |                  ^^",
+        "  3 | console.log(a);",
+      ]
+      .join("\n")
+    );
+
+    assert!(ErrorLocation::from_span(&cm, Span::default(), None).is_none());
+  }
+}
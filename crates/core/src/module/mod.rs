@@ -25,7 +25,7 @@ use swc_css_ast::Stylesheet;
 use swc_ecma_ast::Module as SwcModule;
 use swc_html_ast::Document;
 
-use crate::{config::Mode, resource::resource_pot::ResourcePotId};
+use crate::{config::Mode, error::CompilationError, resource::resource_pot::ResourcePotId};
 
 use self::module_group::ModuleGroupId;
 
@@ -63,10 +63,15 @@ pub struct Module {
   pub execution_order: usize,
   /// Source size of this module
   pub size: usize,
+  /// Estimated size of this module after minify and gzip, only computed when `partialBundling.sizeMetric` is `estimatedGzip`
+  pub estimated_size: usize,
   /// Source content after load and transform
   pub content: Arc<String>,
   /// Used exports of this module. Set by the tree-shake plugin
   pub used_exports: Vec<String>,
+  /// Magic comments of the dynamic imports of this module keyed by the import source, e.g. `webpackChunkName`.
+  /// Set by the script plugin
+  pub dynamic_import_magic_comments: HashMap<String, HashMap<String, String>>,
   /// last update timestamp
   pub last_update_timestamp: u128,
   /// content(after load and transform) hash
@@ -75,6 +80,11 @@ pub struct Module {
   pub package_name: String,
   /// package version of this module
   pub package_version: String,
+  /// The error of the last build of this module when `compilation.errorTolerant` is enabled. The module keeps the meta
+  /// and dependencies of the last successful build, or throws the error when executed if it never built successfully.
+  /// The error is never cached
+  #[with(rkyv::with::Skip)]
+  pub error: Option<Arc<CompilationError>>,
 
   // custom meta map
   pub custom: HashMap<String, Box<dyn SerializeCustomModuleMetaData>>,
@@ -107,12 +117,15 @@ impl Clone for Module {
       immutable: self.immutable,
       execution_order: self.execution_order,
       size: self.size,
+      estimated_size: self.estimated_size,
       content: self.content.clone(),
       used_exports: self.used_exports.clone(),
+      dynamic_import_magic_comments: self.dynamic_import_magic_comments.clone(),
       last_update_timestamp: self.last_update_timestamp,
       content_hash: self.content_hash.clone(),
       package_name: self.package_name.clone(),
       package_version: self.package_version.clone(),
+      error: self.error.clone(),
       custom,
     }
   }
@@ -133,15 +146,23 @@ impl Module {
       // default to the last
       execution_order: usize::MAX,
       size: 0,
+      estimated_size: 0,
       content: Arc::new("".to_string()),
       used_exports: vec![],
+      dynamic_import_magic_comments: HashMap::new(),
       last_update_timestamp: 0,
       content_hash: "".to_string(),
       package_name: "".to_string(),
       package_version: "".to_string(),
+      error: None,
       custom: HashMap::new(),
     }
   }
+
+  /// Whether the module failed to build and is kept in the module graph as an error module
+  pub fn is_errored(&self) -> bool {
+    self.error.is_some()
+  }
 }
 
 /// Module meta data shared by core plugins through the compilation
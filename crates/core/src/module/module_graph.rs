@@ -72,6 +72,18 @@ impl ModuleGraphEdge {
     self.0.iter().all(|item| item.kind.is_dynamic())
   }
 
+  // true if all of the edge data items are assets referenced by html, e.g. `<img src="./a.png" />`
+  pub fn is_html_asset(&self) -> bool {
+    if self.0.is_empty() {
+      return false;
+    }
+
+    self
+      .0
+      .iter()
+      .all(|item| matches!(item.kind, ResolveKind::HtmlAsset))
+  }
+
   pub fn contains_export_from(&self) -> bool {
     if self.0.is_empty() {
       return false;
@@ -101,6 +113,7 @@ impl ModuleGraphEdge {
   }
 }
 
+#[derive(Clone)]
 pub struct ModuleGraph {
   /// internal graph
   g: StableDiGraph<Module, ModuleGraphEdge>,
@@ -342,7 +342,7 @@ impl ModuleGroup {
       let a_order = resource_pots_order_map.get(a).unwrap_or(&0);
       let b_order = resource_pots_order_map.get(b).unwrap_or(&0);
 
-      a_order.cmp(b_order)
+      a_order.cmp(b_order).then_with(|| a.cmp(b))
     });
 
     sorted_resource_pots
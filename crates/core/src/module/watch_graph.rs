@@ -1,5 +1,8 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::Hash;
+use std::path::Path;
+
+use farmfe_macro_cache_item::cache_item;
 
 use petgraph::{
   graph::{DefaultIx, NodeIndex},
@@ -7,6 +10,8 @@ use petgraph::{
   EdgeDirection,
 };
 
+use wax::{Glob, Pattern};
+
 use crate::error::{CompilationError, Result};
 
 use super::ModuleId;
@@ -34,10 +39,49 @@ impl EdgeMode {
   }
 }
 
+/// A glob pattern relative to a directory, e.g. `**/*.ts` of `/root/src`
+#[cache_item]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WatchGlobPattern {
+  /// absolute path of the directory, the directory is watched
+  pub base: String,
+  /// wax glob pattern relative to `base`
+  pub pattern: String,
+}
+
+impl WatchGlobPattern {
+  pub fn is_match(&self, path: &str) -> bool {
+    let Ok(relative_path) = Path::new(path).strip_prefix(&self.base) else {
+      return false;
+    };
+
+    Glob::new(&self.pattern).is_ok_and(|glob| glob.is_match(relative_path))
+  }
+}
+
+/// Files matched by glob patterns of a module, e.g. `import.meta.glob(['./dir/*.ts', '!./dir/foo.ts'])`.
+/// Creating or deleting a matched file updates the module
+#[cache_item]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WatchGlob {
+  pub includes: Vec<WatchGlobPattern>,
+  pub excludes: Vec<WatchGlobPattern>,
+}
+
+impl WatchGlob {
+  pub fn is_match(&self, path: &str) -> bool {
+    self.includes.iter().any(|p| p.is_match(path))
+      && !self.excludes.iter().any(|p| p.is_match(path))
+  }
+}
+
 #[derive(Default)]
 pub struct WatchGraph {
   g: StableDiGraph<ModuleId, EdgeMode>,
   id_index_map: HashMap<ModuleId, NodeIndex<DefaultIx>>,
+  globs: HashMap<ModuleId, Vec<WatchGlob>>,
+  /// absolute path of a missing file -> modules that failed to resolve an import because the file does not exist
+  missing_files: HashMap<String, HashSet<ModuleId>>,
 }
 
 impl WatchGraph {
@@ -45,6 +89,8 @@ impl WatchGraph {
     Self {
       g: StableDiGraph::new(),
       id_index_map: HashMap::new(),
+      globs: HashMap::new(),
+      missing_files: HashMap::new(),
     }
   }
 
@@ -192,7 +238,75 @@ impl WatchGraph {
     self.id_index_map.contains_key(module_id)
   }
 
+  /// Replace the glob patterns watched by the module
+  pub fn set_globs(&mut self, module_id: ModuleId, globs: Vec<WatchGlob>) {
+    if globs.is_empty() {
+      self.globs.remove(&module_id);
+    } else {
+      self.globs.insert(module_id, globs);
+    }
+  }
+
+  pub fn globs(&self, module_id: &ModuleId) -> Vec<&WatchGlob> {
+    self
+      .globs
+      .get(module_id)
+      .map(|globs| globs.iter().collect())
+      .unwrap_or_default()
+  }
+
+  /// Modules whose glob patterns match the absolute `path`, they should be updated when `path` is created or deleted
+  pub fn glob_roots(&self, path: &str) -> Vec<&ModuleId> {
+    self
+      .globs
+      .iter()
+      .filter(|(_, globs)| globs.iter().any(|glob| glob.is_match(path)))
+      .map(|(module_id, _)| module_id)
+      .collect()
+  }
+
+  /// Absolute paths of the directories that the glob patterns are relative to
+  pub fn glob_dirs(&self) -> Vec<&String> {
+    let mut res = HashSet::new();
+
+    for glob in self.globs.values().flatten() {
+      res.extend(glob.includes.iter().map(|p| &p.base));
+    }
+
+    res.into_iter().collect()
+  }
+
+  /// Record the candidate files tried for a failed resolution of the importer, creating any of them updates the importer
+  pub fn add_missing_files(&mut self, importer: ModuleId, files: Vec<String>) {
+    for file in files {
+      self
+        .missing_files
+        .entry(file)
+        .or_default()
+        .insert(importer.clone());
+    }
+  }
+
+  /// Modules that failed to resolve an import because the absolute `path` did not exist
+  pub fn missing_file_roots(&self, path: &str) -> Vec<&ModuleId> {
+    self
+      .missing_files
+      .get(path)
+      .map(|importers| importers.iter().collect())
+      .unwrap_or_default()
+  }
+
+  pub fn missing_files(&self) -> Vec<&String> {
+    self.missing_files.keys().collect()
+  }
+
   pub fn delete_module(&mut self, module_id: &ModuleId) {
+    self.globs.remove(module_id);
+    self.missing_files.retain(|_, importers| {
+      importers.remove(module_id);
+      !importers.is_empty()
+    });
+
     if !self.id_index_map.contains_key(module_id) {
       return;
     }
@@ -214,7 +328,7 @@ mod tests {
 
   use crate::module::ModuleId;
 
-  use super::WatchGraph;
+  use super::{WatchGlob, WatchGlobPattern, WatchGraph};
   ///```md
   ///    a            a          v_c
   ///      \        /   \        /
@@ -277,4 +391,79 @@ mod tests {
     r.sort();
     assert_eq!(r, [&"v_c".into(), &"v_d".into()])
   }
+
+  #[test]
+  fn glob_roots() {
+    let mut watch_graph = create_watch_graph_instance();
+    let pattern = |base: &str, pattern: &str| WatchGlobPattern {
+      base: base.to_string(),
+      pattern: pattern.to_string(),
+    };
+    watch_graph.set_globs(
+      "a".into(),
+      vec![WatchGlob {
+        includes: vec![pattern("/root/src/pages", "**/*.ts")],
+        excludes: vec![pattern("/root/src/pages", "ignored.ts")],
+      }],
+    );
+    watch_graph.set_globs(
+      "b".into(),
+      vec![WatchGlob {
+        includes: vec![pattern("/root/src", "*.ts")],
+        excludes: vec![],
+      }],
+    );
+
+    assert_eq!(
+      watch_graph.glob_roots("/root/src/pages/home/index.ts"),
+      vec![&"a".into()]
+    );
+    assert_eq!(
+      watch_graph.glob_roots("/root/src/pages/ignored.ts"),
+      Vec::<&ModuleId>::new()
+    );
+    assert_eq!(
+      watch_graph.glob_roots("/root/src/index.ts"),
+      vec![&"b".into()]
+    );
+    assert_eq!(
+      watch_graph.glob_roots("/root/src/index.css"),
+      Vec::<&ModuleId>::new()
+    );
+
+    let mut dirs = watch_graph.glob_dirs();
+    dirs.sort();
+    assert_eq!(dirs, vec!["/root/src", "/root/src/pages"]);
+
+    watch_graph.delete_module(&"b".into());
+    assert_eq!(
+      watch_graph.glob_roots("/root/src/index.ts"),
+      Vec::<&ModuleId>::new()
+    );
+  }
+
+  #[test]
+  fn missing_file_roots() {
+    let mut watch_graph = create_watch_graph_instance();
+    watch_graph.add_missing_files(
+      "a".into(),
+      vec!["/root/Foo".to_string(), "/root/Foo.tsx".to_string()],
+    );
+    watch_graph.add_missing_files("b".into(), vec!["/root/Foo.tsx".to_string()]);
+
+    assert_eq!(
+      watch_graph.missing_file_roots("/root/Foo"),
+      vec![&"a".into()]
+    );
+    let mut r = watch_graph.missing_file_roots("/root/Foo.tsx");
+    r.sort();
+    assert_eq!(r, [&"a".into(), &"b".into()]);
+
+    watch_graph.delete_module(&"a".into());
+    assert_eq!(
+      watch_graph.missing_file_roots("/root/Foo"),
+      Vec::<&ModuleId>::new()
+    );
+    assert_eq!(watch_graph.missing_files(), vec!["/root/Foo.tsx"]);
+  }
 }
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
   config::Config,
   context::CompilationContext,
-  error::Result,
+  error::{ErrorLocation, Result},
   module::{
     module_graph::ModuleGraph, module_group::ModuleGroupGraph, Module, ModuleId, ModuleMetaData,
     ModuleType,
@@ -186,6 +186,16 @@ pub trait Plugin: Any + Send + Sync {
     Ok(None)
   }
 
+  /// Customize the output filename of a resource. The returned string is used as the filename template
+  /// instead of `output.filename`, `output.entryFilename` or `output.assetsFilename`, so placeholders like `[hash]` still work
+  fn output_filename(
+    &self,
+    _params: &PluginOutputFilenameHookParams,
+    _context: &Arc<CompilationContext>,
+  ) -> Result<Option<String>> {
+    Ok(None)
+  }
+
   /// Optimize the resource pot, for example, minimize
   fn optimize_resource_pot(
     &self,
@@ -309,6 +319,8 @@ pub enum ResolveKind {
   HmrUpdate,
   /// Custom ResolveKind, e.g. `const worker = new Worker(new Url("worker.js"))` of a web worker
   Custom(String),
+  /// assets referenced by html, e.g. `<img src="./a.png" />`, `srcset` and inline `style="background: url(./a.png)"`
+  HtmlAsset,
 }
 
 impl ResolveKind {
@@ -528,7 +540,18 @@ pub struct UpdateResult {
   pub boundaries: HashMap<String, Vec<Vec<String>>>,
   pub dynamic_resources_map: Option<HashMap<ModuleId, Vec<(String, ResourceType)>>>,
   pub extra_watch_result: WatchDiffResult,
+  /// Modules that failed to build and are kept as error modules, see `compilation.errorTolerant`
+  pub errored_modules: Vec<ErroredModule>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErroredModule {
+  pub module_id: ModuleId,
+  pub message: String,
+  pub loc: Option<ErrorLocation>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum UpdateType {
   // added a new module
@@ -586,6 +609,48 @@ pub struct PluginDriverRenderResourcePotHookResult {
   pub source_map_chain: Vec<Arc<String>>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputFilenameKind {
+  Entry,
+  Chunk,
+  Asset,
+}
+
+/// Values of the placeholders of the output filename template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginOutputFilenameHookParams {
+  pub kind: OutputFilenameKind,
+  /// `[name]` or `[resourceName]`
+  pub name: String,
+  /// `[entryName]`, only available for entry resources
+  pub entry_name: Option<String>,
+  /// `[ext]`
+  pub ext: String,
+  /// `[dir]`, directory of the source file relative to root, only available for assets. Files outside of root are placed
+  /// under `_external` with `..` replaced by `_`
+  pub dir: Option<String>,
+  /// `[format]`, `esm` or `cjs`
+  pub format: String,
+  /// `[target]`, value of `output.targetEnv`
+  pub target: String,
+}
+
+impl PluginOutputFilenameHookParams {
+  pub fn new(kind: OutputFilenameKind, name: &str, ext: &str, config: &Config) -> Self {
+    Self {
+      kind,
+      name: name.to_string(),
+      entry_name: None,
+      ext: ext.to_string(),
+      dir: None,
+      format: config.output.format.as_str().to_string(),
+      target: config.output.target_env.as_str().to_string(),
+    }
+  }
+}
+
 pub struct PluginFinalizeResourcesHookParams<'a> {
   pub resources_map: &'a mut HashMap<String, Resource>,
   pub config: &'a Config,
@@ -7,9 +7,9 @@ use super::{
   PluginFinalizeModuleHookParam, PluginFinalizeResourcesHookParams,
   PluginGenerateResourcesHookResult, PluginHandleEntryResourceHookParams, PluginHookContext,
   PluginLoadHookParam, PluginLoadHookResult, PluginModuleGraphUpdatedHookParams,
-  PluginParseHookParam, PluginProcessModuleHookParam, PluginRenderResourcePotHookParam,
-  PluginResolveHookParam, PluginResolveHookResult, PluginTransformHookParam,
-  PluginUpdateModulesHookParams,
+  PluginOutputFilenameHookParams, PluginParseHookParam, PluginProcessModuleHookParam,
+  PluginRenderResourcePotHookParam, PluginResolveHookParam, PluginResolveHookResult,
+  PluginTransformHookParam, PluginUpdateModulesHookParams,
 };
 use crate::{
   config::Config,
@@ -755,6 +755,13 @@ impl PluginDriver {
     }
   );
 
+  hook_first!(
+    output_filename,
+    Result<Option<String>>,
+    params: &PluginOutputFilenameHookParams,
+    context: &Arc<CompilationContext>
+  );
+
   hook_first!(
     generate_resources,
     Result<Option<PluginGenerateResourcesHookResult>>,
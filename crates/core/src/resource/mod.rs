@@ -98,20 +98,32 @@ pub enum ResourceOrigin {
   ResourcePot(ResourcePotId),
   // The resource is generated by a Module. Usually by static assets like images.
   Module(ModuleId),
+  // The resource is derived from another resource, for example, the compressed variants. The value is the name of the origin resource.
+  Resource(String),
 }
 
 impl ResourceOrigin {
   pub fn as_resource_pot(&self) -> &ResourcePotId {
     match self {
       ResourceOrigin::ResourcePot(id) => id,
-      ResourceOrigin::Module(_) => panic!("ResourceOrigin is not ResourceOrigin::ResourcePot"),
+      _ => panic!("ResourceOrigin is not ResourceOrigin::ResourcePot"),
     }
   }
 
   pub fn as_module(&self) -> &ModuleId {
     match self {
-      ResourceOrigin::ResourcePot(_) => panic!("ResourceOrigin is not ResourceOrigin::Module"),
       ResourceOrigin::Module(id) => id,
+      _ => panic!("ResourceOrigin is not ResourceOrigin::Module"),
+    }
+  }
+}
+
+impl std::fmt::Display for ResourceOrigin {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ResourceOrigin::ResourcePot(id) => write!(f, "resource pot {id}"),
+      ResourceOrigin::Module(id) => write!(f, "module {}", id.to_string()),
+      ResourceOrigin::Resource(name) => write!(f, "resource {name}"),
     }
   }
 }
@@ -85,8 +85,11 @@ impl ResourcePot {
     self.resources.insert(name);
   }
 
+  /// Resource names of this pot, sorted so callers emit them in a stable order.
   pub fn resources(&self) -> Vec<&String> {
-    self.resources.iter().collect()
+    let mut resources = self.resources.iter().collect::<Vec<_>>();
+    resources.sort();
+    resources
   }
 
   pub fn remove_resource(&mut self, name: &String) {
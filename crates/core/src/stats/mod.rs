@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use parking_lot::RwLock;
 
 use crate::{
+  error::{CompilationError, SerializedCompilationError},
   module::{
     module_graph::{ModuleGraph, ModuleGraphEdge},
     ModuleId, ModuleType,
@@ -111,11 +112,70 @@ impl Stats {
     })
   }
 
+  pub fn set_css_purge_stats(&self, css_purge_stats: CssPurgeStats) {
+    handle_compilation_stats!(self, |compilation_stats: &mut CompilationStats| {
+      compilation_stats.css_purge_stats = Some(css_purge_stats);
+    })
+  }
+
+  pub fn set_compression_stats(&self, compression_stats: CompressionStats) {
+    handle_compilation_stats!(self, |compilation_stats: &mut CompilationStats| {
+      compilation_stats.compression_stats = Some(compression_stats);
+    })
+  }
+
+  pub fn set_partial_bundling_stats(&self, partial_bundling_stats: PartialBundlingStats) {
+    handle_compilation_stats!(self, |compilation_stats: &mut CompilationStats| {
+      compilation_stats.partial_bundling_stats = Some(partial_bundling_stats);
+    })
+  }
+
+  /// Update the partial bundling stats of the current compilation, the stats are created if not recorded yet
+  pub fn update_partial_bundling_stats(&self, f: impl FnOnce(&mut PartialBundlingStats)) {
+    handle_compilation_stats!(self, |compilation_stats: &mut CompilationStats| {
+      f(compilation_stats
+        .partial_bundling_stats
+        .get_or_insert_with(Default::default))
+    })
+  }
+
+  pub fn partial_bundling_stats(&self) -> Option<PartialBundlingStats> {
+    handle_compilation_stats!(self, |compilation_stats: &mut CompilationStats| {
+      compilation_stats.partial_bundling_stats.clone()
+    })
+  }
+
+  /// Record the errors of the compilation, [CompilationError::Multiple] is flattened
+  pub fn set_errors(&self, errors: &[CompilationError]) {
+    let errors = errors
+      .iter()
+      .flat_map(|error| match error {
+        CompilationError::Multiple(errors) => errors.iter().collect(),
+        error => vec![error],
+      })
+      .map(|error| error.to_serialized())
+      .collect();
+
+    handle_compilation_stats!(self, |compilation_stats: &mut CompilationStats| {
+      compilation_stats.errors = errors;
+    })
+  }
+
   pub fn set_entries(&self, entries: Vec<ModuleId>) {
     handle_compilation_stats!(self, |compilation_stats: &mut CompilationStats| {
       compilation_stats.entries = entries;
     })
   }
+
+  /// Merge the stats recorded when generating an `output.variants` item into the current compilation.
+  /// The stats are taken from the variant, so the stats of the next generation are merged again
+  pub fn merge_output_variant_stats(&self, variant: &Stats) {
+    let variant_stats = std::mem::take(&mut *variant.initial_compilation_flow_stats.write());
+
+    handle_compilation_stats!(self, |compilation_stats: &mut CompilationStats| {
+      compilation_stats.merge_output_variant_stats(variant_stats)
+    })
+  }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Default)]
@@ -124,6 +184,14 @@ pub struct CompilationStats {
   pub entries: Vec<ModuleId>,
   pub hook_stats_map: HashMap<String, Vec<CompilationPluginHookStats>>,
   pub module_graph_stats: CompilationModuleGraphStats,
+  /// Removed rules and bytes of `css.purge`, [None] if css purge is disabled
+  pub css_purge_stats: Option<CssPurgeStats>,
+  /// Sizes of the compressed resources, [None] if compression is disabled
+  pub compression_stats: Option<CompressionStats>,
+  /// Why the modules are placed in their resource pots, [None] if `partialBundling.report` is disabled
+  pub partial_bundling_stats: Option<PartialBundlingStats>,
+  /// Errors that failed the compilation
+  pub errors: Vec<SerializedCompilationError>,
   pub duration: u128,
   pub start_time: u128,
   pub build_end_time: u128,
@@ -168,6 +236,44 @@ impl CompilationStats {
   pub fn set_module_graph_stats(&mut self, module_graph: &ModuleGraph) {
     self.module_graph_stats = module_graph.into();
   }
+
+  /// The module graph, the css purge stats and the placement of the modules are the same for all the outputs,
+  /// only the hook stats and the stats of the resources are merged
+  pub fn merge_output_variant_stats(&mut self, variant_stats: CompilationStats) {
+    for (hook_name, hook_stats) in variant_stats.hook_stats_map {
+      self
+        .hook_stats_map
+        .entry(hook_name)
+        .or_default()
+        .extend(hook_stats);
+    }
+
+    if let Some(compression_stats) = variant_stats.compression_stats {
+      self
+        .compression_stats
+        .get_or_insert_with(Default::default)
+        .resources
+        .extend(compression_stats.resources);
+    }
+
+    if let Some(partial_bundling_stats) = variant_stats.partial_bundling_stats {
+      let stats = self
+        .partial_bundling_stats
+        .get_or_insert_with(Default::default);
+
+      for (id, resource_pot_stats) in partial_bundling_stats.resource_pots {
+        stats
+          .resource_pots
+          .entry(id)
+          .or_insert_with(|| PartialBundlingResourcePotStats {
+            resources: vec![],
+            ..resource_pot_stats.clone()
+          })
+          .resources
+          .extend(resource_pot_stats.resources);
+      }
+    }
+  }
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -226,3 +332,98 @@ pub struct CompilationModuleStats {
   pub module_id: ModuleId,
   pub module_type: ModuleType,
 }
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CssPurgeStats {
+  pub removed_rules: usize,
+  pub removed_bytes: usize,
+  pub modules: HashMap<ModuleId, CssPurgeModuleStats>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CssPurgeModuleStats {
+  pub removed_rules: usize,
+  pub removed_bytes: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompressionStats {
+  /// key: name of the original resource
+  pub resources: HashMap<String, CompressedResourceStats>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompressedResourceStats {
+  pub size: usize,
+  pub gzip_size: Option<usize>,
+  pub brotli_size: Option<usize>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialBundlingStats {
+  pub modules: HashMap<ModuleId, PartialBundlingModuleStats>,
+  /// key: resource pot id
+  pub resource_pots: HashMap<String, PartialBundlingResourcePotStats>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialBundlingModuleStats {
+  /// id of the resource pot that the module is placed in
+  pub resource_pot: String,
+  /// size of the module measured by `partialBundling.sizeMetric`
+  pub size: usize,
+  /// the module bucket is keyed by the module type, immutable and the module groups of the module
+  pub module_bucket: Option<String>,
+  /// name of the module pot that the module is grouped into before merging, it's the matched group name,
+  /// the immutable package or the module itself
+  pub module_pot: Option<String>,
+  /// name of the matched `partialBundling.groups` item
+  pub group: Option<String>,
+  /// name of the matched `partialBundling.enforceResources` item
+  pub enforce_resource: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialBundlingResourcePotStats {
+  /// the module group that the resource pot is generated for, [None] for the resource pots of `enforceResources`
+  pub module_group: Option<ModuleId>,
+  pub size: usize,
+  /// how the resource pot is generated, in order. The steps of the merged resource pots come first
+  pub merge_history: Vec<PartialBundlingMergeStep>,
+  /// names of the resources generated by the resource pot, filled after the resources are generated
+  pub resources: Vec<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "reason", rename_all = "camelCase")]
+pub enum PartialBundlingMergeStep {
+  /// module pots of the same module bucket are merged until the target size of the module group is reached
+  #[serde(rename_all = "camelCase")]
+  TargetSize {
+    target_size: usize,
+    module_pots: Vec<String>,
+  },
+  /// resource pots smaller than `targetMinSize` are merged when `enforceTargetMinSize` is true
+  #[serde(rename_all = "camelCase")]
+  EnforceTargetMinSize {
+    target_min_size: usize,
+    resource_pots: Vec<String>,
+  },
+  /// the smallest resource pots are merged to meet `targetConcurrentRequests` when `enforceTargetConcurrentRequests` is true
+  #[serde(rename_all = "camelCase")]
+  EnforceTargetConcurrentRequests {
+    target_concurrent_requests: usize,
+    resource_pots: Vec<String>,
+  },
+  /// all the modules matched by the `enforceResources` item are placed together
+  EnforceResource { name: String },
+  /// the modules of the dynamic imports marked by the same `farmChunkName`/`webpackChunkName` magic comment are placed together
+  ChunkName { name: String },
+}
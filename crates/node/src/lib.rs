@@ -3,7 +3,11 @@
 #![allow(clippy::blocks_in_conditions)]
 #[cfg(feature = "file_watcher")]
 use std::path::PathBuf;
-use std::{collections::HashMap, path::Path, sync::Arc};
+use std::{
+  collections::{HashMap, HashSet},
+  path::Path,
+  sync::Arc,
+};
 
 use farmfe_compiler::{trace_module_graph::TracedModuleGraph, Compiler};
 
@@ -15,7 +19,8 @@ pub mod profile_gui;
 use farmfe_core::{
   config::{Config, Mode},
   module::ModuleId,
-  plugin::UpdateType,
+  plugin::{ErroredModule, UpdateType},
+  serde_json,
 };
 
 #[cfg(feature = "file_watcher")]
@@ -83,6 +88,24 @@ impl From<TracedModuleGraph> for JsTracedModuleGraph {
   }
 }
 
+#[napi(object)]
+pub struct JsErroredModule {
+  pub id: String,
+  pub message: String,
+  /// json of [farmfe_core::error::ErrorLocation]
+  pub loc: Option<serde_json::Value>,
+}
+
+impl From<ErroredModule> for JsErroredModule {
+  fn from(m: ErroredModule) -> Self {
+    Self {
+      id: m.module_id.id(Mode::Development),
+      message: m.message,
+      loc: m.loc.map(|loc| serde_json::to_value(loc).unwrap()),
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsUpdateResult {
   pub added: Vec<String>,
@@ -93,6 +116,8 @@ pub struct JsUpdateResult {
   pub boundaries: HashMap<String, Vec<Vec<String>>>,
   pub dynamic_resources_map: Option<HashMap<String, Vec<Vec<String>>>>,
   pub extra_watch_result: WatchDiffResult,
+  /// modules that failed to build and are kept as error modules when `compilation.errorTolerant` is enabled
+  pub errors: Vec<JsErroredModule>,
 }
 
 #[napi(js_name = "Compiler")]
@@ -275,14 +300,37 @@ impl JsCompiler {
     let (promise, result) =
       e.create_deferred::<JsUpdateResult, Box<dyn FnOnce(Env) -> napi::Result<JsUpdateResult>>>()?;
 
+    let paths = {
+      let module_graph = context.module_graph.read();
+      let watch_graph = context.watch_graph.read();
+
+      paths
+        .into_iter()
+        .map(|p| {
+          // the path is created or deleted when it's matched by the glob patterns of a module, e.g. `import.meta.glob`,
+          // or it's a missing file of a failed resolution
+          let update_type = if watch_graph.glob_roots(&p).is_empty()
+            && watch_graph.missing_file_roots(&p).is_empty()
+          {
+            UpdateType::Updated
+          } else if !Path::new(&p).exists() {
+            UpdateType::Removed
+          } else if module_graph.has_module(&ModuleId::new(&p, "", &context.config.root)) {
+            UpdateType::Updated
+          } else {
+            UpdateType::Added
+          };
+
+          (p, update_type)
+        })
+        .collect::<Vec<_>>()
+    };
+
     let compiler = self.compiler.clone();
     self.compiler.thread_pool.spawn(move || {
       match compiler
         .update(
-          paths
-            .into_iter()
-            .map(|p| (p, UpdateType::Updated))
-            .collect(),
+          paths,
           move || {
             thread_safe_callback.call((), ThreadsafeFunctionCallMode::Blocking);
           },
@@ -339,6 +387,7 @@ impl JsCompiler {
                 .map(|path| ModuleId::new(&path, "", &context.config.root).id(Mode::Development))
                 .collect(),
             },
+            errors: res.errored_modules.into_iter().map(|m| m.into()).collect(),
           };
 
           promise.resolve(Box::new(move |_| Ok(js_update_result)));
@@ -379,6 +428,8 @@ impl JsCompiler {
     module_graph.has_module(&module_id)
       || watch_graph.has_module(&module_id)
       || !module_ids_by_file.is_empty()
+      || !watch_graph.glob_roots(&resolved_path).is_empty()
+      || !watch_graph.missing_file_roots(&resolved_path).is_empty()
   }
 
   #[napi]
@@ -443,11 +494,34 @@ impl JsCompiler {
 
     let watch_graph = context.watch_graph.read();
 
-    return watch_graph
+    let mut watch_modules: Vec<String> = watch_graph
       .modules()
       .into_iter()
       .map(|id| id.resolved_path(&context.config.root))
       .collect();
+    // the directories of glob patterns like `import.meta.glob` are watched for created or deleted files
+    watch_modules.extend(watch_graph.glob_dirs().into_iter().cloned());
+    // the directories of missing files are watched so that creating the files heals the failed resolutions
+    let missing_file_dirs: HashSet<String> = watch_graph
+      .missing_files()
+      .into_iter()
+      .filter_map(|file| Path::new(file).parent())
+      .filter(|dir| dir.is_dir())
+      .map(|dir| dir.to_string_lossy().to_string())
+      .collect();
+    watch_modules.extend(missing_file_dirs);
+
+    watch_modules
+  }
+
+  #[napi]
+  pub fn errored_modules(&self) -> Vec<JsErroredModule> {
+    self
+      .compiler
+      .errored_modules()
+      .into_iter()
+      .map(|m| m.into())
+      .collect()
   }
 
   #[napi]
@@ -199,7 +199,11 @@ unsafe extern "C" fn emit_file(env: napi_env, info: napi_callback_info) -> napi_
     .from_js_value(JsUnknown::from_napi_value(env, argv[0]).unwrap())
     .expect("Argument 0 should be a EmitFileParams { name, content, resolvedPath, resourceType } when calling emitFile");
 
-  ctx.emit_file(params);
+  if let Err(e) = ctx.emit_file(params) {
+    Env::from_raw(env)
+      .throw_error(&format!("{e}"), None)
+      .unwrap();
+  }
 
   Env::from_raw(env).get_undefined().unwrap().raw()
 }
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use farmfe_core::{
+  config::config_regex::ConfigRegex,
+  context::CompilationContext,
+  error::Result,
+  module::{ModuleId, ModuleType},
+  plugin::{PluginAnalyzeDepsHookParam, PluginAnalyzeDepsHookResultEntry},
+  serde::{Deserialize, Serialize},
+};
+use napi::{bindgen_prelude::FromNapiValue, NapiRaw};
+
+use crate::{
+  new_js_plugin_hook,
+  plugin_adapters::js_plugin_adapter::thread_safe_js_plugin_hook::ThreadSafeJsPluginHook,
+};
+
+/// the deps returned by the js hook replace the deps analyzed by previous plugins
+type JsPluginAnalyzeDepsHookResult = Vec<PluginAnalyzeDepsHookResultEntry>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPluginAnalyzeDepsHookParams {
+  pub module_id: ModuleId,
+  pub module_type: ModuleType,
+  pub deps: Vec<PluginAnalyzeDepsHookResultEntry>,
+}
+
+impl From<&mut PluginAnalyzeDepsHookParam<'_>> for JsPluginAnalyzeDepsHookParams {
+  fn from(value: &mut PluginAnalyzeDepsHookParam) -> Self {
+    Self {
+      module_id: value.module.id.clone(),
+      module_type: value.module.module_type.clone(),
+      deps: value.deps.clone(),
+    }
+  }
+}
+
+pub struct JsPluginAnalyzeDepsHook {
+  tsfn: ThreadSafeJsPluginHook,
+  filters: PluginModuleHookFilters,
+}
+
+impl JsPluginAnalyzeDepsHook {
+  new_js_plugin_hook!(
+    PluginModuleHookFilters,
+    JsPluginModuleHookFilters,
+    JsPluginAnalyzeDepsHookParams,
+    JsPluginAnalyzeDepsHookResult
+  );
+
+  pub fn call(
+    &self,
+    param: JsPluginAnalyzeDepsHookParams,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<Vec<PluginAnalyzeDepsHookResultEntry>>> {
+    if self
+      .filters
+      .matches(&param.module_id, &param.module_type, &ctx)
+    {
+      self
+        .tsfn
+        .call::<JsPluginAnalyzeDepsHookParams, JsPluginAnalyzeDepsHookResult>(param, ctx, None)
+    } else {
+      Ok(None)
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsPluginModuleHookFilters {
+  pub resolved_paths: Vec<String>,
+  pub module_types: Vec<String>,
+}
+
+/// Filters of the module level hooks like `analyzeDeps` and `processModule`, evaluated before calling the js hook
+#[derive(Debug)]
+pub struct PluginModuleHookFilters {
+  pub resolved_paths: Vec<ConfigRegex>,
+  pub module_types: Vec<ModuleType>,
+}
+
+impl PluginModuleHookFilters {
+  pub fn matches(
+    &self,
+    module_id: &ModuleId,
+    module_type: &ModuleType,
+    ctx: &Arc<CompilationContext>,
+  ) -> bool {
+    let resolved_path = module_id.resolved_path_with_query(&ctx.config.root);
+
+    self
+      .resolved_paths
+      .iter()
+      .any(|f| f.is_match(&resolved_path))
+      || self.module_types.iter().any(|ty| module_type == ty)
+  }
+}
+
+impl From<JsPluginModuleHookFilters> for PluginModuleHookFilters {
+  fn from(f: JsPluginModuleHookFilters) -> Self {
+    Self {
+      resolved_paths: f
+        .resolved_paths
+        .into_iter()
+        .map(|f| ConfigRegex::new(&f))
+        .collect(),
+      module_types: f.module_types.into_iter().map(|ty| ty.into()).collect(),
+    }
+  }
+}
@@ -0,0 +1,195 @@
+use std::{collections::HashSet, sync::Arc};
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  module::{
+    module_graph::ModuleGraph,
+    module_group::{ModuleGroup, ModuleGroupGraph},
+    ModuleId, ModuleType,
+  },
+  serde::{Deserialize, Serialize},
+};
+
+use crate::plugin_adapters::js_plugin_adapter::thread_safe_js_plugin_hook::ThreadSafeJsPluginHook;
+
+/// A serializable snapshot of the module graph passed to the js hooks, the modules are sorted by id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsModuleGraphSnapshot {
+  pub entries: Vec<ModuleId>,
+  pub modules: Vec<JsModuleGraphSnapshotModule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsModuleGraphSnapshotModule {
+  pub id: ModuleId,
+  pub module_type: ModuleType,
+  pub immutable: bool,
+  pub external: bool,
+  pub size: usize,
+  pub dependencies: Vec<JsModuleGraphSnapshotDependency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsModuleGraphSnapshotDependency {
+  pub id: ModuleId,
+  /// true if the dependency is only imported dynamically
+  pub dynamic: bool,
+}
+
+impl From<&ModuleGraph> for JsModuleGraphSnapshot {
+  fn from(module_graph: &ModuleGraph) -> Self {
+    let mut entries = module_graph.entries.keys().cloned().collect::<Vec<_>>();
+    entries.sort();
+
+    let mut modules = module_graph
+      .modules()
+      .into_iter()
+      .map(|module| {
+        let mut dependencies = module_graph
+          .dependencies(&module.id)
+          .into_iter()
+          .map(|(id, edge)| JsModuleGraphSnapshotDependency {
+            id,
+            dynamic: edge.is_dynamic(),
+          })
+          .collect::<Vec<_>>();
+        dependencies.sort_by(|a, b| a.id.cmp(&b.id));
+
+        JsModuleGraphSnapshotModule {
+          id: module.id.clone(),
+          module_type: module.module_type.clone(),
+          immutable: module.immutable,
+          external: module.external,
+          size: module.size,
+          dependencies,
+        }
+      })
+      .collect::<Vec<_>>();
+    modules.sort_by(|a, b| a.id.cmp(&b.id));
+
+    Self { entries, modules }
+  }
+}
+
+/// A module group returned by the js hook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsModuleGroup {
+  /// the entry module of the group, it's always a member of the group
+  pub id: ModuleId,
+  pub modules: Vec<ModuleId>,
+  /// the module groups that are loaded dynamically by this group
+  #[serde(default)]
+  pub dependencies: Vec<ModuleId>,
+}
+
+pub struct JsPluginAnalyzeModuleGraphHook {
+  tsfn: ThreadSafeJsPluginHook,
+}
+
+impl JsPluginAnalyzeModuleGraphHook {
+  pub fn new(env: &napi::Env, obj: napi::JsObject) -> Self {
+    let func = obj
+      .get_named_property::<napi::JsFunction>("executor")
+      .expect("executor should be checked in js side");
+
+    Self {
+      tsfn: ThreadSafeJsPluginHook::new::<JsModuleGraphSnapshot, Vec<JsModuleGroup>>(env, func),
+    }
+  }
+
+  pub fn call(
+    &self,
+    module_graph: &mut ModuleGraph,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<ModuleGroupGraph>> {
+    let Some(groups) = self
+      .tsfn
+      .call::<JsModuleGraphSnapshot, Vec<JsModuleGroup>>((&*module_graph).into(), ctx, None)?
+    else {
+      return Ok(None);
+    };
+
+    module_group_graph_from_js_module_groups(groups, module_graph).map(Some)
+  }
+}
+
+/// Build the module group graph and fill `module_groups` of the modules, like the default analyze_module_graph hook does.
+/// Every module of the module graph must be covered by the returned groups
+fn module_group_graph_from_js_module_groups(
+  groups: Vec<JsModuleGroup>,
+  module_graph: &mut ModuleGraph,
+) -> Result<ModuleGroupGraph> {
+  let error = |msg: String| CompilationError::AnalyzeModuleGraphError {
+    source: Some(Box::new(CompilationError::GenericError(msg)) as _),
+  };
+  let mut module_group_graph = ModuleGroupGraph::new();
+  let mut edges = vec![];
+  let mut grouped_modules = HashSet::new();
+
+  for group in groups {
+    if module_group_graph.has(&group.id) {
+      return Err(error(format!(
+        "duplicate module group {}",
+        group.id.to_string()
+      )));
+    }
+
+    let mut module_group = ModuleGroup::new(group.id.clone());
+
+    for module_id in std::iter::once(&group.id).chain(group.modules.iter()) {
+      let Some(module) = module_graph.module_mut(module_id) else {
+        return Err(error(format!(
+          "module {} of module group {} does not exist in the module graph",
+          module_id.to_string(),
+          group.id.to_string()
+        )));
+      };
+      module.module_groups.insert(group.id.clone());
+      module_group.add_module(module_id.clone());
+      grouped_modules.insert(module_id.clone());
+    }
+
+    edges.extend(
+      group
+        .dependencies
+        .into_iter()
+        .map(|dep| (group.id.clone(), dep)),
+    );
+    module_group_graph.add_module_group(module_group);
+  }
+
+  for (from, to) in &edges {
+    if !module_group_graph.has(to) {
+      return Err(error(format!(
+        "module group {} depends on module group {} which is not returned",
+        from.to_string(),
+        to.to_string()
+      )));
+    }
+
+    module_group_graph.add_edge(from, to);
+  }
+
+  // every module must be in at least one module group, otherwise it's silently dropped from the output
+  let mut ungrouped_modules = module_graph
+    .modules()
+    .into_iter()
+    .map(|module| &module.id)
+    .filter(|id| !grouped_modules.contains(*id))
+    .collect::<Vec<_>>();
+  ungrouped_modules.sort();
+
+  if let Some(module_id) = ungrouped_modules.first() {
+    return Err(error(format!(
+      "module {} is not in any of the returned module groups",
+      module_id.to_string()
+    )));
+  }
+
+  Ok(module_group_graph)
+}
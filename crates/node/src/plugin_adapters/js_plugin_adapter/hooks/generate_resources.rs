@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use farmfe_core::{
+  config::config_regex::ConfigRegex,
+  context::CompilationContext,
+  error::Result,
+  plugin::PluginGenerateResourcesHookResult,
+  resource::resource_pot::{ResourcePot, ResourcePotInfo, ResourcePotType},
+  serde::{Deserialize, Serialize},
+};
+use napi::{bindgen_prelude::FromNapiValue, NapiRaw};
+
+use crate::{
+  new_js_plugin_hook,
+  plugin_adapters::js_plugin_adapter::thread_safe_js_plugin_hook::ThreadSafeJsPluginHook,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPluginGenerateResourcesHookParams {
+  /// the rendered content of the resource pot
+  pub content: Arc<String>,
+  pub source_map_chain: Vec<Arc<String>>,
+  pub resource_pot_info: ResourcePotInfo,
+}
+
+impl From<&ResourcePot> for JsPluginGenerateResourcesHookParams {
+  fn from(resource_pot: &ResourcePot) -> Self {
+    Self {
+      content: resource_pot.meta.rendered_content.clone(),
+      source_map_chain: resource_pot.meta.rendered_map_chain.clone(),
+      resource_pot_info: ResourcePotInfo::new(resource_pot),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsPluginGenerateResourcesHookFilters {
+  pub resource_pot_types: Vec<String>,
+  pub module_ids: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct PluginGenerateResourcesHookFilters {
+  pub resource_pot_types: Vec<ResourcePotType>,
+  pub module_ids: Vec<ConfigRegex>,
+}
+
+impl From<JsPluginGenerateResourcesHookFilters> for PluginGenerateResourcesHookFilters {
+  fn from(f: JsPluginGenerateResourcesHookFilters) -> Self {
+    Self {
+      resource_pot_types: f
+        .resource_pot_types
+        .into_iter()
+        .map(|ty| ty.into())
+        .collect(),
+      module_ids: f
+        .module_ids
+        .into_iter()
+        .map(|p| ConfigRegex::new(&p))
+        .collect(),
+    }
+  }
+}
+
+pub struct JsPluginGenerateResourcesHook {
+  tsfn: ThreadSafeJsPluginHook,
+  filters: PluginGenerateResourcesHookFilters,
+}
+
+impl JsPluginGenerateResourcesHook {
+  new_js_plugin_hook!(
+    PluginGenerateResourcesHookFilters,
+    JsPluginGenerateResourcesHookFilters,
+    JsPluginGenerateResourcesHookParams,
+    PluginGenerateResourcesHookResult
+  );
+
+  pub fn call(
+    &self,
+    resource_pot: &ResourcePot,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<PluginGenerateResourcesHookResult>> {
+    // filter by the resource pot before serializing the rendered content
+    if self
+      .filters
+      .resource_pot_types
+      .contains(&resource_pot.resource_pot_type)
+      || self.filters.module_ids.iter().any(|f| {
+        resource_pot
+          .modules()
+          .into_iter()
+          .any(|id| f.is_match(&id.to_string()))
+      })
+    {
+      self.tsfn.call(
+        JsPluginGenerateResourcesHookParams::from(resource_pot),
+        ctx,
+        None,
+      )
+    } else {
+      Ok(None)
+    }
+  }
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  module::{
+    module_graph::{ModuleGraph, ModuleGraphEdgeDataItem},
+    ModuleId,
+  },
+  plugin::ResolveKind,
+  serde::{Deserialize, Serialize},
+};
+
+use super::analyze_module_graph::JsModuleGraphSnapshot;
+use crate::plugin_adapters::js_plugin_adapter::thread_safe_js_plugin_hook::ThreadSafeJsPluginHook;
+
+/// The patch returned by the js hook, it's applied to the module graph in the order: remove edges, remove modules, add edges.
+/// Modules can not be added as js plugins have no access to the ast
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsModuleGraphPatch {
+  #[serde(default)]
+  pub remove_edges: Vec<JsModuleGraphPatchEdge>,
+  /// the edges of the removed modules are removed too
+  #[serde(default)]
+  pub remove_modules: Vec<ModuleId>,
+  #[serde(default)]
+  pub add_edges: Vec<JsModuleGraphPatchEdge>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsModuleGraphPatchEdge {
+  pub from: ModuleId,
+  pub to: ModuleId,
+  /// the source of the added edge, defaults to the id of `to`
+  #[serde(default)]
+  pub source: Option<String>,
+  #[serde(default)]
+  pub kind: ResolveKind,
+}
+
+/// The module graph is passed to js as a snapshot, the returned patch is applied to the module graph
+pub struct JsPluginOptimizeModuleGraphHook {
+  tsfn: ThreadSafeJsPluginHook,
+}
+
+impl JsPluginOptimizeModuleGraphHook {
+  pub fn new(env: &napi::Env, obj: napi::JsObject) -> Self {
+    let func = obj
+      .get_named_property::<napi::JsFunction>("executor")
+      .expect("executor should be checked in js side");
+
+    Self {
+      tsfn: ThreadSafeJsPluginHook::new::<JsModuleGraphSnapshot, JsModuleGraphPatch>(env, func),
+    }
+  }
+
+  pub fn call(
+    &self,
+    module_graph: &ModuleGraph,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<JsModuleGraphPatch>> {
+    self
+      .tsfn
+      .call::<JsModuleGraphSnapshot, JsModuleGraphPatch>(module_graph.into(), ctx, None)
+  }
+}
+
+pub fn apply_module_graph_patch(
+  patch: JsModuleGraphPatch,
+  module_graph: &mut ModuleGraph,
+) -> Result<()> {
+  let error = |msg: String| {
+    CompilationError::GenericError(format!(
+      "Invalid module graph patch returned by optimizeModuleGraph hook: {msg}"
+    ))
+  };
+
+  for edge in patch.remove_edges {
+    module_graph
+      .remove_edge(&edge.from, &edge.to)
+      .map_err(|e| error(e.to_string()))?;
+  }
+
+  for module_id in patch.remove_modules {
+    if !module_graph.has_module(&module_id) {
+      return Err(error(format!(
+        "module {} does not exist in the module graph",
+        module_id.to_string()
+      )));
+    }
+
+    module_graph.entries.remove(&module_id);
+    module_graph.remove_module(&module_id);
+  }
+
+  for edge in patch.add_edges {
+    let order = module_graph.dependencies(&edge.from).len();
+    let source = edge.source.unwrap_or_else(|| edge.to.to_string());
+
+    module_graph
+      .add_edge_item(
+        &edge.from,
+        &edge.to,
+        ModuleGraphEdgeDataItem {
+          source,
+          kind: edge.kind,
+          order,
+        },
+      )
+      .map_err(|e| error(e.to_string()))?;
+  }
+
+  Ok(())
+}
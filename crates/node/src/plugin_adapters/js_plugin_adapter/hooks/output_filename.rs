@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::plugin_adapters::js_plugin_adapter::{
+  get_named_property, thread_safe_js_plugin_hook::ThreadSafeJsPluginHook,
+};
+use farmfe_core::{
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  plugin::{OutputFilenameKind, PluginOutputFilenameHookParams},
+  serde_json,
+};
+use napi::JsObject;
+
+pub struct JsPluginOutputFilenameHook {
+  tsfn: ThreadSafeJsPluginHook,
+  filters: PluginOutputFilenameHookFilters,
+}
+
+impl JsPluginOutputFilenameHook {
+  /// Unlike the other hooks, the filters are validated here as the kinds are not checked by the js side
+  pub fn new(env: &napi::Env, obj: JsObject) -> Result<Self> {
+    let filters: PluginOutputFilenameHookFilters =
+      get_named_property::<JsPluginOutputFilenameHookFilters>(env, &obj, "filters")?.try_into()?;
+    let func = get_named_property::<napi::JsFunction>(env, &obj, "executor")?;
+
+    Ok(Self {
+      tsfn: ThreadSafeJsPluginHook::new::<PluginOutputFilenameHookParams, String>(env, func),
+      filters,
+    })
+  }
+
+  pub fn call(
+    &self,
+    param: PluginOutputFilenameHookParams,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<String>> {
+    if self.filters.kinds.contains(&param.kind) {
+      self.tsfn.call(param, ctx, None)
+    } else {
+      Ok(None)
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsPluginOutputFilenameHookFilters {
+  /// `entry`, `chunk` or `asset`
+  pub kinds: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct PluginOutputFilenameHookFilters {
+  pub kinds: Vec<OutputFilenameKind>,
+}
+
+impl TryFrom<JsPluginOutputFilenameHookFilters> for PluginOutputFilenameHookFilters {
+  type Error = CompilationError;
+
+  fn try_from(f: JsPluginOutputFilenameHookFilters) -> Result<Self> {
+    let kinds = f
+      .kinds
+      .into_iter()
+      .map(|kind| {
+        serde_json::from_value(serde_json::Value::String(kind.clone())).map_err(|_| {
+          CompilationError::GenericError(format!(
+            "Invalid outputFilename hook filter kind `{kind}`, expected one of `entry`, `chunk` and `asset`"
+          ))
+        })
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    Ok(Self { kinds })
+  }
+}
@@ -0,0 +1,183 @@
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+};
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  module::{ModuleId, ModuleType},
+  resource::resource_pot::{ResourcePot, ResourcePotType},
+  serde::{Deserialize, Serialize},
+};
+
+use crate::plugin_adapters::js_plugin_adapter::thread_safe_js_plugin_hook::ThreadSafeJsPluginHook;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPluginPartialBundlingHookParams {
+  /// the modules to bundle, modules matched by `partialBundling.enforceResources` are not included
+  pub modules: Vec<JsPartialBundlingModule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPartialBundlingModule {
+  pub id: ModuleId,
+  pub module_type: ModuleType,
+  pub immutable: bool,
+  pub size: usize,
+  pub module_groups: Vec<ModuleId>,
+}
+
+/// A resource pot returned by the js hook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPartialBundlingResourcePot {
+  pub name: String,
+  /// inferred from the module types when omitted
+  pub resource_pot_type: Option<ResourcePotType>,
+  pub modules: Vec<ModuleId>,
+}
+
+pub struct JsPluginPartialBundlingHook {
+  tsfn: ThreadSafeJsPluginHook,
+}
+
+impl JsPluginPartialBundlingHook {
+  pub fn new(env: &napi::Env, obj: napi::JsObject) -> Self {
+    let func = obj
+      .get_named_property::<napi::JsFunction>("executor")
+      .expect("executor should be checked in js side");
+
+    Self {
+      tsfn: ThreadSafeJsPluginHook::new::<
+        JsPluginPartialBundlingHookParams,
+        Vec<JsPartialBundlingResourcePot>,
+      >(env, func),
+    }
+  }
+
+  pub fn call(
+    &self,
+    modules: &[ModuleId],
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<Vec<ResourcePot>>> {
+    let param = {
+      let module_graph = ctx.module_graph.read();
+
+      JsPluginPartialBundlingHookParams {
+        modules: modules
+          .iter()
+          .map(|id| {
+            let module = module_graph.module(id).unwrap();
+            let mut module_groups = module.module_groups.iter().cloned().collect::<Vec<_>>();
+            module_groups.sort();
+
+            JsPartialBundlingModule {
+              id: id.clone(),
+              module_type: module.module_type.clone(),
+              immutable: module.immutable,
+              size: module.size,
+              module_groups,
+            }
+          })
+          .collect(),
+      }
+    };
+
+    let Some(resource_pots) = self
+      .tsfn
+      .call::<JsPluginPartialBundlingHookParams, Vec<JsPartialBundlingResourcePot>>(
+        param.clone(),
+        ctx,
+        None,
+      )?
+    else {
+      return Ok(None);
+    };
+
+    resource_pots_from_js_resource_pots(resource_pots, &param.modules).map(Some)
+  }
+}
+
+/// Every module should be placed in exactly one resource pot, and a resource pot can only contain modules of the same resource pot type
+fn resource_pots_from_js_resource_pots(
+  resource_pots: Vec<JsPartialBundlingResourcePot>,
+  modules: &[JsPartialBundlingModule],
+) -> Result<Vec<ResourcePot>> {
+  let error = |msg: String| {
+    CompilationError::GenericError(format!("Invalid result of js partialBundling hook: {msg}"))
+  };
+  let mut unplaced = modules
+    .iter()
+    .map(|module| (&module.id, module))
+    .collect::<HashMap<_, _>>();
+  let mut names = HashSet::new();
+  let mut result = vec![];
+
+  for js_resource_pot in resource_pots {
+    if !names.insert(js_resource_pot.name.clone()) {
+      return Err(error(format!(
+        "duplicate resource pot name `{}`",
+        js_resource_pot.name
+      )));
+    }
+
+    if js_resource_pot.modules.is_empty() {
+      return Err(error(format!(
+        "resource pot `{}` does not contain any module",
+        js_resource_pot.name
+      )));
+    }
+
+    let mut resource_pot_type = js_resource_pot.resource_pot_type.clone();
+    let mut placed = vec![];
+
+    for module_id in &js_resource_pot.modules {
+      let Some(module) = unplaced.remove(module_id) else {
+        return Err(error(format!(
+          "module {} of resource pot `{}` is not a module to bundle or is already placed in another resource pot",
+          module_id.to_string(),
+          js_resource_pot.name
+        )));
+      };
+      let ty = ResourcePotType::from(module.module_type.clone());
+
+      match &resource_pot_type {
+        None => resource_pot_type = Some(ty),
+        Some(expected) if js_resource_pot.resource_pot_type.is_none() && expected != &ty => {
+          return Err(error(format!(
+            "resource pot `{}` contains modules of different resource pot types, specify resourcePotType explicitly",
+            js_resource_pot.name
+          )));
+        }
+        _ => {}
+      }
+
+      placed.push(module);
+    }
+
+    let mut resource_pot = ResourcePot::new(js_resource_pot.name, resource_pot_type.unwrap());
+    resource_pot.immutable = placed.iter().all(|module| module.immutable);
+
+    for module in placed {
+      resource_pot.add_module(module.id.clone());
+    }
+
+    result.push(resource_pot);
+  }
+
+  if let Some(module_id) = modules
+    .iter()
+    .map(|module| &module.id)
+    .find(|id| unplaced.contains_key(id))
+  {
+    return Err(error(format!(
+      "module {} is not placed in any resource pot",
+      module_id.to_string()
+    )));
+  }
+
+  Ok(result)
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  module::{CommentsMetaData, ModuleId, ModuleMetaData, ModuleType},
+  plugin::PluginProcessModuleHookParam,
+  serde::{Deserialize, Serialize},
+  swc_common::{Mark, GLOBALS},
+  swc_ecma_ast::EsVersion,
+};
+use farmfe_toolkit::{
+  css::{parse_css_stylesheet, ParseCssModuleResult},
+  script::{parse_module, syntax_from_module_type, ParseScriptModuleResult},
+  swc_ecma_transforms::resolver,
+  swc_ecma_visit::VisitMutWith,
+};
+use napi::{bindgen_prelude::FromNapiValue, NapiRaw};
+
+use super::analyze_deps::{JsPluginModuleHookFilters, PluginModuleHookFilters};
+use crate::{
+  new_js_plugin_hook,
+  plugin_adapters::js_plugin_adapter::thread_safe_js_plugin_hook::ThreadSafeJsPluginHook,
+};
+
+/// The ast of the module stays in rust, so js plugins receive the module content in this hook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPluginProcessModuleHookParams {
+  pub module_id: ModuleId,
+  pub module_type: ModuleType,
+  pub content: Arc<String>,
+}
+
+impl From<&mut PluginProcessModuleHookParam<'_>> for JsPluginProcessModuleHookParams {
+  fn from(value: &mut PluginProcessModuleHookParam) -> Self {
+    Self {
+      module_id: value.module_id.clone(),
+      module_type: value.module_type.clone(),
+      content: value.content.clone(),
+    }
+  }
+}
+
+/// The content returned by the js hook replaces the module, it's parsed again on the rust side
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPluginProcessModuleHookResult {
+  pub content: String,
+}
+
+pub struct JsPluginProcessModuleHook {
+  tsfn: ThreadSafeJsPluginHook,
+  filters: PluginModuleHookFilters,
+}
+
+impl JsPluginProcessModuleHook {
+  new_js_plugin_hook!(
+    PluginModuleHookFilters,
+    JsPluginModuleHookFilters,
+    JsPluginProcessModuleHookParams,
+    JsPluginProcessModuleHookResult
+  );
+
+  pub fn call(
+    &self,
+    param: JsPluginProcessModuleHookParams,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<JsPluginProcessModuleHookResult>> {
+    if self
+      .filters
+      .matches(&param.module_id, &param.module_type, &ctx)
+    {
+      self
+        .tsfn
+        .call::<JsPluginProcessModuleHookParams, JsPluginProcessModuleHookResult>(param, ctx, None)
+    } else {
+      Ok(None)
+    }
+  }
+}
+
+/// Parse the content returned by the js hook and replace the ast of the module, only script and css modules are supported
+pub fn replace_module_meta_with_content(
+  param: &mut PluginProcessModuleHookParam,
+  content: String,
+  context: &Arc<CompilationContext>,
+) -> Result<()> {
+  let module_id = param.module_id.to_string();
+
+  match param.meta {
+    ModuleMetaData::Script(script) => {
+      let syntax =
+        syntax_from_module_type(param.module_type, context.config.script.parser.clone())
+          .ok_or_else(|| {
+            CompilationError::GenericError(format!(
+              "processModule hook returned content for {module_id}, but its module type {:?} is not a script module type",
+              param.module_type
+            ))
+          })?;
+      let ParseScriptModuleResult {
+        ast: mut swc_module,
+        comments,
+      } = parse_module(&module_id, &content, syntax, EsVersion::EsNext)?;
+
+      GLOBALS.set(&context.meta.script.globals, || {
+        let top_level_mark = Mark::new();
+        let unresolved_mark = Mark::new();
+
+        swc_module.visit_mut_with(&mut resolver(
+          unresolved_mark,
+          top_level_mark,
+          param.module_type.is_typescript(),
+        ));
+
+        script.ast = swc_module;
+        script.top_level_mark = top_level_mark.as_u32();
+        script.unresolved_mark = unresolved_mark.as_u32();
+        script.comments = CommentsMetaData::from(comments);
+      });
+    }
+    ModuleMetaData::Css(css) => {
+      let ParseCssModuleResult { ast, comments } =
+        parse_css_stylesheet(&module_id, Arc::new(content))?;
+
+      css.ast = ast;
+      css.comments = CommentsMetaData::from(comments);
+    }
+    _ => {
+      return Err(CompilationError::GenericError(format!(
+        "processModule hook returned content for {module_id}, but only script and css modules can be replaced"
+      )))
+    }
+  }
+
+  Ok(())
+}
@@ -5,24 +5,33 @@ use farmfe_compiler::{DYNAMIC_VIRTUAL_SUFFIX, FARM_CSS_MODULES_SUFFIX, RUNTIME_S
 use farmfe_core::{
   context::CompilationContext,
   error::{CompilationError, Result},
-  module::ModuleType,
+  module::{module_graph::ModuleGraph, module_group::ModuleGroupGraph, ModuleId, ModuleType},
   plugin::{
-    EmptyPluginHookParam, Plugin, PluginFinalizeResourcesHookParams, PluginHookContext,
-    PluginLoadHookParam, PluginLoadHookResult, PluginResolveHookParam, PluginResolveHookResult,
-    PluginTransformHookParam, PluginTransformHookResult, UpdateType, DEFAULT_PRIORITY,
+    EmptyPluginHookParam, Plugin, PluginAnalyzeDepsHookParam, PluginFinalizeResourcesHookParams,
+    PluginGenerateResourcesHookResult, PluginHookContext, PluginLoadHookParam,
+    PluginLoadHookResult, PluginOutputFilenameHookParams, PluginProcessModuleHookParam,
+    PluginResolveHookParam, PluginResolveHookResult, PluginTransformHookParam,
+    PluginTransformHookResult, UpdateType, DEFAULT_PRIORITY,
   },
-  resource::{Resource, ResourceOrigin, ResourceType},
+  resource::{resource_pot::ResourcePot, Resource, ResourceOrigin, ResourceType},
 };
 use napi::{bindgen_prelude::FromNapiValue, Env, JsObject, JsUnknown, NapiRaw};
 
 use self::hooks::{
+  analyze_deps::JsPluginAnalyzeDepsHook,
+  analyze_module_graph::JsPluginAnalyzeModuleGraphHook,
   augment_resource_hash::JsPluginAugmentResourceHashHook,
   build_end::JsPluginBuildEndHook,
   build_start::JsPluginBuildStartHook,
   finalize_resources::JsPluginFinalizeResourcesHook,
   finish::JsPluginFinishHook,
+  generate_resources::JsPluginGenerateResourcesHook,
   load::JsPluginLoadHook,
+  optimize_module_graph::{apply_module_graph_patch, JsPluginOptimizeModuleGraphHook},
+  output_filename::JsPluginOutputFilenameHook,
+  partial_bundling::JsPluginPartialBundlingHook,
   plugin_cache_loaded::JsPluginPluginCacheLoadedHook,
+  process_module::{replace_module_meta_with_content, JsPluginProcessModuleHook},
   render_resource_pot::JsPluginRenderResourcePotHook,
   render_start::JsPluginRenderStartHook,
   resolve::JsPluginResolveHook,
@@ -47,6 +56,8 @@ pub struct JsPluginAdapter {
   js_resolve_hook: Option<JsPluginResolveHook>,
   js_load_hook: Option<JsPluginLoadHook>,
   js_transform_hook: Option<JsPluginTransformHook>,
+  js_process_module_hook: Option<JsPluginProcessModuleHook>,
+  js_analyze_deps_hook: Option<JsPluginAnalyzeDepsHook>,
   js_build_end_hook: Option<JsPluginBuildEndHook>,
   js_finish_hook: Option<JsPluginFinishHook>,
   js_update_modules_hook: Option<JsPluginUpdateModulesHook>,
@@ -55,9 +66,14 @@ pub struct JsPluginAdapter {
   js_render_resource_pot_hook: Option<JsPluginRenderResourcePotHook>,
   js_render_start_hook: Option<JsPluginRenderStartHook>,
   js_augment_resource_hash_hook: Option<JsPluginAugmentResourceHashHook>,
+  js_output_filename_hook: Option<JsPluginOutputFilenameHook>,
   js_finalize_resources_hook: Option<JsPluginFinalizeResourcesHook>,
   js_transform_html_hook: Option<JsPluginTransformHtmlHook>,
   js_update_finished_hook: Option<JsPluginUpdateFinishedHook>,
+  js_optimize_module_graph_hook: Option<JsPluginOptimizeModuleGraphHook>,
+  js_analyze_module_graph_hook: Option<JsPluginAnalyzeModuleGraphHook>,
+  js_partial_bundling_hook: Option<JsPluginPartialBundlingHook>,
+  js_generate_resources_hook: Option<JsPluginGenerateResourcesHook>,
 }
 
 impl JsPluginAdapter {
@@ -87,12 +103,29 @@ impl JsPluginAdapter {
       get_named_property::<JsObject>(env, &js_plugin_object, "renderStart").ok();
     let augment_resource_hash_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "augmentResourceHash").ok();
+    let output_filename_hook =
+      get_named_property::<JsObject>(env, &js_plugin_object, "outputFilename")
+        .ok()
+        .map(|obj| JsPluginOutputFilenameHook::new(env, obj))
+        .transpose()?;
     let finalize_resources_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "finalizeResources").ok();
     let transform_html_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "transformHtml").ok();
     let update_finished_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "updateFinished").ok();
+    let process_module_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "processModule").ok();
+    let analyze_deps_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "analyzeDeps").ok();
+    let optimize_module_graph_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "optimizeModuleGraph").ok();
+    let analyze_module_graph_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "analyzeModuleGraph").ok();
+    let partial_bundling_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "partialBundling").ok();
+    let generate_resources_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "generateResources").ok();
 
     Ok(Self {
       name,
@@ -114,12 +147,24 @@ impl JsPluginAdapter {
       js_render_start_hook: render_start_obj.map(|obj| JsPluginRenderStartHook::new(env, obj)),
       js_augment_resource_hash_hook: augment_resource_hash_obj
         .map(|obj| JsPluginAugmentResourceHashHook::new(env, obj)),
+      js_output_filename_hook: output_filename_hook,
       js_finalize_resources_hook: finalize_resources_obj
         .map(|obj| JsPluginFinalizeResourcesHook::new(env, obj)),
       js_transform_html_hook: transform_html_obj
         .map(|obj| JsPluginTransformHtmlHook::new(env, obj)),
       js_update_finished_hook: update_finished_obj
         .map(|obj| JsPluginUpdateFinishedHook::new(env, obj)),
+      js_process_module_hook: process_module_obj
+        .map(|obj| JsPluginProcessModuleHook::new(env, obj)),
+      js_analyze_deps_hook: analyze_deps_obj.map(|obj| JsPluginAnalyzeDepsHook::new(env, obj)),
+      js_optimize_module_graph_hook: optimize_module_graph_obj
+        .map(|obj| JsPluginOptimizeModuleGraphHook::new(env, obj)),
+      js_analyze_module_graph_hook: analyze_module_graph_obj
+        .map(|obj| JsPluginAnalyzeModuleGraphHook::new(env, obj)),
+      js_partial_bundling_hook: partial_bundling_obj
+        .map(|obj| JsPluginPartialBundlingHook::new(env, obj)),
+      js_generate_resources_hook: generate_resources_obj
+        .map(|obj| JsPluginGenerateResourcesHook::new(env, obj)),
     })
   }
 
@@ -243,6 +288,44 @@ impl Plugin for JsPluginAdapter {
     }
   }
 
+  fn process_module(
+    &self,
+    param: &mut PluginProcessModuleHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    if self.is_internal_virtual_module(&param.module_id.to_string()) {
+      return Ok(None);
+    }
+
+    if let Some(js_process_module_hook) = &self.js_process_module_hook {
+      if let Some(result) = js_process_module_hook.call(param.into(), context.clone())? {
+        replace_module_meta_with_content(param, result.content, context)?;
+        return Ok(Some(()));
+      }
+    }
+
+    Ok(None)
+  }
+
+  fn analyze_deps(
+    &self,
+    param: &mut PluginAnalyzeDepsHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    if self.is_internal_virtual_module(&param.module.id.to_string()) {
+      return Ok(None);
+    }
+
+    if let Some(js_analyze_deps_hook) = &self.js_analyze_deps_hook {
+      if let Some(deps) = js_analyze_deps_hook.call(param.into(), context.clone())? {
+        param.deps = deps;
+      }
+      Ok(Some(()))
+    } else {
+      Ok(None)
+    }
+  }
+
   fn build_end(&self, context: &Arc<CompilationContext>) -> Result<Option<()>> {
     if let Some(js_build_end_hook) = &self.js_build_end_hook {
       js_build_end_hook.call(EmptyPluginHookParam {}, context.clone())?;
@@ -315,6 +398,47 @@ impl Plugin for JsPluginAdapter {
     }
   }
 
+  fn optimize_module_graph(
+    &self,
+    module_graph: &mut ModuleGraph,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    if let Some(js_optimize_module_graph_hook) = &self.js_optimize_module_graph_hook {
+      if let Some(patch) = js_optimize_module_graph_hook.call(module_graph, context.clone())? {
+        apply_module_graph_patch(patch, module_graph)?;
+        return Ok(Some(()));
+      }
+    }
+
+    Ok(None)
+  }
+
+  fn analyze_module_graph(
+    &self,
+    module_graph: &mut ModuleGraph,
+    context: &Arc<CompilationContext>,
+    _hook_context: &PluginHookContext,
+  ) -> Result<Option<ModuleGroupGraph>> {
+    if let Some(js_analyze_module_graph_hook) = &self.js_analyze_module_graph_hook {
+      js_analyze_module_graph_hook.call(module_graph, context.clone())
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn partial_bundling(
+    &self,
+    modules: &Vec<ModuleId>,
+    context: &Arc<CompilationContext>,
+    _hook_context: &PluginHookContext,
+  ) -> Result<Option<Vec<ResourcePot>>> {
+    if let Some(js_partial_bundling_hook) = &self.js_partial_bundling_hook {
+      js_partial_bundling_hook.call(modules, context.clone())
+    } else {
+      Ok(None)
+    }
+  }
+
   fn render_resource_pot(
     &self,
     param: &farmfe_core::plugin::PluginRenderResourcePotHookParam,
@@ -352,6 +476,31 @@ impl Plugin for JsPluginAdapter {
     }
   }
 
+  fn output_filename(
+    &self,
+    params: &PluginOutputFilenameHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<String>> {
+    if let Some(js_output_filename_hook) = &self.js_output_filename_hook {
+      js_output_filename_hook.call(params.clone(), context.clone())
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn generate_resources(
+    &self,
+    resource_pot: &mut ResourcePot,
+    context: &Arc<CompilationContext>,
+    _hook_context: &PluginHookContext,
+  ) -> Result<Option<PluginGenerateResourcesHookResult>> {
+    if let Some(js_generate_resources_hook) = &self.js_generate_resources_hook {
+      js_generate_resources_hook.call(resource_pot, context.clone())
+    } else {
+      Ok(None)
+    }
+  }
+
   fn finalize_resources(
     &self,
     params: &mut PluginFinalizeResourcesHookParams,
@@ -4,12 +4,17 @@
 use std::{collections::HashMap, sync::Arc};
 
 use farmfe_core::{
+  config::{Config, ModuleFormat},
+  context::CompilationContext,
   enhanced_magic_string::bundle::Bundle,
+  error::CompilationError,
+  module::ModuleId,
   parking_lot::Mutex,
-  plugin::Plugin,
-  resource::resource_pot::{ResourcePotMetaData, ResourcePotType},
+  plugin::{OutputFilenameKind, Plugin, PluginHookContext, PluginOutputFilenameHookParams},
+  resource::resource_pot::{ResourcePot, ResourcePotMetaData, ResourcePotType},
+  swc_ecma_ast::EsReserved,
 };
-use resource_pot_to_bundle::{Polyfill, SharedBundle};
+use resource_pot_to_bundle::{preserved_module_ext, preserved_module_name, Polyfill, SharedBundle};
 
 pub mod resource_pot_to_bundle;
 
@@ -23,6 +28,8 @@ const MODULE_NEED_POLYFILLS: [Polyfill; 3] = [
 pub struct FarmPluginBundle {
   runtime_code: Mutex<Arc<String>>,
   bundle_map: Mutex<HashMap<String, Bundle>>,
+  /// resource pot id and name -> path of the preserved module, without extension
+  preserved_module_names: Mutex<HashMap<String, String>>,
 }
 
 impl FarmPluginBundle {
@@ -36,6 +43,130 @@ impl Plugin for FarmPluginBundle {
     "farm-plugin-bundle"
   }
 
+  fn config(&self, config: &mut Config) -> farmfe_core::error::Result<Option<()>> {
+    // variants share the module graph built for the primary output, which only works when all of them are bundled by this plugin
+    if !config.output.variants.is_empty()
+      && (!config.output.target_env.is_library()
+        || config
+          .output
+          .variants
+          .iter()
+          .any(|variant| !config.output.variant(variant).target_env.is_library()))
+    {
+      return Err(CompilationError::GenericError(
+        "output.variants is only supported by library targets, set output.targetEnv of all the outputs to `library`, `library-browser` or `library-node`".to_string(),
+      ));
+    }
+
+    // iife declares the global by `var {globalName} =`, so dotted names like `My.Lib` and reserved words like `class` are
+    // not supported
+    if let Some(global_name) = &config.output.global_name
+      && !is_valid_global_name(global_name)
+    {
+      return Err(CompilationError::GenericError(format!(
+        "output.globalName `{global_name}` is not a valid identifier"
+      )));
+    }
+
+    if !config.output.preserve_modules.enabled() {
+      return Ok(None);
+    }
+
+    if !config.output.target_env.is_library() {
+      return Err(CompilationError::GenericError(
+        "output.preserveModules is only supported by library targets, set output.targetEnv to `library`, `library-browser` or `library-node`".to_string(),
+      ));
+    }
+
+    if !matches!(
+      config.output.format,
+      ModuleFormat::EsModule | ModuleFormat::CommonJs
+    ) {
+      return Err(CompilationError::GenericError(format!(
+        "output.preserveModules does not support output.format `{}`, use `esm` or `cjs`",
+        config.output.format.as_str()
+      )));
+    }
+
+    Ok(Some(()))
+  }
+
+  /// with `output.preserveModules`, every module is placed in its own resource pot named by its path
+  fn partial_bundling(
+    &self,
+    modules: &Vec<ModuleId>,
+    context: &Arc<CompilationContext>,
+    _hook_context: &PluginHookContext,
+  ) -> farmfe_core::error::Result<Option<Vec<ResourcePot>>> {
+    if !context.config.output.preserve_modules.enabled() {
+      return Ok(None);
+    }
+
+    let module_graph = context.module_graph.read();
+    let mut preserved_module_names = self.preserved_module_names.lock();
+    preserved_module_names.clear();
+
+    // resource pot id -> the module it preserves, two modules must not be emitted to the same file
+    let mut preserved_modules = HashMap::<String, &ModuleId>::new();
+
+    let resource_pots = modules
+      .iter()
+      .map(|module_id| {
+        let module = module_graph.module(module_id).unwrap();
+        let name = preserved_module_name(module_id, &context.config);
+        let mut resource_pot = ResourcePot::new(
+          name.clone(),
+          ResourcePotType::from(module.module_type.clone()),
+        );
+        resource_pot.add_module(module_id.clone());
+
+        if let Some(existing) = preserved_modules.insert(resource_pot.id.clone(), module_id) {
+          return Err(CompilationError::GenericError(format!(
+            "output.preserveModules: modules `{}` and `{}` are both emitted as `{name}`",
+            existing.relative_path(),
+            module_id.relative_path()
+          )));
+        }
+
+        preserved_module_names.insert(resource_pot.id.clone(), name.clone());
+        preserved_module_names.insert(name.clone(), name);
+
+        Ok(resource_pot)
+      })
+      .collect::<farmfe_core::error::Result<Vec<_>>>()?;
+
+    Ok(Some(resource_pots))
+  }
+
+  /// the preserved modules are emitted by their paths, so that they can import each other by relative paths
+  fn output_filename(
+    &self,
+    params: &PluginOutputFilenameHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<String>> {
+    if matches!(params.kind, OutputFilenameKind::Asset) {
+      return Ok(None);
+    }
+
+    // keep the extension of the configured filename like `.mjs`, the imports of the other modules are rewritten to it
+    let ext = if params.ext == "js" {
+      preserved_module_ext(
+        &context.config,
+        matches!(params.kind, OutputFilenameKind::Entry),
+      )
+    } else {
+      "[ext]".to_string()
+    };
+
+    Ok(
+      self
+        .preserved_module_names
+        .lock()
+        .get(&params.name)
+        .map(|name| format!("{name}.{ext}")),
+    )
+  }
+
   fn process_resource_pots(
     &self,
     resource_pots: &mut Vec<&mut farmfe_core::resource::resource_pot::ResourcePot>,
@@ -48,6 +179,28 @@ impl Plugin for FarmPluginBundle {
 
     resource_pots.sort_by_key(|item| item.id.clone());
 
+    // only the resource pot of the entry is wrapped, other chunks could not be loaded by iife or umd bundles
+    if context.config.output.target_env.is_library()
+      && matches!(
+        context.config.output.format,
+        ModuleFormat::Iife | ModuleFormat::Umd
+      )
+    {
+      let js_resource_pots = resource_pots
+        .iter()
+        .filter(|item| item.resource_pot_type == ResourcePotType::Js)
+        .map(|item| item.id.as_str())
+        .collect::<Vec<_>>();
+
+      if js_resource_pots.len() > 1 {
+        return Err(CompilationError::GenericError(format!(
+          "output.format `{}` requires all modules to be bundled into a single chunk, but got chunks: {}. Remove the dynamic imports and the other entries, or configure partialBundling to generate a single chunk",
+          context.config.output.format.as_str(),
+          js_resource_pots.join(", ")
+        )));
+      }
+    }
+
     let r = resource_pots
       .iter()
       .filter(|item| {
@@ -56,46 +209,63 @@ impl Plugin for FarmPluginBundle {
       })
       .map(|item| &**item)
       .collect::<Vec<_>>();
-    let mut shared_bundle = SharedBundle::new(r, &module_graph, context)?;
 
-    let inject_resource_pot_id = resource_pots
-      .iter()
-      .find(|item| {
-        (context.config.output.target_env.is_library() && item.entry_module.is_some())
-          || matches!(item.resource_pot_type, ResourcePotType::Runtime)
-      })
-      .map(|i| i.id.clone());
+    // every preserved module is bundled separately, the other modules are imported from their own files
+    let groups = if context.config.output.preserve_modules.enabled() {
+      r.into_iter().map(|item| vec![item]).collect::<Vec<_>>()
+    } else {
+      vec![r]
+    };
+
+    let mut defer_minify = vec![];
 
-    if let Some(resource_pot_id) = inject_resource_pot_id {
-      let polyfill = &mut shared_bundle
-        .bundle_map
-        .get_mut(&resource_pot_id)
-        .unwrap()
-        .polyfill;
+    for group in groups {
+      let group_ids = group.iter().map(|item| item.id.clone()).collect::<Vec<_>>();
+      let mut shared_bundle = SharedBundle::new(group, &module_graph, context)?;
 
-      MODULE_NEED_POLYFILLS
+      let inject_resource_pot_id = resource_pots
         .iter()
-        .for_each(|item| polyfill.add(item.clone()));
-    }
+        .find(|item| {
+          group_ids.contains(&item.id)
+            && ((context.config.output.target_env.is_library() && item.entry_module.is_some())
+              || matches!(item.resource_pot_type, ResourcePotType::Runtime))
+        })
+        .map(|i| i.id.clone());
 
-    shared_bundle.render()?;
+      if let Some(resource_pot_id) = inject_resource_pot_id {
+        let polyfill = &mut shared_bundle
+          .bundle_map
+          .get_mut(&resource_pot_id)
+          .unwrap()
+          .polyfill;
 
-    let mut defer_minify = vec![];
-    for resource_pot in resource_pots.iter() {
-      if matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime)
-        || (context.config.output.target_env.is_library()
-          && resource_pot.resource_pot_type == ResourcePotType::Js)
-      {
-        let resource_pot_id = resource_pot.id.clone();
+        MODULE_NEED_POLYFILLS
+          .iter()
+          .for_each(|item| polyfill.add(item.clone()));
+      }
 
-        let bundle = shared_bundle.codegen(&resource_pot_id)?;
+      shared_bundle.render()?;
 
-        defer_minify.push(resource_pot_id.clone());
+      for resource_pot in resource_pots.iter() {
+        if !group_ids.contains(&resource_pot.id) {
+          continue;
+        }
+
+        if matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime)
+          || (context.config.output.target_env.is_library()
+            && resource_pot.resource_pot_type == ResourcePotType::Js)
+        {
+          let resource_pot_id = resource_pot.id.clone();
+
+          let bundle = shared_bundle.codegen(&resource_pot_id)?;
+
+          defer_minify.push(resource_pot_id.clone());
 
-        if matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime) {
-          *self.runtime_code.lock() = Arc::new(bundle.to_string());
-        } else {
-          self.bundle_map.lock().insert(resource_pot_id, bundle);
+          if matches!(resource_pot.resource_pot_type, ResourcePotType::Runtime) {
+            *self.runtime_code.lock() = Arc::new(bundle.to_string());
+          } else {
+            self.bundle_map.lock().insert(resource_pot_id, bundle);
+          }
         }
       }
     }
@@ -136,3 +306,15 @@ impl Plugin for FarmPluginBundle {
     Ok(None)
   }
 }
+
+fn is_valid_global_name(name: &str) -> bool {
+  let mut chars = name.chars();
+
+  chars
+    .next()
+    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    && !name.is_reserved()
+    && !name.is_reserved_in_strict_mode(false)
+    && !name.is_reserved_in_strict_bind()
+}
@@ -7,7 +7,10 @@ use std::{
 };
 
 use farmfe_core::{
-  config::{external::ExternalConfig, Config, Mode, ModuleFormat, TargetEnv},
+  config::{
+    external::{ExternalConfig, ExternalConfigItem},
+    Config, Mode, ModuleFormat, TargetEnv,
+  },
   context::CompilationContext,
   enhanced_magic_string::{
     bundle::{Bundle, BundleOptions},
@@ -33,8 +36,11 @@ use crate::resource_pot_to_bundle::{
     ExportSpecifierInfo, ImportSpecifierInfo, StmtAction, Variable,
   },
   polyfill::SimplePolyfill,
-  targets::generate::{
-    generate_bundle_import_by_bundle_reference, generate_export_by_reference_export,
+  preserve_modules::PreservedModuleSourceReplacer,
+  targets::{
+    generate::{generate_bundle_import_by_bundle_reference, generate_export_by_reference_export},
+    iife::generate::{ExternalGlobal, IifeGenerate},
+    umd::generate::UmdGenerate,
   },
   uniq_name::{BundleVariable, FindModuleExportResult},
 };
@@ -210,7 +216,7 @@ impl<'a> BundleAnalyzer<'a> {
   ) -> Result<()> {
     farm_profile_function!("");
 
-    let is_format_to_commonjs = self.context.config.output.format == ModuleFormat::CommonJs;
+    let is_format_to_commonjs = self.context.config.output.format.is_commonjs_like();
 
     for module_id in &self.ordered_modules {
       farm_profile_scope!(format!(
@@ -584,7 +590,7 @@ impl<'a> BundleAnalyzer<'a> {
 
                             if reference.all {
                               if is_hybrid_dynamic
-                                && self.context.config.output.format == ModuleFormat::CommonJs
+                                && self.context.config.output.format.is_commonjs_like()
                               {
                                 self.bundle_reference.add_import(
                                   &ImportSpecifierInfo::Namespace(
@@ -680,6 +686,30 @@ impl<'a> BundleAnalyzer<'a> {
                         }
                         FindModuleExportResult::External(_, target_source) => {
                           if is_reference_by_another {
+                            // other preserved modules are external to the bundle of the module
+                            // export { name } from "./other.js"
+                            // => commonjs
+                            // var name = other_ns.name;
+                            // module.exports.name = name;
+                            if is_format_to_commonjs
+                              && !is_find_default
+                              && self.context.config.output.preserve_modules.enabled()
+                            {
+                              let rename = self.bundle_reference.add_import(
+                                &ImportSpecifierInfo::Named {
+                                  local: variable.local(),
+                                  imported: None,
+                                },
+                                target_source.clone().into(),
+                                &self.bundle_variable.borrow(),
+                              )?;
+
+                              self
+                                .bundle_variable
+                                .borrow_mut()
+                                .set_uniq_name_both(rename, variable.local());
+                            }
+
                             self.bundle_reference.add_reference_export(
                               specify,
                               target_source.into(),
@@ -986,6 +1016,21 @@ impl<'a> BundleAnalyzer<'a> {
       };
     }
 
+    // other modules are emitted as separate files, import them by the relative paths of the files
+    if self.context.config.output.preserve_modules.enabled() {
+      for module_id in &self.ordered_modules {
+        if let Some(module_analyzer) = module_analyzer_manager.module_analyzer_mut(module_id) {
+          module_analyzer
+            .ast
+            .visit_mut_with(&mut PreservedModuleSourceReplacer {
+              module_id,
+              module_graph: self.module_graph,
+              config: &self.context.config,
+            });
+        }
+      }
+    }
+
     Ok(())
   }
 
@@ -1095,7 +1140,7 @@ impl<'a> BundleAnalyzer<'a> {
     };
 
     let injectable_resource_pot = (config.output.target_env.is_library()
-      && self.resource_pot.entry_module.is_some())
+      && (self.resource_pot.entry_module.is_some() || config.output.preserve_modules.enabled()))
       || matches!(
         self.resource_pot.resource_pot_type,
         ResourcePotType::Runtime
@@ -1107,6 +1152,56 @@ impl<'a> BundleAnalyzer<'a> {
       }
     }
 
+    if config.output.target_env.is_library() && self.resource_pot.entry_module.is_some() {
+      match config.output.format {
+        ModuleFormat::Iife => IifeGenerate::wrap(
+          &mut bundle,
+          config.output.global_name.as_deref(),
+          &self.external_globals(config)?,
+        ),
+        ModuleFormat::Umd => UmdGenerate::wrap(
+          &mut bundle,
+          config.output.global_name.as_deref(),
+          &self.external_globals(config)?,
+        ),
+        _ => {}
+      }
+    }
+
     Ok(bundle)
   }
+
+  /// iife and umd bundles can not import the external modules, they are read from the global variables
+  /// configured by `external: [{ "jquery": "$" }]`
+  fn external_globals(&self, config: &Config) -> Result<Vec<ExternalGlobal>> {
+    let external_config = ExternalConfig::from(config);
+    let mut externals = self
+      .ordered_modules
+      .iter()
+      .flat_map(|module_id| self.module_graph.dependencies_ids(module_id))
+      .filter(|dep| {
+        self
+          .module_graph
+          .module(dep)
+          .is_some_and(|module| module.external)
+      })
+      .map(|dep| dep.to_string())
+      .collect::<Vec<_>>();
+    externals.sort();
+    externals.dedup();
+
+    externals
+      .into_iter()
+      .map(|source| match external_config.find_match(&source) {
+        Some(ExternalConfigItem::Object(external)) => {
+          let global_name = external.global_name.clone();
+          Ok((source, global_name))
+        }
+        _ => Err(CompilationError::GenericError(format!(
+          "external module `{source}` needs a global name for the {} format, configure it by `external: [{{ \"{source}\": \"GlobalName\" }}]`",
+          config.output.format.as_str()
+        ))),
+      })
+      .collect()
+  }
 }
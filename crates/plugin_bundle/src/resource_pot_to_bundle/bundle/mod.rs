@@ -71,6 +71,9 @@ pub struct ModuleAnalyzerManager<'a> {
   ///
   ///
   pub module_global_uniq_name: ModuleGlobalUniqName,
+  /// with `output.preserveModules`, the modules that are not analyzed are emitted as other files,
+  /// they are imported like external modules
+  pub preserve_modules: bool,
   module_graph: &'a ModuleGraph,
 }
 
@@ -174,6 +177,7 @@ impl<'a> ModuleAnalyzerManager<'a> {
       module_map,
       namespace_modules: HashSet::new(),
       module_global_uniq_name: ModuleGlobalUniqName::new(),
+      preserve_modules: false,
       module_graph,
     }
   }
@@ -302,10 +306,9 @@ impl<'a> ModuleAnalyzerManager<'a> {
 
   #[inline]
   pub fn is_external(&self, module_id: &ModuleId) -> bool {
-    self
-      .module_graph
-      .module(module_id)
-      .is_some_and(|m| m.external)
+    self.module_graph.module(module_id).is_some_and(|m| {
+      m.external || (self.preserve_modules && !self.module_map.contains_key(module_id))
+    })
   }
 
   #[inline]
@@ -51,6 +51,7 @@ pub fn parse_module_item(string: &str) -> Result<ModuleItem> {
     .map_err(|msg| CompilationError::ParseError {
       resolved_path: "unknown temp parser".to_string(),
       msg: format!("failed parse content, cause: {msg:#?}"),
+      loc: None,
     })
 }
 
@@ -17,6 +17,7 @@ use farmfe_core::{
   swc_ecma_ast::Id,
 };
 pub use polyfill::{Polyfill, SimplePolyfill};
+pub use preserve_modules::{preserved_module_ext, preserved_module_name};
 
 pub use crate::resource_pot_to_bundle::bundle::bundle_analyzer::BundleAnalyzer;
 
@@ -69,6 +70,7 @@ impl Hash for Var {
 }
 
 mod polyfill;
+mod preserve_modules;
 mod uniq_name;
 
 pub type BundleMap<'a> = HashMap<ResourcePotId, BundleAnalyzer<'a>>;
@@ -134,10 +136,12 @@ impl<'a> SharedBundle<'a> {
         .into_par_iter()
         .try_for_each(|module_id| {
           let is_dynamic = module_graph.is_dynamic(module_id);
-          let is_entry = resource_pot
-            .entry_module
-            .as_ref()
-            .is_some_and(|item| item == module_id);
+          // every preserved module is emitted as a separate file that exports its own exports
+          let is_entry = context.config.output.preserve_modules.enabled()
+            || resource_pot
+              .entry_module
+              .as_ref()
+              .is_some_and(|item| item == module_id);
           let module = module_graph.module(module_id).unwrap();
           let is_runtime = matches!(module.module_type, ModuleType::Runtime);
 
@@ -166,8 +170,9 @@ impl<'a> SharedBundle<'a> {
     }
 
     // modules manager
-    let module_analyzer_manager =
+    let mut module_analyzer_manager =
       ModuleAnalyzerManager::new(module_analyzer_map.into_inner().unwrap(), module_graph);
+    module_analyzer_manager.preserve_modules = context.config.output.preserve_modules.enabled();
 
     Ok(Self {
       module_analyzer_manager,
@@ -0,0 +1,169 @@
+use std::path::Path;
+
+use farmfe_core::{
+  config::{bool_or_obj::BoolOrObj, Config, OutputPreserveModulesConfig},
+  module::{module_graph::ModuleGraph, ModuleId},
+  swc_ecma_ast::{CallExpr, Callee, ExportAll, Expr, ImportDecl, Lit, NamedExport, Str},
+};
+use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
+use farmfe_utils::relative;
+
+/// the directory that the paths of the preserved modules are relative to
+fn preserve_modules_root(config: &Config) -> String {
+  match &config.output.preserve_modules {
+    BoolOrObj::Obj(OutputPreserveModulesConfig { root: Some(root) }) => {
+      if Path::new(root).is_absolute() {
+        root.clone()
+      } else {
+        Path::new(&config.root)
+          .join(root)
+          .to_string_lossy()
+          .to_string()
+      }
+    }
+    _ => config.root.clone(),
+  }
+}
+
+/// Name of the resource pot of a preserved module, the module is emitted as `<name>.<ext>`.
+///
+/// e.g. `src/components/button.tsx` is named `components/button` when `output.preserveModules.root` is `src`.
+/// Modules outside of the root are placed under `_external` by their paths relative to `root`, with `..` replaced by `_`,
+/// so they do not collide with the modules under the root. Virtual modules are placed under `_virtual`.
+pub fn preserved_module_name(module_id: &ModuleId, config: &Config) -> String {
+  let resolved_path = module_id.resolved_path(&config.root);
+
+  let path = if !Path::new(&resolved_path).is_absolute() {
+    format!("_virtual/{}", module_id.relative_path().replace(':', "_"))
+  } else {
+    let path = relative(&preserve_modules_root(config), &resolved_path);
+
+    if path.starts_with("..") {
+      let path = relative(&config.root, &resolved_path)
+        .split('/')
+        .map(|segment| if segment == ".." { "_" } else { segment })
+        .collect::<Vec<_>>()
+        .join("/");
+
+      format!("_external/{path}")
+    } else {
+      path
+    }
+  };
+
+  // strip the extension of the file name
+  match path.rfind('.') {
+    Some(index) if index > path.rfind('/').map(|i| i + 1).unwrap_or(0) => path[..index].to_string(),
+    _ => path,
+  }
+}
+
+/// Extension of the emitted js file of a preserved module. It's the literal extension of `output.entryFilename` for
+/// entries and of `output.filename` for the other modules, e.g. `mjs` for `[entryName].mjs`, and `js` when the template
+/// ends with `[ext]`
+pub fn preserved_module_ext(config: &Config, is_entry: bool) -> String {
+  let template = if is_entry {
+    &config.output.entry_filename
+  } else {
+    &config.output.filename
+  };
+  let file_name = template.rsplit('/').next().unwrap_or(template);
+
+  match file_name.rsplit_once('.') {
+    Some((_, ext)) if !ext.is_empty() && !ext.contains('[') => ext.to_string(),
+    _ => "js".to_string(),
+  }
+}
+
+/// Rewrite the sources of imports, exports, `require` and dynamic `import` that point to other preserved modules
+/// to the relative paths of their emitted files.
+///
+/// ```js
+/// // components/button.js
+/// import { format } from "src/utils/format.ts";
+/// // =>
+/// import { format } from "../utils/format.js";
+/// ```
+pub struct PreservedModuleSourceReplacer<'a> {
+  pub module_id: &'a ModuleId,
+  pub module_graph: &'a ModuleGraph,
+  pub config: &'a Config,
+}
+
+impl<'a> PreservedModuleSourceReplacer<'a> {
+  fn replace(&self, src: &mut Str) {
+    let source = src.value.to_string();
+    // sources of generated imports are module ids, the others are the original sources
+    let dep = Some(ModuleId::from(source.as_str()))
+      .filter(|id| self.module_graph.has_module(id))
+      .or_else(|| {
+        self
+          .module_graph
+          .get_dep_by_source_optional(self.module_id, &source, None)
+      });
+
+    let Some(dep) = dep else {
+      return;
+    };
+
+    if !self
+      .module_graph
+      .module(&dep)
+      .is_some_and(|m| !m.external && m.module_type.is_script())
+    {
+      return;
+    }
+
+    let importer = preserved_module_name(self.module_id, self.config);
+    let importer_dir = importer
+      .rfind('/')
+      .map(|index| &importer[..index])
+      .unwrap_or_default();
+    let ext = preserved_module_ext(self.config, self.module_graph.entries.contains_key(&dep));
+    let path = relative(
+      &format!("/{importer_dir}"),
+      &format!("/{}.{ext}", preserved_module_name(&dep, self.config)),
+    );
+
+    let path = if path.starts_with('.') {
+      path
+    } else {
+      format!("./{path}")
+    };
+
+    *src = Str::from(path.as_str());
+  }
+}
+
+impl<'a> VisitMut for PreservedModuleSourceReplacer<'a> {
+  fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
+    self.replace(&mut import.src);
+  }
+
+  fn visit_mut_export_all(&mut self, export: &mut ExportAll) {
+    self.replace(&mut export.src);
+  }
+
+  fn visit_mut_named_export(&mut self, export: &mut NamedExport) {
+    if let Some(src) = export.src.as_mut() {
+      self.replace(src);
+    }
+  }
+
+  fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+    let is_require_or_import = match &call_expr.callee {
+      Callee::Import(_) => true,
+      Callee::Expr(box Expr::Ident(ident)) => ident.sym == *"require",
+      _ => false,
+    };
+
+    if is_require_or_import && call_expr.args.len() == 1 {
+      if let box Expr::Lit(Lit::Str(src)) = &mut call_expr.args[0].expr {
+        self.replace(src);
+        return;
+      }
+    }
+
+    call_expr.visit_mut_children_with(self);
+  }
+}
@@ -96,8 +96,11 @@ impl<'a> VisitMut for CJSReplace<'a> {
                     prop: MemberProp::Ident("nodeRequire".into()),
                   })));
                 }
-              } else {
-                // browser
+              } else if !matches!(
+                self.config.output.format,
+                ModuleFormat::Iife | ModuleFormat::Umd
+              ) {
+                // browser, iife and umd keep the `require` calls, their wrapper provides a `require` that maps externals to globals
                 self.polyfill.add(Polyfill::BrowserExternalRequire);
 
                 let replace_source = self
@@ -263,7 +263,7 @@ pub fn generate_export_by_reference_export(
       module_analyzer_manager,
       context,
       polyfill,
-      &mut is_patch_esm_flag
+      &mut is_patch_esm_flag,
     )?);
   }
 
@@ -289,14 +289,16 @@ pub fn generate_export_as_module_export(
       EsmGenerate::generate_export(source, export, bundle_variable, module_analyzer_manager)
     }
 
-    (_, ModuleFormat::CommonJs) => CjsGenerate::generate_export(
-      source,
-      export,
-      bundle_variable,
-      module_analyzer_manager,
-      polyfill,
-      is_patch_esm_flag
-    ),
+    (_, ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd) => {
+      CjsGenerate::generate_export(
+        source,
+        export,
+        bundle_variable,
+        module_analyzer_manager,
+        polyfill,
+        is_patch_esm_flag,
+      )
+    }
   }
 }
 
@@ -312,7 +314,8 @@ pub fn generate_bundle_import_by_bundle_reference(
   let mut patch_import_to_module = vec![];
 
   match format {
-    ModuleFormat::CommonJs => {
+    // iife and umd are generated as commonjs and wrapped after codegen
+    ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
       patch_import_to_module.extend(CjsGenerate::generate_import(
         bundle_variable,
         &bundle_reference.import_map,
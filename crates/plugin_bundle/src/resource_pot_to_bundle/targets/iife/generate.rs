@@ -0,0 +1,65 @@
+use farmfe_core::{enhanced_magic_string::bundle::Bundle, serde_json};
+
+/// An external module of the library and the global variable it is read from, like `("jquery", "$")`
+pub type ExternalGlobal = (String, String);
+
+pub struct IifeGenerate {}
+
+impl IifeGenerate {
+  /// the bundle is generated as commonjs, wrap it with a function that provides `module`, `exports` and `require`
+  ///
+  /// ```js
+  /// var MyLib = (function (require) {
+  /// var module = { exports: {} }, exports = module.exports;
+  /// // ...bundle
+  /// return module.exports;
+  /// })(function (id) { switch (id) { case "jquery": return $; } throw new Error(...); });
+  /// ```
+  pub fn wrap(bundle: &mut Bundle, global_name: Option<&str>, externals: &[ExternalGlobal]) {
+    let assignment = global_name
+      .map(|name| format!("var {name} = "))
+      .unwrap_or_default();
+
+    bundle.prepend(&format!("{assignment}{}", factory_intro()));
+    bundle.append(
+      &format!(
+        "{}({});",
+        factory_outro(),
+        external_globals_require(externals, |global| global.to_string())
+      ),
+      None,
+    );
+  }
+}
+
+/// start of the factory function that receives `require` and returns the exports of the library
+pub fn factory_intro() -> String {
+  "(function (require) {\nvar module = { exports: {} }, exports = module.exports;\n".to_string()
+}
+
+pub fn factory_outro() -> String {
+  "return module.exports;\n})".to_string()
+}
+
+/// a `require` function that maps the external modules to their global variables,
+/// `access` generates the expression that reads the global variable
+pub fn external_globals_require(
+  externals: &[ExternalGlobal],
+  access: impl Fn(&str) -> String,
+) -> String {
+  let cases = externals
+    .iter()
+    .map(|(source, global)| {
+      format!(
+        "case {}: return {};",
+        serde_json::to_string(source).unwrap(),
+        access(global)
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(" ");
+
+  format!(
+    "function (id) {{ switch (id) {{ {cases} }} throw new Error(\"Cannot find external module \" + id); }}"
+  )
+}
@@ -2,3 +2,5 @@ pub mod cjs;
 pub mod dynamic_import;
 pub mod esm;
 pub mod generate;
+pub mod iife;
+pub mod umd;
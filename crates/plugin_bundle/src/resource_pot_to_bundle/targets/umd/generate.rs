@@ -0,0 +1,42 @@
+use farmfe_core::{enhanced_magic_string::bundle::Bundle, serde_json};
+
+use crate::resource_pot_to_bundle::targets::iife::generate::{
+  external_globals_require, factory_intro, factory_outro, ExternalGlobal,
+};
+
+pub struct UmdGenerate {}
+
+impl UmdGenerate {
+  /// the bundle is generated as commonjs and wrapped by the factory of [IifeGenerate](crate::resource_pot_to_bundle::targets::iife::generate::IifeGenerate),
+  /// the factory is called with the `require` of commonjs, amd or the globals
+  ///
+  /// ```js
+  /// (function (root, factory) {
+  ///   if (typeof exports === "object" && typeof module !== "undefined") module.exports = factory(require);
+  ///   else if (typeof define === "function" && define.amd) define(["require", "jquery"], factory);
+  ///   else { root = typeof globalThis !== "undefined" ? globalThis : root || self; root["MyLib"] = factory(function (id) { ... }); }
+  /// })(this, function (require) { ... });
+  /// ```
+  pub fn wrap(bundle: &mut Bundle, global_name: Option<&str>, externals: &[ExternalGlobal]) {
+    let amd_deps = std::iter::once("require")
+      .chain(externals.iter().map(|(source, _)| source.as_str()))
+      .collect::<Vec<_>>();
+    let globals_require = external_globals_require(externals, |global| {
+      format!("root[{}]", serde_json::to_string(global).unwrap())
+    });
+    let assign_global = global_name
+      .map(|name| format!("root[{}] = ", serde_json::to_string(name).unwrap()))
+      .unwrap_or_default();
+
+    bundle.prepend(&format!(
+      "(function (root, factory) {{\n\
+       if (typeof exports === \"object\" && typeof module !== \"undefined\") module.exports = factory(require);\n\
+       else if (typeof define === \"function\" && define.amd) define({}, factory);\n\
+       else {{ root = typeof globalThis !== \"undefined\" ? globalThis : root || self; {assign_global}factory({globals_require}); }}\n\
+       }})(this, {}",
+      serde_json::to_string(&amd_deps).unwrap(),
+      factory_intro()
+    ));
+    bundle.append(&format!("{});", factory_outro()), None);
+  }
+}
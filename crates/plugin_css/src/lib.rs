@@ -66,6 +66,7 @@ lazy_static! {
 }
 
 mod dep_analyzer;
+mod purge;
 mod source_replacer;
 pub mod transform_css_to_script;
 
@@ -353,13 +354,15 @@ impl Plugin for FarmPluginCss {
     export default {{{}}}
     "#,
           css_modules_module_id.to_string(),
-          dynamic_import_of_composes
-            .into_iter()
-            .fold(Vec::new(), |mut acc, (from, name)| {
-              acc.push(format!("import {name} from \"{from}\""));
-              acc
-            })
-            .join(";\n"),
+          {
+            // sort the composes imports so the generated code does not depend on hash map order
+            let mut imports = dynamic_import_of_composes
+              .into_iter()
+              .map(|(from, name)| format!("import {name} from \"{from}\""))
+              .collect::<Vec<_>>();
+            imports.sort();
+            imports.join(";\n")
+          },
           export_names
             .iter()
             .map(|(name, classes)| format!("\"{}\": `{}`", name, classes.join(" ").trim()))
@@ -482,6 +485,27 @@ impl Plugin for FarmPluginCss {
     Ok(None)
   }
 
+  /// Remove unused css rules when `css.purge` is enabled. Only runs in production, the purge
+  /// mutates the css ast in place and selectors added later by hmr would be lost in development.
+  fn optimize_module_graph(
+    &self,
+    module_graph: &mut ModuleGraph,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    if !matches!(context.config.mode, farmfe_core::config::Mode::Production) {
+      return Ok(None);
+    }
+
+    let Some(purge_config) = &context.config.css.purge else {
+      return Ok(None);
+    };
+
+    let stats = purge::purge_css_modules(module_graph, purge_config, &context.config.root);
+    context.record_manager.set_css_purge_stats(stats);
+
+    Ok(Some(()))
+  }
+
   fn build_end(&self, context: &Arc<CompilationContext>) -> farmfe_core::error::Result<Option<()>> {
     if !matches!(context.config.mode, farmfe_core::config::Mode::Development)
       || !matches!(context.config.output.target_env, TargetEnv::Browser)
@@ -576,6 +600,7 @@ impl Plugin for FarmPluginCss {
             &resources_map,
             context.config.output.public_path.clone(),
             context.config.resolve.alias.clone(),
+            context,
           );
 
           if minify_enabled {
@@ -822,6 +847,7 @@ pub fn source_replace(
   resources_map: &HashMap<String, Resource>,
   public_path: String,
   alias: HashMap<String, String>,
+  context: &CompilationContext,
 ) {
   let mut source_replacer = SourceReplacer::new(
     module_id.clone(),
@@ -829,6 +855,7 @@ pub fn source_replace(
     resources_map,
     public_path,
     alias,
+    context,
   );
   stylesheet.visit_mut_with(&mut source_replacer);
 }
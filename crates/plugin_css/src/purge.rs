@@ -0,0 +1,88 @@
+//! Remove css rules whose selectors can not match any content of the module graph.
+//!
+//! Candidates(class names, ids and tag names) are collected from string literals, template literals, object keys and jsx of
+//! script modules, and from the tags and attributes of html modules.
+use std::collections::HashSet;
+
+use farmfe_core::{
+  config::CssPurgeConfig,
+  module::{module_graph::ModuleGraph, ModuleMetaData, ModuleType},
+  stats::{CssPurgeModuleStats, CssPurgeStats},
+  swc_css_ast::Stylesheet,
+};
+use farmfe_toolkit::{
+  css::{
+    codegen_css_stylesheet,
+    purge::{CssPurger, PurgeCandidatesCollector},
+  },
+  swc_ecma_visit::VisitWith as _,
+  swc_html_visit::VisitWith as _,
+};
+
+/// Collect all class, id and tag candidates of script and html modules in the module graph
+pub fn collect_purge_candidates(module_graph: &ModuleGraph) -> HashSet<String> {
+  let mut collector = PurgeCandidatesCollector::default();
+
+  for module in module_graph.modules() {
+    match &*module.meta {
+      ModuleMetaData::Script(script) => script.ast.visit_with(&mut collector),
+      ModuleMetaData::Html(html) => html.ast.visit_with(&mut collector),
+      _ => {}
+    }
+  }
+
+  collector.candidates
+}
+
+/// Purge all css modules matched by the config, return the report of removed rules and bytes
+pub fn purge_css_modules(
+  module_graph: &mut ModuleGraph,
+  config: &CssPurgeConfig,
+  root: &str,
+) -> CssPurgeStats {
+  let candidates = collect_purge_candidates(module_graph);
+  let purger = CssPurger::new(&candidates, &config.safelist);
+  let mut report = CssPurgeStats::default();
+
+  for module in module_graph.modules_mut() {
+    if module.module_type != ModuleType::Css || module.external {
+      continue;
+    }
+
+    let path = module.id.resolved_path(root);
+
+    if (!config.include.is_empty() && !config.include.iter().any(|r| r.is_match(&path)))
+      || config.exclude.iter().any(|r| r.is_match(&path))
+    {
+      continue;
+    }
+
+    let ModuleMetaData::Css(meta) = &mut *module.meta else {
+      continue;
+    };
+
+    let size_before = stylesheet_size(&meta.ast);
+    let removed_rules = purger.purge_stylesheet(&mut meta.ast);
+
+    if removed_rules == 0 {
+      continue;
+    }
+
+    let removed_bytes = size_before.saturating_sub(stylesheet_size(&meta.ast));
+    report.removed_rules += removed_rules;
+    report.removed_bytes += removed_bytes;
+    report.modules.insert(
+      module.id.clone(),
+      CssPurgeModuleStats {
+        removed_rules,
+        removed_bytes,
+      },
+    );
+  }
+
+  report
+}
+
+fn stylesheet_size(stylesheet: &Stylesheet) -> usize {
+  codegen_css_stylesheet(stylesheet, None, true).0.len()
+}
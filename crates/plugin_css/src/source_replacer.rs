@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use farmfe_core::{
+  context::CompilationContext,
   module::{module_graph::ModuleGraph, ModuleId},
   plugin::ResolveKind,
   resource::{Resource, ResourceOrigin},
@@ -20,6 +21,7 @@ pub struct SourceReplacer<'a> {
   resources_map: &'a HashMap<String, Resource>,
   public_path: String,
   alias: HashMap<String, String>,
+  context: &'a CompilationContext,
 }
 
 impl<'a> SourceReplacer<'a> {
@@ -29,6 +31,7 @@ impl<'a> SourceReplacer<'a> {
     resources_map: &'a HashMap<String, Resource>,
     public_path: String,
     alias: HashMap<String, String>,
+    context: &'a CompilationContext,
   ) -> Self {
     Self {
       module_id,
@@ -36,6 +39,7 @@ impl<'a> SourceReplacer<'a> {
       resources_map,
       public_path,
       alias,
+      context,
     }
   }
 }
@@ -55,6 +59,10 @@ impl<'a> VisitMut for SourceReplacer<'a> {
               Some(ResolveKind::CssUrl),
             );
 
+            if let Some(data_uri) = self.context.inlined_asset(&dep_module) {
+              return data_uri;
+            }
+
             for resource in self.resources_map.values() {
               if let ResourceOrigin::Module(m_id) = &resource.origin {
                 if &dep_module == m_id {
@@ -131,7 +131,13 @@ pub fn transform_css_to_script_modules(
           .source_map_chain = vec![];
       }
 
-      let css_code = wrapper_style_load(&css_code, module_id.to_string(), &css_deps, src_map);
+      let css_code = wrapper_style_load(
+        &css_code,
+        module_id.to_string(),
+        &css_deps,
+        src_map,
+        context.config.html.csp_nonce.is_some(),
+      );
       let css_code = Arc::new(css_code);
       let (cm, _) = create_swc_source_map(Source {
         path: PathBuf::from(module_id.to_string()),
@@ -207,6 +213,7 @@ pub fn transform_css_stylesheet(
     &resources_map,
     context.config.output.public_path.clone(),
     context.config.resolve.alias.clone(),
+    context,
   );
 
   stylesheet
@@ -254,6 +261,7 @@ pub fn wrapper_style_load(
   id: String,
   css_deps: &String,
   src_map: Option<String>,
+  csp_nonce: bool,
 ) -> String {
   format!(
     r#"
@@ -262,7 +270,7 @@ const farmId = '{}';
 {}
 const previousStyle = document.querySelector(`style[data-farm-id="${{farmId}}"]`);
 const style = document.createElement('style');
-style.setAttribute('data-farm-id', farmId);
+style.setAttribute('data-farm-id', farmId);{}
 style.innerHTML = cssCode;
 if (previousStyle) {{
 previousStyle.replaceWith(style);
@@ -291,5 +299,14 @@ if (module.meta.hot) {{
     ),
     id.replace('\\', "\\\\"),
     css_deps,
+    if csp_nonce {
+      r#"
+const cspNonceMeta = document.querySelector('meta[property="csp-nonce"]');
+if (cspNonceMeta) {
+  style.nonce = cspNonceMeta.nonce;
+}"#
+    } else {
+      ""
+    },
   )
 }
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use farmfe_core::{
+  context::CompilationContext,
+  module::{module_graph::ModuleGraph, ModuleId},
+  plugin::ResolveKind,
+  resource::{Resource, ResourceOrigin},
+  swc_html_ast::Element,
+};
+use farmfe_toolkit::swc_html_visit::{VisitMut, VisitMutWith};
+
+use crate::deps_analyzer::{
+  html_asset_attr, is_asset_source, parse_srcset, HtmlAssetAttr, CSS_URL_REGEX,
+};
+
+/// Replace the asset urls of html to the final public paths of the emitted asset resources
+pub struct HtmlAssetsReplacer<'a> {
+  html_id: &'a ModuleId,
+  module_graph: &'a ModuleGraph,
+  /// key: module id of the asset, value: resource name
+  asset_resources: HashMap<&'a ModuleId, &'a str>,
+  public_path: String,
+  context: &'a CompilationContext,
+}
+
+impl<'a> HtmlAssetsReplacer<'a> {
+  pub fn new(
+    html_id: &'a ModuleId,
+    module_graph: &'a ModuleGraph,
+    resources_map: &'a HashMap<String, Resource>,
+    public_path: &str,
+    context: &'a CompilationContext,
+  ) -> Self {
+    let asset_resources = resources_map
+      .values()
+      .filter_map(|resource| match &resource.origin {
+        ResourceOrigin::Module(module_id) => Some((module_id, resource.name.as_str())),
+        _ => None,
+      })
+      .collect();
+    // keep the same as the url replacing of css, `/` or `publicPath/`
+    let public_path = format!("{}/", public_path.trim_end_matches('/'));
+
+    Self {
+      html_id,
+      module_graph,
+      asset_resources,
+      public_path,
+      context,
+    }
+  }
+
+  fn replace_url(&self, url: &str) -> String {
+    if !is_asset_source(url) {
+      return url.to_string();
+    }
+
+    let Some(dep) =
+      self
+        .module_graph
+        .get_dep_by_source_optional(self.html_id, url, Some(ResolveKind::HtmlAsset))
+    else {
+      return url.to_string();
+    };
+
+    if let Some(data_uri) = self.context.inlined_asset(&dep) {
+      return data_uri;
+    }
+
+    self
+      .asset_resources
+      .get(&dep)
+      .map(|name| format!("{}{name}", self.public_path))
+      .unwrap_or_else(|| url.to_string())
+  }
+
+  fn replace_attr_value(&self, kind: HtmlAssetAttr, value: &str) -> String {
+    match kind {
+      HtmlAssetAttr::Url => self.replace_url(value.trim()),
+      HtmlAssetAttr::Srcset => parse_srcset(value)
+        .into_iter()
+        .map(|(url, descriptor)| {
+          let url = self.replace_url(&url);
+
+          if descriptor.is_empty() {
+            url
+          } else {
+            format!("{url} {descriptor}")
+          }
+        })
+        .collect::<Vec<_>>()
+        .join(", "),
+      HtmlAssetAttr::InlineStyle => CSS_URL_REGEX
+        .replace_all(value, |c: &farmfe_toolkit::regex::Captures| {
+          // inlined svg data uris may contain quotes, escape the quote of the url. Unquoted urls can not contain quotes,
+          // so they are single quoted
+          if let Some(url) = c.get(2) {
+            let url = self.replace_url(url.as_str().trim()).replace('"', "%22");
+            format!("url(\"{url}\")")
+          } else {
+            let url = c.get(1).or_else(|| c.get(3)).map(|m| m.as_str().trim());
+            let url = self.replace_url(url.unwrap_or_default()).replace('\'', "%27");
+            format!("url('{url}')")
+          }
+        })
+        .to_string(),
+    }
+  }
+}
+
+impl<'a> VisitMut for HtmlAssetsReplacer<'a> {
+  fn visit_mut_element(&mut self, element: &mut Element) {
+    let replaced = element
+      .attributes
+      .iter()
+      .enumerate()
+      .filter_map(|(i, attr)| {
+        let kind = html_asset_attr(element, &attr.name)?;
+        let value = attr.value.as_ref()?;
+
+        Some((i, self.replace_attr_value(kind, value)))
+      })
+      .collect::<Vec<_>>();
+
+    for (i, value) in replaced {
+      let attr = &mut element.attributes[i];
+
+      if attr.value.as_deref() != Some(value.as_str()) {
+        attr.value = Some(value.into());
+        attr.raw_value = None;
+      }
+    }
+
+    element.visit_mut_children_with(self);
+  }
+}
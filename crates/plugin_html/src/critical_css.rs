@@ -0,0 +1,97 @@
+//! Inline the css of html entries into a `<style>` tag to avoid render blocking `<link>` requests.
+//!
+//! If the initial css is larger than `html.criticalCss.threshold`, only the rules that may match the html document are inlined,
+//! and the original stylesheets are still loaded without blocking rendering. The original stylesheets are loaded as a whole
+//! so the order of the rules, which the cascade depends on, is kept.
+use std::{collections::HashMap, sync::Arc};
+
+use farmfe_core::{
+  config::html::HtmlCriticalCssConfig,
+  resource::Resource,
+  swc_css_ast::Stylesheet,
+  swc_html_ast::Document,
+};
+use farmfe_toolkit::{
+  css::{
+    codegen_css_stylesheet, parse_css_stylesheet,
+    purge::{CssPurger, PurgeCandidatesCollector},
+  },
+  swc_html_visit::VisitWith,
+};
+
+pub struct CriticalCss {
+  /// css code that is inlined into `<head>`
+  pub code: String,
+  /// names of the original css resources that are loaded by a non blocking `<link>`, empty if all css is inlined
+  pub deferred_resources: Vec<String>,
+}
+
+pub fn extract_critical_css(
+  html_ast: &Document,
+  css_resources: &[String],
+  resources_map: &HashMap<String, Resource>,
+  config: &HtmlCriticalCssConfig,
+  minify: bool,
+) -> farmfe_core::error::Result<CriticalCss> {
+  let mut stylesheets = vec![];
+  let mut total_size = 0;
+
+  for name in css_resources {
+    let Some(resource) = resources_map.get(name) else {
+      continue;
+    };
+
+    total_size += resource.bytes.len();
+    let content = Arc::new(String::from_utf8_lossy(&resource.bytes).to_string());
+    stylesheets.push((name, parse_css_stylesheet(name, content)?.ast));
+  }
+
+  let inline_all = config
+    .threshold
+    .map(|threshold| total_size <= threshold)
+    .unwrap_or(true);
+
+  if inline_all {
+    let code = stylesheets
+      .iter()
+      .map(|(_, stylesheet)| codegen_css_stylesheet(stylesheet, None, minify).0)
+      .collect::<Vec<_>>()
+      .join("\n");
+
+    return Ok(CriticalCss {
+      code,
+      deferred_resources: vec![],
+    });
+  }
+
+  let mut collector = PurgeCandidatesCollector::default();
+  html_ast.visit_with(&mut collector);
+  let safelist = vec![];
+  let purger = CssPurger::new(&collector.candidates, &safelist);
+
+  let mut critical_code = vec![];
+  let mut deferred_resources = vec![];
+
+  for (name, stylesheet) in stylesheets {
+    let critical_rules = stylesheet
+      .rules
+      .into_iter()
+      .filter(|rule| purger.is_rule_used(rule))
+      .collect::<Vec<_>>();
+
+    if !critical_rules.is_empty() {
+      let critical = Stylesheet {
+        span: stylesheet.span,
+        rules: critical_rules,
+      };
+      critical_code.push(codegen_css_stylesheet(&critical, None, minify).0);
+    }
+
+    deferred_resources.push(name.clone());
+  }
+
+  Ok(CriticalCss {
+    code: critical_code.join("\n"),
+    deferred_resources,
+  })
+}
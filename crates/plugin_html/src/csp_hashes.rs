@@ -0,0 +1,112 @@
+//! Collect hashes of inline scripts and styles of the final html, so a strict Content-Security-Policy can allow them
+//! without `'unsafe-inline'`.
+use farmfe_core::{
+  config::html::IntegrityAlgorithm,
+  serde::Serialize,
+  serde_json,
+  swc_html_ast::{Child, Document, Element},
+};
+use farmfe_toolkit::{
+  hash::integrity,
+  swc_html_visit::{Visit, VisitWith},
+};
+
+#[derive(Debug, Default, Serialize)]
+#[serde(crate = "farmfe_core::serde")]
+pub struct CspHashes {
+  #[serde(rename = "script-src")]
+  pub script_src: Vec<String>,
+  #[serde(rename = "style-src")]
+  pub style_src: Vec<String>,
+  /// the policy that can be used as the value of `Content-Security-Policy` header directly
+  pub policy: String,
+}
+
+/// Collect hashes of inline `<script>`, `<style>` and event handler attributes.
+/// Event handlers require `'unsafe-hashes'`, so it's added to `script-src` when there are event handlers.
+pub fn collect_csp_hashes(document: &Document, algorithm: IntegrityAlgorithm) -> CspHashes {
+  let mut collector = InlineCodeCollector::default();
+  document.visit_with(&mut collector);
+
+  let hash = |code: &String| format!("'{}'", integrity(code.as_bytes(), algorithm));
+  let mut script_src = collector.scripts.iter().map(hash).collect::<Vec<_>>();
+
+  if !collector.event_handlers.is_empty() {
+    script_src.push("'unsafe-hashes'".to_string());
+    script_src.extend(collector.event_handlers.iter().map(hash));
+  }
+
+  let style_src = collector.styles.iter().map(hash).collect::<Vec<_>>();
+  let directive = |name: &str, sources: &Vec<String>| {
+    std::iter::once(name)
+      .chain(std::iter::once("'self'"))
+      .chain(sources.iter().map(|s| s.as_str()))
+      .collect::<Vec<_>>()
+      .join(" ")
+  };
+  let policy = format!(
+    "{}; {}",
+    directive("script-src", &script_src),
+    directive("style-src", &style_src)
+  );
+
+  CspHashes {
+    script_src,
+    style_src,
+    policy,
+  }
+}
+
+pub fn csp_hashes_to_json(hashes: &CspHashes) -> String {
+  serde_json::to_string_pretty(hashes).unwrap()
+}
+
+#[derive(Default)]
+struct InlineCodeCollector {
+  scripts: Vec<String>,
+  styles: Vec<String>,
+  event_handlers: Vec<String>,
+}
+
+impl Visit for InlineCodeCollector {
+  fn visit_element(&mut self, element: &Element) {
+    let text = || {
+      element
+        .children
+        .iter()
+        .filter_map(|child| match child {
+          Child::Text(text) => Some(text.data.to_string()),
+          _ => None,
+        })
+        .collect::<String>()
+    };
+
+    match element.tag_name.as_ref() {
+      "script" if !element.attributes.iter().any(|attr| attr.name == "src") => {
+        let code = text();
+
+        if !code.is_empty() {
+          self.scripts.push(code);
+        }
+      }
+      "style" => {
+        let code = text();
+
+        if !code.is_empty() {
+          self.styles.push(code);
+        }
+      }
+      _ => {}
+    }
+
+    for attr in &element.attributes {
+      if attr.name.starts_with("on") {
+        if let Some(value) = &attr.value {
+          self.event_handlers.push(value.to_string());
+        }
+      }
+    }
+
+    element.visit_children_with(self);
+  }
+}
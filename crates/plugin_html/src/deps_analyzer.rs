@@ -6,9 +6,27 @@ use farmfe_core::{
   plugin::{PluginAnalyzeDepsHookResultEntry, ResolveKind},
   swc_html_ast::{Child, Document, Element},
 };
-use farmfe_toolkit::swc_html_visit::{Visit, VisitWith};
+use farmfe_toolkit::{
+  lazy_static::lazy_static,
+  regex::Regex,
+  swc_html_visit::{Visit, VisitWith},
+};
 
 pub const HTML_INLINE_ID_PREFIX: &str = "virtual:html-inline:";
+/// `rel` of `<link>` whose `href` is an asset
+const ASSET_LINK_RELS: [&str; 5] = [
+  "icon",
+  "apple-touch-icon",
+  "apple-touch-icon-precomposed",
+  "mask-icon",
+  "manifest",
+];
+
+lazy_static! {
+  /// `url()` in inline style attribute
+  pub static ref CSS_URL_REGEX: Regex =
+    Regex::new(r#"url\(\s*(?:'([^']*)'|"([^"]*)"|([^'")\s]*))\s*\)"#).unwrap();
+}
 
 pub struct DepsAnalyzer {
   deps: Option<Vec<PluginAnalyzeDepsHookResultEntry>>,
@@ -73,6 +91,13 @@ impl Visit for DepsAnalyzer {
       })
     }
 
+    for source in get_asset_sources(element) {
+      self.insert_dep(PluginAnalyzeDepsHookResultEntry {
+        kind: ResolveKind::HtmlAsset,
+        source,
+      })
+    }
+
     element.visit_children_with(self);
   }
 }
@@ -209,6 +234,94 @@ pub fn get_href_link_value(element: &Element) -> Option<String> {
   get_href_link_or_code(None, element)
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum HtmlAssetAttr {
+  /// the value is a single url, e.g. `<img src>`
+  Url,
+  /// the value is a list of `url descriptor` candidates, e.g. `<img srcset="a.png 1x, b.png 2x">`
+  Srcset,
+  /// the value is inline css, e.g. `style="background: url(a.png)"`
+  InlineStyle,
+}
+
+/// Whether the attribute of the element references assets
+pub fn html_asset_attr(element: &Element, attr_name: &str) -> Option<HtmlAssetAttr> {
+  if attr_name == "style" {
+    return Some(HtmlAssetAttr::InlineStyle);
+  }
+
+  match (element.tag_name.as_ref(), attr_name) {
+    ("img" | "source", "src") | ("video", "poster") => Some(HtmlAssetAttr::Url),
+    ("img" | "source", "srcset") => Some(HtmlAssetAttr::Srcset),
+    ("link", "href") if is_link_asset(element) => Some(HtmlAssetAttr::Url),
+    _ => None,
+  }
+}
+
+fn is_link_asset(element: &Element) -> bool {
+  element.attributes.iter().any(|attr| {
+    attr.name == "rel"
+      && attr.value.as_ref().is_some_and(|rel| {
+        rel
+          .split_ascii_whitespace()
+          .any(|r| ASSET_LINK_RELS.contains(&r.to_ascii_lowercase().as_str()))
+      })
+  })
+}
+
+/// Split `srcset` to `(url, descriptor)` candidates
+pub fn parse_srcset(value: &str) -> Vec<(String, String)> {
+  value
+    .split(',')
+    .map(|candidate| candidate.trim())
+    .filter(|candidate| !candidate.is_empty())
+    .map(|candidate| match candidate.split_once(char::is_whitespace) {
+      Some((url, descriptor)) => (url.to_string(), descriptor.trim().to_string()),
+      None => (candidate.to_string(), String::new()),
+    })
+    .collect()
+}
+
+/// Get the urls referenced by the attribute value
+pub fn get_attr_asset_urls(kind: &HtmlAssetAttr, value: &str) -> Vec<String> {
+  match kind {
+    HtmlAssetAttr::Url => vec![value.trim().to_string()],
+    HtmlAssetAttr::Srcset => parse_srcset(value)
+      .into_iter()
+      .map(|(url, _)| url)
+      .collect(),
+    HtmlAssetAttr::InlineStyle => CSS_URL_REGEX
+      .captures_iter(value)
+      .filter_map(|c| c.get(1).or(c.get(2)).or(c.get(3)))
+      .map(|m| m.as_str().trim().to_string())
+      .collect(),
+  }
+}
+
+/// Whether the url should be resolved as an asset dependency of html
+pub fn is_asset_source(source: &str) -> bool {
+  !source.is_empty() && !source.starts_with("//") && !should_ignore_source(source)
+}
+
+/// Get the asset sources referenced by the element
+pub fn get_asset_sources(element: &Element) -> Vec<String> {
+  let mut sources = vec![];
+
+  for attr in &element.attributes {
+    let (Some(kind), Some(value)) = (html_asset_attr(element, &attr.name), &attr.value) else {
+      continue;
+    };
+
+    sources.extend(
+      get_attr_asset_urls(&kind, value)
+        .into_iter()
+        .filter(|source| is_asset_source(source)),
+    );
+  }
+
+  sources
+}
+
 pub fn should_ignore_source(source: &str) -> bool {
   source.starts_with("http")
     // || source.starts_with('/')
@@ -0,0 +1,110 @@
+//! Subresource integrity of the resources injected into html.
+//!
+//! The bytes of the resources may still be changed by the `finalize_resources` hooks running after the html is transformed,
+//! so the html only contains placeholders first, and [FarmPluginHtmlIntegrity] replaces them by the integrity of the final bytes.
+use std::{collections::HashMap, sync::Arc};
+
+use farmfe_core::{
+  config::{html::IntegrityAlgorithm, Config},
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  plugin::{Plugin, PluginFinalizeResourcesHookParams},
+  resource::{Resource, ResourceType},
+};
+use farmfe_toolkit::hash::integrity as hash_integrity;
+
+const INTEGRITY_PLACEHOLDER_START: &str = "__FARM_INTEGRITY__(";
+const INTEGRITY_PLACEHOLDER_END: &str = ")__";
+
+/// Placeholder of the integrity of the resource, it's replaced by [replace_integrity_placeholders]
+pub fn integrity_placeholder(name: &str) -> String {
+  format!("{INTEGRITY_PLACEHOLDER_START}{name}{INTEGRITY_PLACEHOLDER_END}")
+}
+
+/// Replace the integrity placeholders in the resources. Resources that are not html (for example the isolated dynamic
+/// resources map) are replaced first, as their integrity is referenced by the html. Fails if a placeholder refers to a
+/// resource that does not exist, e.g. the resource is removed by a `finalize_resources` hook
+pub fn replace_integrity_placeholders(
+  resources_map: &mut HashMap<String, Resource>,
+  algorithm: IntegrityAlgorithm,
+) -> Result<()> {
+  let mut names = resources_map
+    .iter()
+    .filter(|(_, resource)| contains_placeholder(&resource.bytes))
+    .map(|(name, resource)| {
+      let is_html = matches!(resource.resource_type, ResourceType::Html);
+      (is_html, name.clone())
+    })
+    .collect::<Vec<_>>();
+  names.sort();
+
+  for (_, name) in names {
+    let code = String::from_utf8_lossy(&resources_map[&name].bytes).to_string();
+    let mut result = String::with_capacity(code.len());
+    let mut rest = code.as_str();
+
+    while let Some(start) = rest.find(INTEGRITY_PLACEHOLDER_START) {
+      let name_start = start + INTEGRITY_PLACEHOLDER_START.len();
+      let Some(len) = rest[name_start..].find(INTEGRITY_PLACEHOLDER_END) else {
+        break;
+      };
+      let referenced = &rest[name_start..name_start + len];
+
+      result.push_str(&rest[..start]);
+
+      let Some(resource) = resources_map.get(referenced) else {
+        return Err(CompilationError::GenericError(format!(
+          "Can not compute the integrity of resource {referenced} referenced by {name}: the resource does not exist"
+        )));
+      };
+      result.push_str(&hash_integrity(&resource.bytes, algorithm));
+
+      rest = &rest[name_start + len + INTEGRITY_PLACEHOLDER_END.len()..];
+    }
+
+    result.push_str(rest);
+    resources_map.get_mut(&name).unwrap().bytes = result.into_bytes();
+  }
+
+  Ok(())
+}
+
+fn contains_placeholder(bytes: &[u8]) -> bool {
+  bytes
+    .windows(INTEGRITY_PLACEHOLDER_START.len())
+    .any(|w| w == INTEGRITY_PLACEHOLDER_START.as_bytes())
+}
+
+/// Compute the integrity after all other plugins have finalized the resources
+pub struct FarmPluginHtmlIntegrity;
+
+impl FarmPluginHtmlIntegrity {
+  pub fn new(_: &Config) -> Self {
+    Self
+  }
+}
+
+impl Plugin for FarmPluginHtmlIntegrity {
+  fn name(&self) -> &str {
+    "FarmPluginHtmlIntegrity"
+  }
+
+  /// runs after [crate::FarmPluginMinifyHtml] and before [crate::FarmPluginHtmlCspHashes], the inline scripts contain the integrity
+  fn priority(&self) -> i32 {
+    -100
+  }
+
+  fn finalize_resources(
+    &self,
+    params: &mut PluginFinalizeResourcesHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    let Some(algorithm) = context.config.html.integrity else {
+      return Ok(None);
+    };
+
+    replace_integrity_placeholders(params.resources_map, algorithm)?;
+
+    Ok(Some(()))
+  }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use std::{collections::HashMap, path::PathBuf};
 
 use absolute_path_handler::AbsolutePathHandler;
+use assets_replacer::HtmlAssetsReplacer;
 use deps_analyzer::{DepsAnalyzer, HtmlInlineModule, HTML_INLINE_ID_PREFIX};
 // use farmfe_core::config::minify::MinifyOptions;
 use farmfe_core::parking_lot::Mutex;
@@ -31,12 +32,24 @@ use farmfe_toolkit::{
   fs::read_file_utf8,
   get_dynamic_resources_map::get_dynamic_resources_map,
   html::{codegen_html_document, parse_html_document},
+  import_map::ImportMap,
   script::{module_type_from_id, swc_try_with::try_with},
+  swc_html_visit::VisitMutWith,
 };
-use resources_injector::{ResourcesInjector, ResourcesInjectorOptions};
+use critical_css::extract_critical_css;
+use integrity::integrity_placeholder;
+pub use integrity::FarmPluginHtmlIntegrity;
+use csp_hashes::{collect_csp_hashes, csp_hashes_to_json};
+use resource_hints::{collect_resource_hints, CollectResourceHintsParams};
+use resources_injector::{ResourcesInjector, ResourcesInjectorOptions, ResourcesIntegrity};
 
 mod absolute_path_handler;
+mod assets_replacer;
+mod critical_css;
+mod csp_hashes;
 mod deps_analyzer;
+mod integrity;
+mod resource_hints;
 mod resources_injector;
 mod utils;
 
@@ -76,6 +89,41 @@ impl Plugin for FarmPluginHtml {
       }));
     }
 
+    if matches!(param.kind, ResolveKind::HtmlAsset) {
+      // `/xxx` is tried as `./xxx` first, then as is, e.g. assets under `publicDir`
+      let mut sources = vec![param.source.clone()];
+
+      if param.source.starts_with('/') {
+        sources.insert(0, format!(".{}", param.source));
+      }
+
+      for source in sources {
+        let resolve_result = context.plugin_driver.resolve(
+          &PluginResolveHookParam {
+            source,
+            importer: param.importer.clone(),
+            kind: param.kind.clone(),
+          },
+          context,
+          &PluginHookContext {
+            caller: Some(self.name().to_string()),
+            meta: hook_context.meta.clone(),
+          },
+        )?;
+
+        if resolve_result.is_some() {
+          return Ok(resolve_result);
+        }
+      }
+
+      // assets that can not be resolved are kept as is instead of failing the build
+      return Ok(Some(PluginResolveHookResult {
+        resolved_path: param.source.clone(),
+        external: true,
+        ..Default::default()
+      }));
+    }
+
     // try resolve `/xxx` as `./xxx`, for example: `/src/index.ts` to `./src/index.ts`
     if matches!(param.kind, ResolveKind::ScriptSrc | ResolveKind::LinkHref)
       && param.source.starts_with("/")
@@ -165,12 +213,14 @@ impl Plugin for FarmPluginHtml {
         .map_err(|e| CompilationError::TransformError {
           resolved_path: param.resolved_path.to_string(),
           msg: format!("Load base html({base}) fail. Error: {e:?}"),
+          loc: None,
         })?
         .ok_or(CompilationError::TransformError {
           resolved_path: param.resolved_path.to_string(),
           msg: format!(
             "Load base html({base}) fail: Base html file does not exist"
           ),
+          loc: None,
         })?;
 
       return Ok(Some(PluginTransformHookResult {
@@ -431,7 +481,7 @@ impl Plugin for FarmPluginTransformHtml {
     let mut already_injected_resources = Vec::new();
 
     for (html_resource_name, (dep_resources, dynamic_resources_map)) in resources_to_inject {
-      let mut resource_pot_map = context.resource_pot_map.write();
+      let resource_pot_map = context.resource_pot_map.read();
       let mut script_resources: Vec<String> = vec![];
       let mut css_resources: Vec<String> = vec![];
 
@@ -448,20 +498,23 @@ impl Plugin for FarmPluginTransformHtml {
         }
       }
 
-      let html_resource = params.resources_map.get_mut(&html_resource_name).unwrap();
+      let html_resource = params.resources_map.get(&html_resource_name).unwrap();
+      let html_resource_pot_id = html_resource.origin.as_resource_pot().clone();
+      let minify = self.minify_config.is_enabled(&html_resource.name);
 
       let module_graph = context.module_graph.read();
       let current_html_id = resource_pot_map
-        .resource_pot(html_resource.origin.as_resource_pot())
+        .resource_pot(&html_resource_pot_id)
         .unwrap()
-        .modules()[0];
+        .modules()[0]
+        .clone();
       let script_entries = module_graph
-        .dependencies(current_html_id)
+        .dependencies(&current_html_id)
         .into_iter()
         .filter_map(|dep| {
           let dep_module = module_graph.module(&dep.0).unwrap();
-
-          if dep_module.module_type.is_script() {
+          // assets referenced by html are not executed
+          if dep_module.module_type.is_script() && !dep.1.is_html_asset() {
             Some(dep.0.id(context.config.mode.clone()))
           } else {
             None
@@ -470,6 +523,87 @@ impl Plugin for FarmPluginTransformHtml {
         .collect();
       drop(module_graph);
 
+      let resource_pot = resource_pot_map
+        .resource_pot(&html_resource_pot_id)
+        .unwrap();
+      let mut html_ast =
+        parse_html_document(&resource_pot.id, resource_pot.meta.rendered_content.clone())?;
+
+      let critical_css = if let Some(critical_css_config) = &context.config.html.critical_css {
+        Some(extract_critical_css(
+          &html_ast,
+          &css_resources,
+          params.resources_map,
+          critical_css_config,
+          minify,
+        )?)
+      } else {
+        None
+      };
+
+      let resource_hints = context.config.html.resource_hints.as_ref().map(|config| {
+        let module_graph = context.module_graph.read();
+        // stylesheets are loaded by a blocking <link> in the head before the hints, scripts are injected at the end of
+        // the body so they are discovered earlier when preloaded. The css of critical css is loaded by a non blocking
+        // <link>, so it's still preloaded
+        let blocking_resources = if critical_css.is_none() {
+          css_resources.clone()
+        } else {
+          vec![]
+        };
+
+        collect_resource_hints(CollectResourceHintsParams {
+          config,
+          html_module_group_id: &current_html_id,
+          script_resources: &script_resources,
+          css_resources: &css_resources,
+          blocking_resources: &blocking_resources,
+          dynamic_resources_map: &dynamic_resources_map,
+          resources_map: params.resources_map,
+          module_graph: &module_graph,
+        })
+      });
+
+      // the integrity is computed by FarmPluginHtmlIntegrity after all resources are finalized
+      let integrity = context.config.html.integrity.map(|_| {
+        let mut names = script_resources
+          .iter()
+          .chain(css_resources.iter())
+          .chain(
+            dynamic_resources_map
+              .values()
+              .flat_map(|resources| resources.iter().map(|(name, _)| name)),
+          )
+          .collect::<Vec<_>>();
+
+        if let Some(resource_hints) = &resource_hints {
+          names.extend(resource_hints.preload.iter().map(|r| &r.name));
+        }
+
+        ResourcesIntegrity {
+          placeholders: names
+            .into_iter()
+            .filter(|name| params.resources_map.contains_key(*name))
+            .map(|name| (name.clone(), integrity_placeholder(name)))
+            .collect(),
+        }
+      });
+
+      let import_map = context.config.output.import_map.enabled().then(|| {
+        let names = script_resources.iter().chain(css_resources.iter()).chain(
+          dynamic_resources_map
+            .values()
+            .flat_map(|resources| resources.iter().map(|(name, _)| name)),
+        );
+
+        ImportMap::new(
+          names,
+          params.resources_map,
+          &resource_pot_map,
+          &context.config.output.public_path,
+        )
+      });
+
       let mut resources_injector = ResourcesInjector::new(
         vec![],
         runtime_code.clone(),
@@ -483,17 +617,27 @@ impl Plugin for FarmPluginTransformHtml {
           namespace: context.config.runtime.namespace.clone(),
           current_html_id: current_html_id.clone(),
           context: context.clone(),
+          critical_css,
+          resource_hints,
+          integrity,
+          import_map,
         },
         &mut already_injected_resources,
       );
 
-      let resource_pot = resource_pot_map
-        .resource_pot_mut(html_resource.origin.as_resource_pot())
-        .unwrap();
-      let mut html_ast =
-        parse_html_document(&resource_pot.id, resource_pot.meta.rendered_content.clone())?;
+      resources_injector.inject(&mut html_ast)?;
 
-      resources_injector.inject(&mut html_ast);
+      // replace asset urls like `<img src="./logo.png">` to the final public paths
+      let module_graph = context.module_graph.read();
+      let mut assets_replacer = HtmlAssetsReplacer::new(
+        &current_html_id,
+        &module_graph,
+        params.resources_map,
+        &context.config.output.public_path,
+        context,
+      );
+      html_ast.visit_mut_with(&mut assets_replacer);
+      drop(module_graph);
 
       // set publicPath prefix
       let mut absolute_path_handler = AbsolutePathHandler {
@@ -501,10 +645,8 @@ impl Plugin for FarmPluginTransformHtml {
       };
       absolute_path_handler.add_public_path_prefix(&mut html_ast);
 
-      let code = codegen_html_document(
-        &html_ast,
-        self.minify_config.is_enabled(&html_resource.name),
-      );
+      let code = codegen_html_document(&html_ast, minify);
+      let html_resource = params.resources_map.get_mut(&html_resource_name).unwrap();
       html_resource.bytes = code.bytes().collect();
 
       resources_injector.update_resource(params.resources_map);
@@ -592,3 +734,66 @@ impl Plugin for FarmPluginMinifyHtml {
     Ok(Some(()))
   }
 }
+
+/// Emit the csp hashes of inline scripts and styles after html is minified and the integrity is computed
+pub struct FarmPluginHtmlCspHashes;
+
+impl FarmPluginHtmlCspHashes {
+  pub fn new(_: &Config) -> Self {
+    Self
+  }
+}
+
+impl Plugin for FarmPluginHtmlCspHashes {
+  fn name(&self) -> &str {
+    "FarmPluginHtmlCspHashes"
+  }
+
+  fn priority(&self) -> i32 {
+    -101
+  }
+
+  fn finalize_resources(
+    &self,
+    params: &mut PluginFinalizeResourcesHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    let Some(algorithm) = context.config.html.csp_hashes else {
+      return Ok(None);
+    };
+
+    let mut csp_resources = vec![];
+
+    for resource in params.resources_map.values() {
+      if !matches!(resource.resource_type, ResourceType::Html) {
+        continue;
+      }
+
+      let html_code = Arc::new(String::from_utf8_lossy(&resource.bytes).to_string());
+      let html_ast = parse_html_document(&resource.name, html_code)?;
+      let hashes = collect_csp_hashes(&html_ast, algorithm);
+      let name = format!(
+        "{}.csp.json",
+        resource
+          .name
+          .strip_suffix(".html")
+          .unwrap_or(&resource.name)
+      );
+
+      csp_resources.push(Resource {
+        name: name.clone(),
+        bytes: csp_hashes_to_json(&hashes).into_bytes(),
+        emitted: false,
+        resource_type: ResourceType::Asset("json".to_string()),
+        origin: ResourceOrigin::ResourcePot(name),
+        info: None,
+      });
+    }
+
+    for resource in csp_resources {
+      params.resources_map.insert(resource.name.clone(), resource);
+    }
+
+    Ok(Some(()))
+  }
+}
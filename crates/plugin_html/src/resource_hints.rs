@@ -0,0 +1,216 @@
+//! Collect resources that should be hinted by `<link rel="preload">` and `<link rel="prefetch">` so the browser
+//! can discover them before the runtime requests them.
+use std::collections::{HashMap, HashSet};
+
+use farmfe_core::{
+  config::html::{HtmlPreloadResourceType, HtmlResourceHintsConfig},
+  module::{module_graph::ModuleGraph, module_group::ModuleGroupId, ModuleId},
+  resource::{Resource, ResourceOrigin, ResourceType},
+};
+use farmfe_toolkit::script::magic_comments::{
+  magic_comment_bool, PREFETCH_MAGIC_COMMENTS, PRELOAD_MAGIC_COMMENTS,
+};
+
+const FONT_EXTS: [&str; 5] = ["woff2", "woff", "ttf", "otf", "eot"];
+
+pub struct PreloadResource {
+  pub name: String,
+  /// `preload` or `modulepreload`
+  pub rel: &'static str,
+  /// value of the `as` attribute
+  pub as_type: &'static str,
+  /// fonts must be preloaded with `crossorigin` even if they are same-origin
+  pub crossorigin: bool,
+}
+
+#[derive(Default)]
+pub struct ResourceHints {
+  pub preload: Vec<PreloadResource>,
+  pub prefetch: Vec<String>,
+}
+
+pub struct CollectResourceHintsParams<'a> {
+  pub config: &'a HtmlResourceHintsConfig,
+  pub html_module_group_id: &'a ModuleGroupId,
+  pub script_resources: &'a [String],
+  pub css_resources: &'a [String],
+  /// resources that are already loaded synchronously by a `<link rel="stylesheet">` in the head, the browser discovers
+  /// them as early as a preload hint so they are not preloaded
+  pub blocking_resources: &'a [String],
+  pub dynamic_resources_map: &'a HashMap<ModuleId, Vec<(String, ResourceType)>>,
+  pub resources_map: &'a HashMap<String, Resource>,
+  pub module_graph: &'a ModuleGraph,
+}
+
+pub fn collect_resource_hints(params: CollectResourceHintsParams) -> ResourceHints {
+  let CollectResourceHintsParams {
+    config,
+    html_module_group_id,
+    script_resources,
+    css_resources,
+    blocking_resources,
+    dynamic_resources_map,
+    resources_map,
+    module_graph,
+  } = params;
+  let mut hints = ResourceHints::default();
+  let script_rel = if config.module_preload {
+    "modulepreload"
+  } else {
+    "preload"
+  };
+
+  if config.preload.contains(&HtmlPreloadResourceType::Script) {
+    for name in script_resources
+      .iter()
+      .filter(|name| !blocking_resources.contains(name))
+    {
+      hints.preload.push(PreloadResource {
+        name: name.clone(),
+        rel: script_rel,
+        as_type: "script",
+        crossorigin: false,
+      });
+    }
+  }
+
+  if config.preload.contains(&HtmlPreloadResourceType::Css) {
+    for name in css_resources
+      .iter()
+      .filter(|name| !blocking_resources.contains(name))
+    {
+      hints.preload.push(PreloadResource {
+        name: name.clone(),
+        rel: "preload",
+        as_type: "style",
+        crossorigin: false,
+      });
+    }
+  }
+
+  if config.preload.contains(&HtmlPreloadResourceType::Font) {
+    let mut fonts = resources_map
+      .values()
+      .filter(|resource| {
+        let ResourceType::Asset(ext) = &resource.resource_type else {
+          return false;
+        };
+        let ResourceOrigin::Module(module_id) = &resource.origin else {
+          return false;
+        };
+
+        FONT_EXTS.contains(&ext.as_str())
+          && module_graph
+            .module(module_id)
+            .is_some_and(|m| m.module_groups.contains(html_module_group_id))
+      })
+      .map(|resource| resource.name.clone())
+      .collect::<Vec<_>>();
+    fonts.sort();
+
+    for name in fonts {
+      hints.preload.push(PreloadResource {
+        name,
+        rel: "preload",
+        as_type: "font",
+        crossorigin: true,
+      });
+    }
+  }
+
+  let initial_resources = script_resources
+    .iter()
+    .chain(css_resources.iter())
+    .collect::<HashSet<_>>();
+  let mut dynamic_entries = dynamic_resources_map.iter().collect::<Vec<_>>();
+  dynamic_entries.sort_by(|a, b| a.0.cmp(b.0));
+  let mut prefetch = HashSet::new();
+  let mut preloaded = HashSet::new();
+
+  for (dynamic_entry, resources) in dynamic_entries {
+    let hint = dynamic_entry_hint(dynamic_entry, module_graph);
+    let resources = resources
+      .iter()
+      .filter(|(name, _)| !initial_resources.contains(name));
+
+    if hint.preload {
+      for (name, resource_type) in resources {
+        let (rel, as_type) = match resource_type {
+          ResourceType::Js => (script_rel, "script"),
+          ResourceType::Css => ("preload", "style"),
+          _ => continue,
+        };
+
+        if preloaded.insert(name.clone()) {
+          hints.preload.push(PreloadResource {
+            name: name.clone(),
+            rel,
+            as_type,
+            crossorigin: false,
+          });
+        }
+      }
+    } else if hint.prefetch.unwrap_or(config.prefetch) {
+      prefetch.extend(resources.map(|(name, _)| name.clone()));
+    }
+  }
+
+  hints.prefetch = prefetch
+    .into_iter()
+    .filter(|name| !preloaded.contains(name))
+    .collect();
+  hints.prefetch.sort();
+
+  hints
+}
+
+#[derive(Default)]
+struct DynamicEntryHint {
+  /// [None] if no dynamic import of the entry contains a prefetch magic comment
+  prefetch: Option<bool>,
+  preload: bool,
+}
+
+/// Read the `farmPrefetch`/`webpackPrefetch` and `farmPreload`/`webpackPreload` magic comments of the dynamic imports of
+/// the dynamic entry. The entry is prefetched if any import enables prefetching and is not prefetched only if all
+/// imports disable it
+fn dynamic_entry_hint(dynamic_entry: &ModuleId, module_graph: &ModuleGraph) -> DynamicEntryHint {
+  let mut hint = DynamicEntryHint::default();
+
+  if !module_graph.has_module(dynamic_entry) {
+    return hint;
+  }
+
+  let mut all_disabled = true;
+  let mut has_dynamic_import = false;
+
+  for (importer, edge) in module_graph.dependents(dynamic_entry) {
+    let Some(importer) = module_graph.module(&importer) else {
+      continue;
+    };
+
+    for item in edge.items() {
+      if !item.kind.is_dynamic() {
+        continue;
+      }
+
+      has_dynamic_import = true;
+      let comments = importer.dynamic_import_magic_comments.get(&item.source);
+      let prefetch = comments.and_then(|c| magic_comment_bool(c, &PREFETCH_MAGIC_COMMENTS));
+
+      if prefetch == Some(true) {
+        hint.prefetch = Some(true);
+      }
+
+      all_disabled &= prefetch == Some(false);
+      hint.preload |=
+        comments.and_then(|c| magic_comment_bool(c, &PRELOAD_MAGIC_COMMENTS)) == Some(true);
+    }
+  }
+
+  if hint.prefetch.is_none() && has_dynamic_import && all_disabled {
+    hint.prefetch = Some(false);
+  }
+
+  hint
+}
@@ -3,16 +3,21 @@ use std::{borrow::Cow, collections::HashMap, rc::Rc, sync::Arc};
 use farmfe_core::{
   config::{custom::get_config_runtime_isolate, Mode, FARM_MODULE_SYSTEM},
   context::CompilationContext,
+  error::{CompilationError, Result},
   module::ModuleId,
   resource::{Resource, ResourceType},
   swc_html_ast::{Child, Document, Element},
 };
 use farmfe_toolkit::{
   get_dynamic_resources_map::get_dynamic_resources_code,
-  html::{create_element, get_farm_global_this},
+  html::{create_attribute, create_element, get_farm_global_this},
+  import_map::ImportMap,
   swc_html_visit::{VisitMut, VisitMutWith},
 };
 
+use crate::critical_css::CriticalCss;
+use crate::integrity::integrity_placeholder;
+use crate::resource_hints::ResourceHints;
 use crate::utils::{
   create_farm_runtime_output_resource, is_link_css_or_code, is_script_resource,
   is_script_src_or_type_module_code, FARM_RESOURCE,
@@ -24,6 +29,19 @@ pub struct ResourcesInjectorOptions {
   pub namespace: String,
   pub current_html_id: ModuleId,
   pub context: Arc<CompilationContext>,
+  /// inline the critical css instead of injecting css `<link>`, see [crate::critical_css]
+  pub critical_css: Option<CriticalCss>,
+  /// inject preload and prefetch `<link>`, see [crate::resource_hints]
+  pub resource_hints: Option<ResourceHints>,
+  /// add subresource integrity to the injected resources
+  pub integrity: Option<ResourcesIntegrity>,
+  /// load the resources by logical names resolved by `<script type="importmap">`, enabled by `output.importMap`
+  pub import_map: Option<ImportMap>,
+}
+
+pub struct ResourcesIntegrity {
+  /// integrity placeholders of the resources to inject keyed by resource name, see [crate::integrity]
+  pub placeholders: HashMap<String, String>,
 }
 
 /// inject resources into the html ast
@@ -37,10 +55,13 @@ pub struct ResourcesInjector<'a> {
   options: ResourcesInjectorOptions,
   farm_global_this: String,
   already_injected_resources: &'a mut Vec<String>,
+  /// error of creating the isolated resources, visitors can not return errors
+  error: Option<CompilationError>,
 }
 pub const FARM_RUNTIME_INJECT_RESOURCE: &str = "farm_runtime_resource";
 pub const FARM_MODULE_SYSTEM_RESOURCE: &str = "farm_module_system";
 pub const FARM_DYNAMIC_RESOURCES_MAP_RESOURCE: &str = "farm_dynamic_resources_map";
+pub const CSP_NONCE_META_PROPERTY: &str = "csp-nonce";
 
 impl<'a> ResourcesInjector<'a> {
   pub fn new(
@@ -66,11 +87,17 @@ impl<'a> ResourcesInjector<'a> {
       ),
       options,
       already_injected_resources,
+      error: None,
     }
   }
 
-  pub fn inject(&mut self, ast: &mut Document) {
+  pub fn inject(&mut self, ast: &mut Document) -> Result<()> {
     ast.visit_mut_with(self);
+
+    match self.error.take() {
+      Some(e) => Err(e),
+      None => Ok(()),
+    }
   }
 
   // insert the runtime and other resources that need to be inject in the resource_map.
@@ -81,27 +108,81 @@ impl<'a> ResourcesInjector<'a> {
     }
   }
 
+  /// create a separate script resource for the code when runtime is isolated
+  fn create_isolated_script(&mut self, bytes: Cow<[u8]>, resource_name: &str) -> Element {
+    let (name, resource) = match create_farm_runtime_output_resource(
+      bytes,
+      resource_name,
+      &self.options.context,
+      self.already_injected_resources,
+    ) {
+      Ok(res) => res,
+      Err(e) => {
+        self.error.get_or_insert(e);
+        (resource_name.to_string(), None)
+      }
+    };
+
+    if let Some(resource) = resource {
+      self.additional_inject_resources.push(resource);
+    }
+
+    let integrity = self
+      .options
+      .integrity
+      .as_ref()
+      .map(|_| integrity_placeholder(&name));
+    let mut script_element = create_element("script", None, vec![("src", &format!("/{name}"))]);
+    self.add_integrity_attrs(&mut script_element, integrity);
+
+    script_element
+  }
+
+  /// create inline `<script>` or `<style>`, the csp nonce is added if configured
+  fn create_inline_element(&self, tag: &str, code: &str) -> Element {
+    let mut element = create_element(tag, Some(code), vec![]);
+
+    if let Some(nonce) = &self.options.context.config.html.csp_nonce {
+      element
+        .attributes
+        .push(create_attribute("nonce", Some(nonce)));
+    }
+
+    element
+  }
+
+  fn resource_integrity(&self, name: &str) -> Option<String> {
+    self
+      .options
+      .integrity
+      .as_ref()
+      .and_then(|integrity| integrity.placeholders.get(name).cloned())
+  }
+
+  fn add_integrity_attrs(&self, element: &mut Element, integrity: Option<String>) {
+    if let Some(integrity) = integrity {
+      element
+        .attributes
+        .push(create_attribute("integrity", Some(&integrity)));
+      element
+        .attributes
+        .push(create_attribute("crossorigin", Some("anonymous")));
+    }
+  }
+
   // Support isolate runtime resource (https://github.com/farm-fe/farm/issues/434)
   fn inject_runtime_resources(&mut self, element: &mut Element) {
     if get_config_runtime_isolate(&self.options.context) {
-      let (name, resource) = create_farm_runtime_output_resource(
-        Cow::Borrowed(self.runtime_code.as_bytes()),
+      let runtime_code = self.runtime_code.clone();
+      let script_element = self.create_isolated_script(
+        Cow::Borrowed(runtime_code.as_bytes()),
         FARM_RUNTIME_INJECT_RESOURCE,
-        &self.options.context,
-        &self.already_injected_resources,
       );
-
-      let script_element = create_element("script", None, vec![("src", &format!("/{name}"))]);
       element.children.push(Child::Element(script_element));
-
-      if let Some(resource) = resource {
-        self.additional_inject_resources.push(resource);
-      }
     } else {
-      element.children.push(Child::Element(create_element(
+      element.children.push(Child::Element(self.create_inline_element(
         "script",
-        Some(&self.runtime_code),
-        vec![],
+        &self.runtime_code,
       )));
     }
   }
@@ -110,6 +191,15 @@ impl<'a> ResourcesInjector<'a> {
     let mut initial_resources = vec![];
     initial_resources.extend(self.script_resources.clone());
     initial_resources.extend(self.css_resources.clone());
+
+    // the runtime tracks the loaded resources by the names in the dynamic resources map
+    if let Some(import_map) = &self.options.import_map {
+      initial_resources = initial_resources
+        .into_iter()
+        .map(|name| import_map.logical_name(&name))
+        .collect();
+    }
+
     initial_resources.sort();
 
     let initial_resources_code = initial_resources
@@ -123,33 +213,40 @@ impl<'a> ResourcesInjector<'a> {
       self.farm_global_this, FARM_MODULE_SYSTEM, initial_resources_code
     );
     if get_config_runtime_isolate(&self.options.context) {
-      let (name, resource) = create_farm_runtime_output_resource(
-        Cow::Owned(code.into_bytes()),
-        "initial_loaded_resources",
-        &self.options.context,
-        &self.already_injected_resources,
-      );
-      element.children.push(Child::Element(create_element(
-        "script",
-        None,
-        vec![("src", &format!("/{}", name))],
-      )));
-
-      if let Some(resource) = resource {
-        self.additional_inject_resources.push(resource);
-      }
+      let script_element =
+        self.create_isolated_script(Cow::Owned(code.into_bytes()), "initial_loaded_resources");
+      element.children.push(Child::Element(script_element));
     } else {
-      element.children.push(Child::Element(create_element(
+      element.children.push(Child::Element(self.create_inline_element(
         "script",
-        Some(&code),
-        vec![],
+        &code,
       )));
     }
   }
 
   fn inject_dynamic_resources_map(&mut self, element: &mut Element) {
+    let empty_hashes = HashMap::new();
+    let hashes = self
+      .options
+      .integrity
+      .as_ref()
+      .map(|integrity| &integrity.placeholders)
+      .unwrap_or(&empty_hashes);
+
     let (dynamic_resources, dynamic_module_resources_map) =
-      get_dynamic_resources_code(&self.dynamic_resources_map, self.options.mode.clone());
+      if let Some(import_map) = &self.options.import_map {
+        get_dynamic_resources_code(
+          &import_map.apply_to_dynamic_resources_map(self.dynamic_resources_map.clone()),
+          self.options.mode.clone(),
+          &import_map.apply_to_keys(hashes),
+        )
+      } else {
+        get_dynamic_resources_code(
+          &self.dynamic_resources_map,
+          self.options.mode.clone(),
+          hashes,
+        )
+      };
 
     if dynamic_resources.is_empty() {
       return;
@@ -161,27 +258,103 @@ impl<'a> ResourcesInjector<'a> {
     );
 
     if get_config_runtime_isolate(&self.options.context) {
-      let (name, resource) = create_farm_runtime_output_resource(
+      let script_element = self.create_isolated_script(
         Cow::Owned(finalize_code.into_bytes()),
         FARM_DYNAMIC_RESOURCES_MAP_RESOURCE,
-        &self.options.context,
-        &self.already_injected_resources,
       );
-
-      element.children.push(Child::Element(create_element(
+      element.children.push(Child::Element(script_element));
+    } else {
+      element.children.push(Child::Element(self.create_inline_element(
         "script",
-        None,
-        vec![("src", &format!("/{name}"))],
+        &finalize_code,
+      )));
+    }
+  }
+
+  /// the import map must be placed before any module script, so it's inserted as the first child of `<head>`
+  fn inject_import_map(&self, element: &mut Element) {
+    let Some(import_map) = &self.options.import_map else {
+      return;
+    };
+
+    if import_map.is_empty() {
+      return;
+    }
+
+    let mut script_element = self.create_inline_element("script", &import_map.to_json());
+    script_element
+      .attributes
+      .push(create_attribute("type", Some("importmap")));
+    element.children.insert(0, Child::Element(script_element));
+  }
+
+  fn inject_critical_css(&self, element: &mut Element) {
+    let critical_css = self.options.critical_css.as_ref().unwrap();
+
+    if !critical_css.code.is_empty() {
+      element.children.push(Child::Element(self.create_inline_element(
+        "style",
+        &critical_css.code,
       )));
+    }
 
-      if let Some(resource) = resource {
-        self.additional_inject_resources.push(resource);
+    // load the original css without blocking rendering, and fallback to a normal <link> when script is disabled
+    for name in &critical_css.deferred_resources {
+      let href = format!("{}{}", self.options.public_path, name);
+      let integrity = self.resource_integrity(name);
+      let mut link = create_element(
+        "link",
+        None,
+        vec![
+          ("rel", "stylesheet"),
+          ("href", &href),
+          ("media", "print"),
+          ("onload", "this.media='all'"),
+        ],
+      );
+      self.add_integrity_attrs(&mut link, integrity.clone());
+      element.children.push(Child::Element(link));
+
+      let mut noscript = create_element("noscript", None, vec![]);
+      let mut noscript_link =
+        create_element("link", None, vec![("rel", "stylesheet"), ("href", &href)]);
+      self.add_integrity_attrs(&mut noscript_link, integrity);
+      noscript.children.push(Child::Element(noscript_link));
+      element.children.push(Child::Element(noscript));
+    }
+  }
+
+  fn inject_resource_hints(&self, element: &mut Element) {
+    let resource_hints = self.options.resource_hints.as_ref().unwrap();
+
+    for resource in &resource_hints.preload {
+      let href = format!("{}{}", self.options.public_path, resource.name);
+      let mut attrs = vec![("rel", resource.rel), ("href", &href)];
+
+      // modulepreload does not accept the as attribute of script
+      if resource.rel == "preload" {
+        attrs.push(("as", resource.as_type));
       }
-    } else {
+
+      let mut link = create_element("link", None, attrs);
+
+      if let Some(integrity) = self.resource_integrity(&resource.name) {
+        self.add_integrity_attrs(&mut link, Some(integrity));
+      } else if resource.crossorigin {
+        link.attributes.push(create_attribute("crossorigin", None));
+      }
+
+      element.children.push(Child::Element(link));
+    }
+
+    for name in &resource_hints.prefetch {
       element.children.push(Child::Element(create_element(
-        "script",
-        Some(&finalize_code),
-        vec![],
+        "link",
+        None,
+        vec![
+          ("rel", "prefetch"),
+          ("href", &format!("{}{}", self.options.public_path, name)),
+        ],
       )));
     }
   }
@@ -197,57 +370,41 @@ impl<'a> ResourcesInjector<'a> {
     );
 
     if get_config_runtime_isolate(&self.options.context) {
-      let (name, resource) = create_farm_runtime_output_resource(
-        Cow::Owned(code.into_bytes()),
-        "global_this",
-        &self.options.context,
-        &self.already_injected_resources,
-      );
-
-      element.children.push(Child::Element(create_element(
-        "script",
-        None,
-        vec![("src", &format!("/{name}"))],
-      )));
-      if let Some(resource) = resource {
-        self.additional_inject_resources.push(resource);
-      }
+      let script_element =
+        self.create_isolated_script(Cow::Owned(code.into_bytes()), "global_this");
+      element.children.push(Child::Element(script_element));
     } else {
-      element.children.push(Child::Element(create_element(
+      element.children.push(Child::Element(self.create_inline_element(
         "script",
-        Some(&code),
-        vec![],
+        &code,
       )));
     }
   }
 
   fn inject_other_entry_file(&self, element: &mut Element) {
-    element.children.push(Child::Element(create_element(
+    element.children.push(Child::Element(self.create_inline_element(
       "script",
-      Some(&format!(
+      &format!(
         r#"{}.{}.setPublicPaths(['{}']);"#,
         self.farm_global_this, FARM_MODULE_SYSTEM, self.options.public_path
-      )),
-      vec![],
+      ),
     )));
 
-    element.children.push(Child::Element(create_element(
+    element.children.push(Child::Element(self.create_inline_element(
       "script",
-      Some(&format!(
+      &format!(
         r#"{}.{}.bootstrap();"#,
         self.farm_global_this, FARM_MODULE_SYSTEM
-      )),
-      vec![],
+      ),
     )));
 
     for entry in &self.script_entries {
-      element.children.push(Child::Element(create_element(
+      element.children.push(Child::Element(self.create_inline_element(
         "script",
-        Some(&format!(
+        &format!(
           r#"{}.{}.require("{}")"#,
           self.farm_global_this, FARM_MODULE_SYSTEM, entry
-        )),
-        vec![],
+        ),
       )));
     }
   }
@@ -268,23 +425,12 @@ impl<'a> ResourcesInjector<'a> {
         self.farm_global_this, FARM_MODULE_SYSTEM, entry
       ))
     }
-    // create resource
-    let (name, resource) = create_farm_runtime_output_resource(
+    // create resource and inject script
+    let script_element = self.create_isolated_script(
       Cow::Owned(finalize_code.into_bytes()),
       FARM_MODULE_SYSTEM_RESOURCE,
-      &self.options.context,
-      &self.already_injected_resources,
     );
-    // inject script
-    element.children.push(Child::Element(create_element(
-      "script",
-      None,
-      vec![("src", &format!("/{name}"))],
-    )));
-
-    if let Some(resource) = resource {
-      self.additional_inject_resources.push(resource);
-    }
+    element.children.push(Child::Element(script_element));
   }
 }
 
@@ -317,33 +463,56 @@ impl<'a> VisitMut for ResourcesInjector<'a> {
     }
 
     if element.tag_name.to_string() == "head" {
+      // expose the csp nonce to the runtime, so the <style> created by the runtime can use it
+      if let Some(nonce) = &self.options.context.config.html.csp_nonce {
+        element.children.push(Child::Element(create_element(
+          "meta",
+          None,
+          vec![("property", CSP_NONCE_META_PROPERTY), ("nonce", nonce)],
+        )));
+      }
+
+      self.inject_import_map(element);
+
       // inject global this
       self.inject_global_this(element);
 
       // inject runtime <script>
       self.inject_runtime_resources(element);
 
-      // inject css <link>
-      for css in &self.css_resources {
-        element.children.push(Child::Element(create_element(
-          "link",
-          None,
-          vec![
-            ("rel", "stylesheet"),
-            ("href", &format!("{}{}", self.options.public_path, css)),
-          ],
-        )));
+      if self.options.critical_css.is_some() {
+        self.inject_critical_css(element);
+      } else {
+        // inject css <link>
+        for css in &self.css_resources {
+          let mut link = create_element(
+            "link",
+            None,
+            vec![
+              ("rel", "stylesheet"),
+              ("href", &format!("{}{}", self.options.public_path, css)),
+            ],
+          );
+          self.add_integrity_attrs(&mut link, self.resource_integrity(css));
+          element.children.push(Child::Element(link));
+        }
+      }
+
+      if self.options.resource_hints.is_some() {
+        self.inject_resource_hints(element);
       }
     } else if element.tag_name.to_string() == "body" {
       for script in &self.script_resources {
-        element.children.push(Child::Element(create_element(
+        let mut script_element = create_element(
           "script",
           None,
           vec![
             ("src", &format!("{}{}", self.options.public_path, script)),
             (FARM_RESOURCE, "true"),
           ],
-        )));
+        );
+        self.add_integrity_attrs(&mut script_element, self.resource_integrity(script));
+        element.children.push(Child::Element(script_element));
       }
 
       self.inject_initial_loaded_resources(element);
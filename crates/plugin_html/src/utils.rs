@@ -2,7 +2,9 @@ use std::{borrow::Cow, sync::Arc};
 
 use farmfe_core::{
   context::CompilationContext,
+  error::Result,
   module::ModuleId,
+  plugin::{OutputFilenameKind, PluginOutputFilenameHookParams},
   resource::{Resource, ResourceOrigin, ResourceType},
   swc_html_ast::Element,
 };
@@ -72,23 +74,24 @@ pub fn create_farm_runtime_output_resource(
   resource_name: &str,
   context: &Arc<CompilationContext>,
   already_inject_resources: &Vec<String>,
-) -> (String, Option<Resource>) {
+) -> Result<(String, Option<Resource>)> {
   let name = transform_output_filename(
-    context.config.output.filename.clone(),
-    resource_name,
+    &context.config.output.filename,
+    &PluginOutputFilenameHookParams::new(
+      OutputFilenameKind::Chunk,
+      resource_name,
+      "js", // todo: support configuring extension
+      &context.config,
+    ),
     &bytes,
-    "js", // todo: support configuring extension
-          // match context.config.output.format {
-          //   ModuleFormat::EsModule => "mjs",
-          //   ModuleFormat::CommonJs => "cjs",
-          // },
-  );
+    context,
+  )?;
 
   if already_inject_resources.contains(&name) {
-    return (name, None);
+    return Ok((name, None));
   }
 
-  (
+  Ok((
     name.clone(),
     Some(Resource {
       name: name.clone(),
@@ -98,5 +101,5 @@ pub fn create_farm_runtime_output_resource(
       origin: ResourceOrigin::ResourcePot(name),
       info: None,
     }),
-  )
+  ))
 }
@@ -0,0 +1,321 @@
+//! Emit a manifest that maps entries and source modules to their emitted files, so a backend that renders html itself
+//! can find the hashed files of each entry. For example:
+//! ```json
+//! {
+//!   "src/main.ts": {
+//!     "file": "main.3f2a1b.js",
+//!     "src": "src/main.ts",
+//!     "name": "main",
+//!     "isEntry": true,
+//!     "imports": ["vendor.c71d9e.js"],
+//!     "dynamicImports": ["src/lazy.ts"],
+//!     "css": ["main.8e1f2c.css"],
+//!     "assets": ["logo.a9b8c7.png"]
+//!   }
+//! }
+//! ```
+use std::{
+  collections::{BTreeMap, HashMap, HashSet},
+  sync::Arc,
+};
+
+use farmfe_core::{
+  config::{Config, OutputManifestConfig},
+  context::CompilationContext,
+  module::{module_graph::ModuleGraph, module_group::ModuleGroupGraph, Module, ModuleId},
+  plugin::{Plugin, PluginFinalizeResourcesHookParams},
+  resource::{
+    resource_pot::ResourcePotId, resource_pot_map::ResourcePotMap, Resource, ResourceOrigin,
+    ResourceType,
+  },
+  serde::Serialize,
+  serde_json,
+};
+
+const PLUGIN_NAME: &str = "FarmPluginManifest";
+
+#[derive(Debug, Default, Serialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct ManifestChunk {
+  /// the emitted file of the module, relative to `output.path`
+  pub file: String,
+  /// the module id of the source module
+  pub src: String,
+  /// name of the entry in `compilation.input`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(skip_serializing_if = "is_false")]
+  pub is_entry: bool,
+  #[serde(skip_serializing_if = "is_false")]
+  pub is_dynamic_entry: bool,
+  /// js files that are loaded together with `file`
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub imports: Vec<String>,
+  /// keys of the dynamically imported modules in this manifest
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub dynamic_imports: Vec<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub css: Vec<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub assets: Vec<String>,
+}
+
+fn is_false(v: &bool) -> bool {
+  !*v
+}
+
+/// key: module id of the source module
+pub type Manifest = BTreeMap<String, ManifestChunk>;
+
+pub struct FarmPluginManifest {
+  config: Option<OutputManifestConfig>,
+}
+
+impl FarmPluginManifest {
+  pub fn new(config: &Config) -> Self {
+    Self {
+      config: config
+        .output
+        .manifest
+        .clone()
+        .unwrap_as_option(|enabled| enabled.and_then(|e| e.then(Default::default))),
+    }
+  }
+}
+
+impl Plugin for FarmPluginManifest {
+  fn name(&self) -> &str {
+    PLUGIN_NAME
+  }
+
+  /// Make sure the manifest is generated after all resources are finalized
+  fn priority(&self) -> i32 {
+    -101
+  }
+
+  fn finalize_resources(
+    &self,
+    params: &mut PluginFinalizeResourcesHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    let Some(config) = &self.config else {
+      return Ok(None);
+    };
+
+    let module_graph = context.module_graph.read();
+    let module_group_graph = context.module_group_graph.read();
+    let resource_pot_map = context.resource_pot_map.read();
+
+    let manifest = generate_manifest(
+      &module_graph,
+      &module_group_graph,
+      &resource_pot_map,
+      params.resources_map,
+    );
+    let name = config.filename.clone();
+
+    params.resources_map.insert(
+      name.clone(),
+      Resource {
+        name: name.clone(),
+        bytes: serde_json::to_string_pretty(&manifest)
+          .unwrap()
+          .into_bytes(),
+        emitted: false,
+        resource_type: ResourceType::Asset("json".to_string()),
+        origin: ResourceOrigin::ResourcePot(name),
+        info: None,
+      },
+    );
+
+    Ok(Some(()))
+  }
+}
+
+/// Files emitted for the modules, collected from the resource pots and the assets emitted by modules
+struct ModuleFiles<'a> {
+  resource_pot_map: &'a ResourcePotMap,
+  resources_map: &'a HashMap<String, Resource>,
+  /// key: module id, value: assets emitted by the module
+  module_assets: HashMap<&'a ModuleId, Vec<String>>,
+}
+
+impl<'a> ModuleFiles<'a> {
+  fn new(
+    resource_pot_map: &'a ResourcePotMap,
+    resources_map: &'a HashMap<String, Resource>,
+  ) -> Self {
+    let mut module_assets: HashMap<&ModuleId, Vec<String>> = HashMap::new();
+
+    for resource in resources_map.values() {
+      if let (ResourceOrigin::Module(module_id), ResourceType::Asset(_)) =
+        (&resource.origin, &resource.resource_type)
+      {
+        module_assets
+          .entry(module_id)
+          .or_default()
+          .push(resource.name.clone());
+      }
+    }
+
+    Self {
+      resource_pot_map,
+      resources_map,
+      module_assets,
+    }
+  }
+
+  /// js, css and html resources of the resource pot, source maps are excluded
+  fn resource_pot_files(
+    &self,
+    resource_pot_id: &ResourcePotId,
+  ) -> Vec<(&'a String, &'a ResourceType)> {
+    let Some(resource_pot) = self.resource_pot_map.resource_pot(resource_pot_id) else {
+      return vec![];
+    };
+
+    resource_pot
+      .resources()
+      .into_iter()
+      .filter_map(|name| {
+        let resource = self.resources_map.get(name)?;
+
+        matches!(
+          resource.resource_type,
+          ResourceType::Js | ResourceType::Css | ResourceType::Html
+        )
+        .then_some((name, &resource.resource_type))
+      })
+      .collect()
+  }
+
+  /// The emitted file of the module, asset modules are mapped to the emitted asset
+  fn module_file(&self, module: &Module) -> Option<String> {
+    if let Some(assets) = self.module_assets.get(&module.id) {
+      return assets.first().cloned();
+    }
+
+    let files = self.resource_pot_files(module.resource_pot.as_ref()?);
+
+    files
+      .iter()
+      .find(|(_, ty)| module.module_type.is_script() == matches!(ty, ResourceType::Js))
+      .or(files.first())
+      .map(|(name, _)| name.to_string())
+  }
+
+  fn assets(&self, module_id: &ModuleId) -> &[String] {
+    self
+      .module_assets
+      .get(module_id)
+      .map(|assets| assets.as_slice())
+      .unwrap_or_default()
+  }
+}
+
+#[derive(Default)]
+struct Files {
+  js: HashSet<String>,
+  css: HashSet<String>,
+  assets: HashSet<String>,
+}
+
+impl Files {
+  fn add_resource_pot(&mut self, module_files: &ModuleFiles, resource_pot_id: &ResourcePotId) {
+    for (name, ty) in module_files.resource_pot_files(resource_pot_id) {
+      match ty {
+        ResourceType::Css => self.css.insert(name.clone()),
+        _ => self.js.insert(name.clone()),
+      };
+    }
+  }
+}
+
+fn sorted(set: HashSet<String>, exclude: &str) -> Vec<String> {
+  let mut list = set.into_iter().filter(|f| f != exclude).collect::<Vec<_>>();
+  list.sort();
+  list
+}
+
+/// Entries and dynamic entries contain all the files of their module groups,
+/// other modules only contain the files of their direct dependencies.
+pub fn generate_manifest(
+  module_graph: &ModuleGraph,
+  module_group_graph: &ModuleGroupGraph,
+  resource_pot_map: &ResourcePotMap,
+  resources_map: &HashMap<String, Resource>,
+) -> Manifest {
+  let module_files = ModuleFiles::new(resource_pot_map, resources_map);
+  let mut manifest = Manifest::new();
+
+  for module in module_graph.modules() {
+    if module.external {
+      continue;
+    }
+
+    let Some(file) = module_files.module_file(module) else {
+      continue;
+    };
+
+    let mut files = Files::default();
+    let mut dynamic_imports = HashSet::new();
+    let module_group = module_group_graph
+      .has(&module.id)
+      .then(|| module_group_graph.module_group(&module.id))
+      .flatten();
+
+    if let Some(module_group) = module_group {
+      for resource_pot_id in module_group.resource_pots() {
+        files.add_resource_pot(&module_files, resource_pot_id);
+      }
+
+      for module_id in module_group.modules() {
+        files
+          .assets
+          .extend(module_files.assets(module_id).iter().cloned());
+      }
+
+      for dynamic_group in module_group_graph.dependencies(&module.id) {
+        dynamic_imports.insert(dynamic_group.id.to_string());
+      }
+    } else {
+      for (dep, edge) in module_graph.dependencies(&module.id) {
+        if edge.is_dynamic() {
+          dynamic_imports.insert(dep.to_string());
+          continue;
+        }
+
+        let Some(dep_module) = module_graph.module(&dep) else {
+          continue;
+        };
+
+        files
+          .assets
+          .extend(module_files.assets(&dep).iter().cloned());
+
+        if let Some(resource_pot_id) = &dep_module.resource_pot {
+          files.add_resource_pot(&module_files, resource_pot_id);
+        }
+      }
+    }
+
+    let name = module_graph.entries.get(&module.id).cloned();
+
+    manifest.insert(
+      module.id.to_string(),
+      ManifestChunk {
+        src: module.id.to_string(),
+        is_entry: name.is_some(),
+        is_dynamic_entry: module_group.is_some() && name.is_none(),
+        name,
+        imports: sorted(files.js, &file),
+        dynamic_imports: sorted(dynamic_imports, &file),
+        css: sorted(files.css, &file),
+        assets: sorted(files.assets, &file),
+        file,
+      },
+    );
+  }
+
+  manifest
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use farmfe_core::{
-  config::partial_bundling::PartialBundlingGroupConfigResourceType,
+  config::partial_bundling::{PartialBundlingConfig, PartialBundlingGroupConfigResourceType},
   module::{
     module_graph::ModuleGraph,
     module_group::{ModuleGroupGraph, ModuleGroupId},
@@ -52,6 +52,7 @@ impl ModuleGroupBuckets {
 pub fn generate_module_buckets_map(
   modules: &Vec<ModuleId>,
   module_graph: &ModuleGraph,
+  config: &PartialBundlingConfig,
 ) -> HashMap<String, ModuleBucket> {
   let mut module_buckets_map = HashMap::<String, ModuleBucket>::new();
 
@@ -62,12 +63,18 @@ pub fn generate_module_buckets_map(
       continue;
     }
 
+    // assets referenced by html are only emitted as assets, they are never executed by scripts
+    let dependents = module_graph.dependents(module_id);
+    if !dependents.is_empty() && dependents.iter().all(|(_, edge)| edge.is_html_asset()) {
+      continue;
+    }
+
     let key = ModuleBucket::id(module);
 
     if let Some(module_bucket) = module_buckets_map.get_mut(&key) {
-      module_bucket.add_module(module);
+      module_bucket.add_module(module, config);
     } else {
-      let module_bucket = ModuleBucket::new(key.clone(), module);
+      let module_bucket = ModuleBucket::new(key.clone(), module, config);
       module_buckets_map.insert(key, module_bucket);
     }
   }
@@ -142,7 +149,7 @@ mod tests {
       .collect::<Vec<_>>();
     modules.sort();
 
-    generate_module_buckets_map(&modules, module_graph)
+    generate_module_buckets_map(&modules, module_graph, &Default::default())
   }
 
   #[test]
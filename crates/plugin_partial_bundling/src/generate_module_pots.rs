@@ -32,7 +32,11 @@ pub fn generate_module_pots(
       )
     });
 
-    module_pot.add_module(module_id.clone(), module.size, module.execution_order);
+    module_pot.add_module(
+      module_id.clone(),
+      config.module_size(module),
+      module.execution_order,
+    );
   }
 
   // split module_pots from module_pot_map that its size larger that target_max_size
@@ -70,7 +74,11 @@ pub fn generate_module_pots(
 
       for module_id in &modules[start..end] {
         let module = module_graph.module(module_id).unwrap();
-        new_module_pot.add_module(module_id.clone(), module.size, module.execution_order);
+        new_module_pot.add_module(
+          module_id.clone(),
+          config.module_size(module),
+          module.execution_order,
+        );
       }
     }
   }
@@ -91,19 +99,8 @@ fn generate_module_pot_name(
   resource_type: ResourceType,
 ) -> String {
   // 1. get name from partialBundling.groups
-  for group_config in &config.groups {
-    // use the first matched group name, so the order of groups is important
-    if group_config
-      .test
-      .iter()
-      .any(|c| c.is_match(&module.id.to_string()))
-    {
-      if (group_config.group_type.is_match(module.immutable))
-        && (resource_type.is_match(group_config.resource_type.clone()))
-      {
-        return group_config.name.clone();
-      }
-    }
+  if let Some(group_name) = matched_group_name(module, config, resource_type) {
+    return group_name;
   }
 
   // 2. get name from immutable package
@@ -114,6 +111,27 @@ fn generate_module_pot_name(
   module.id.to_string()
 }
 
+/// name of the first `partialBundling.groups` item that matches the module
+pub fn matched_group_name(
+  module: &Module,
+  config: &PartialBundlingConfig,
+  resource_type: ResourceType,
+) -> Option<String> {
+  // use the first matched group name, so the order of groups is important
+  config
+    .groups
+    .iter()
+    .find(|group_config| {
+      group_config
+        .test
+        .iter()
+        .any(|c| c.is_match(&module.id.to_string()))
+        && group_config.group_type.is_match(module.immutable)
+        && resource_type.is_match(group_config.resource_type.clone())
+    })
+    .map(|group_config| group_config.name.clone())
+}
+
 #[cfg(test)]
 mod tests {
   use farmfe_core::{
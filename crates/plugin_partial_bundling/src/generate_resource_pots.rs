@@ -4,33 +4,58 @@ use std::path::PathBuf;
 use farmfe_core::{
   config::partial_bundling::PartialBundlingConfig,
   module::{module_graph::ModuleGraph, module_group::ModuleGroupId},
-  resource::resource_pot::ResourcePot,
+  resource::resource_pot::{ResourcePot, ResourcePotType},
+  stats::{
+    PartialBundlingMergeStep, PartialBundlingModuleStats, PartialBundlingResourcePotStats,
+    PartialBundlingStats,
+  },
 };
 
 use crate::{
   generate_module_buckets::ModuleGroupBuckets,
-  generate_module_pots::generate_module_pots,
-  merge_module_pots::{merge_module_pots, ModuleGroupModulePots},
+  generate_module_pots::{generate_module_pots, matched_group_name},
+  merge_module_pots::{merge_module_pots, MergeHistory, ModuleGroupModulePots},
   module_bucket::ModuleBucket,
   module_pot::ModulePot,
-  utils::try_get_filename,
+  utils::{get_module_group_chunk_name, try_get_filename},
 };
 
 /// Generate resource pots from module group buckets.
 /// 1. create module pots from module buckets.
 /// 2. merge module pots to resource pots.
+///
+/// The modules of the dynamic module groups that have a chunk name magic comment are not split by size, they are placed
+/// into the resource pot named by the chunk name. The placement of the modules and how the resource pots are merged are
+/// recorded in `stats` if it's provided.
 pub fn generate_resource_pots(
   module_group_buckets: Vec<ModuleGroupBuckets>,
   mut module_buckets_map: HashMap<String, ModuleBucket>,
   module_graph: &ModuleGraph,
   config: &PartialBundlingConfig,
+  mut stats: Option<&mut PartialBundlingStats>,
 ) -> Vec<ResourcePot> {
   let mut resource_pots = vec![];
   let mut handled_module_group_buckets = HashSet::new();
-  let mut used_resource_pot_names = HashSet::new();
+  // resource pot id -> (the first module group of the named resource pot, resource pot)
+  let mut named_resource_pots = HashMap::<String, (ModuleGroupId, ResourcePot)>::new();
+  let chunk_names = module_group_buckets
+    .iter()
+    .filter_map(|bucket| {
+      get_module_group_chunk_name(&bucket.module_group_id, module_graph)
+        .map(|name| (bucket.module_group_id.clone(), name))
+    })
+    .collect::<HashMap<_, _>>();
+  // the chunk names and the enforce resource names are reserved, so the resource pots named after the module groups
+  // never clash with them
+  let mut used_resource_pot_names = chunk_names
+    .values()
+    .cloned()
+    .chain(config.enforce_resources.iter().map(|r| r.name.clone()))
+    .collect::<HashSet<_>>();
 
   for mut module_group_bucket in module_group_buckets {
     let module_group_id = module_group_bucket.module_group_id;
+    let chunk_name = chunk_names.get(&module_group_id);
     let base_resource_pot_name = generate_resource_pot_name(
       module_group_id.clone(),
       &used_resource_pot_names,
@@ -57,6 +82,29 @@ pub fn generate_resource_pots(
         module_group_bucket.resource_type.clone(),
       );
 
+      if let Some(stats) = stats.as_deref_mut() {
+        for module_pot in &module_pots {
+          for module_id in module_pot.modules() {
+            let module = module_graph.module(module_id).unwrap();
+
+            stats.modules.insert(
+              module_id.clone(),
+              PartialBundlingModuleStats {
+                size: config.module_size(module),
+                module_bucket: Some(module_bucket_id.clone()),
+                module_pot: Some(module_pot.name.clone()),
+                group: matched_group_name(
+                  module,
+                  config,
+                  module_group_bucket.resource_type.clone(),
+                ),
+                ..Default::default()
+              },
+            );
+          }
+        }
+      }
+
       module_group_module_pots.add_module_pots(module_bucket_id.clone(), module_pots);
       handled_module_group_buckets.insert(module_bucket_id);
     }
@@ -69,19 +117,113 @@ pub fn generate_resource_pots(
         pots.sort_by(|a, b| b.size.cmp(&a.size));
       });
 
+    if let Some(chunk_name) = chunk_name {
+      add_to_named_resource_pots(
+        module_group_module_pots,
+        chunk_name,
+        &module_group_id,
+        &mut named_resource_pots,
+      );
+      continue;
+    }
+
+    let mut history = stats.is_some().then(MergeHistory::new);
     let merged_resource_pots = merge_module_pots(
       module_group_module_pots,
       config,
       &base_resource_pot_name,
       module_graph,
+      history.as_mut(),
     );
 
+    if let (Some(stats), Some(mut history)) = (stats.as_deref_mut(), history) {
+      for resource_pot in &merged_resource_pots {
+        record_resource_pot_stats(
+          stats,
+          resource_pot,
+          module_group_id.clone(),
+          history.remove(&resource_pot.id).unwrap_or_default(),
+        );
+      }
+    }
+
     resource_pots.extend(merged_resource_pots);
   }
 
+  let mut named_resource_pots = named_resource_pots.into_values().collect::<Vec<_>>();
+  named_resource_pots.sort_by(|a, b| a.1.id.cmp(&b.1.id));
+
+  for (module_group_id, resource_pot) in named_resource_pots {
+    if let Some(stats) = stats.as_deref_mut() {
+      record_resource_pot_stats(
+        stats,
+        &resource_pot,
+        module_group_id,
+        vec![PartialBundlingMergeStep::ChunkName {
+          name: resource_pot.name.clone(),
+        }],
+      );
+    }
+
+    resource_pots.push(resource_pot);
+  }
+
   resource_pots
 }
 
+/// Record the resource pot that the modules are placed in and the size of the resource pot
+fn record_resource_pot_stats(
+  stats: &mut PartialBundlingStats,
+  resource_pot: &ResourcePot,
+  module_group_id: ModuleGroupId,
+  merge_history: Vec<PartialBundlingMergeStep>,
+) {
+  let mut size = 0;
+
+  for module_id in resource_pot.modules() {
+    if let Some(module_stats) = stats.modules.get_mut(module_id) {
+      module_stats.resource_pot.clone_from(&resource_pot.id);
+      size += module_stats.size;
+    }
+  }
+
+  stats.resource_pots.insert(
+    resource_pot.id.clone(),
+    PartialBundlingResourcePotStats {
+      module_group: Some(module_group_id),
+      size,
+      merge_history,
+      resources: vec![],
+    },
+  );
+}
+
+/// Put all module pots of the module group into the resource pots named by the chunk name, one resource pot for each
+/// resource pot type. Module groups with the same chunk name share the resource pots.
+fn add_to_named_resource_pots(
+  module_group_module_pots: ModuleGroupModulePots,
+  chunk_name: &str,
+  module_group_id: &ModuleGroupId,
+  named_resource_pots: &mut HashMap<String, (ModuleGroupId, ResourcePot)>,
+) {
+  for module_pot in module_group_module_pots.module_pots.into_values().flatten() {
+    let resource_pot_type = ResourcePotType::from(module_pot.module_type.clone());
+    let immutable = module_pot.immutable;
+    let (_, resource_pot) = named_resource_pots
+      .entry(ResourcePot::gen_id(chunk_name, resource_pot_type.clone()))
+      .or_insert_with(|| {
+        let mut resource_pot = ResourcePot::new(chunk_name.to_string(), resource_pot_type);
+        resource_pot.immutable = true;
+        (module_group_id.clone(), resource_pot)
+      });
+    resource_pot.immutable &= immutable;
+
+    for module_id in module_pot.take_modules() {
+      resource_pot.add_module(module_id);
+    }
+  }
+}
+
 /// Generate resource pot id from module group id.
 /// 1. If module_group_id is entry module group, then the resource pot id is the name defined in config.
 /// 2. If module_group_id is not entry module group, then the resource pot id is the module group id's filename(without extension).
@@ -122,9 +264,14 @@ fn generate_resource_pot_name(
 #[cfg(test)]
 mod tests {
   use farmfe_core::module::{module_graph::ModuleGraph, module_group::ModuleGroupId, Module};
-  use std::collections::HashSet;
+  use farmfe_testing_helpers::construct_test_module_graph_complex;
+  use std::collections::{HashMap, HashSet};
 
-  use crate::generate_resource_pots::generate_resource_pot_name;
+  use crate::{
+    generate_module_buckets::{generate_module_buckets_map, group_module_buckets_by_module_group},
+    generate_resource_pots::{generate_resource_pot_name, generate_resource_pots},
+    module_group_graph_from_entries,
+  };
 
   #[test]
   fn test_generate_resource_pot_name() {
@@ -164,4 +311,54 @@ mod tests {
       "test_src_api"
     );
   }
+
+  #[test]
+  fn test_generate_named_resource_pots() {
+    let mut module_graph = construct_test_module_graph_complex();
+    let magic_comments = [
+      ("A", "./D", "webpackChunkName"),
+      ("E", "./G", "farmChunkName"),
+    ];
+
+    for (importer, source, key) in magic_comments {
+      module_graph
+        .module_mut(&importer.into())
+        .unwrap()
+        .dynamic_import_magic_comments
+        .insert(
+          source.to_string(),
+          HashMap::from([(key.to_string(), "admin".to_string())]),
+        );
+    }
+
+    let entries = module_graph.entries.clone().into_keys().collect::<Vec<_>>();
+    let module_group_graph = module_group_graph_from_entries(&entries, &mut module_graph);
+    let mut modules = module_graph
+      .modules()
+      .iter()
+      .map(|m| m.id.clone())
+      .collect::<Vec<_>>();
+    modules.sort();
+
+    let config = Default::default();
+    let module_buckets_map = generate_module_buckets_map(&modules, &module_graph, &config);
+    let module_group_buckets =
+      group_module_buckets_by_module_group(&module_buckets_map, &module_group_graph, &module_graph);
+    let resource_pots = generate_resource_pots(
+      module_group_buckets,
+      module_buckets_map,
+      &module_graph,
+      &config,
+      None,
+    );
+
+    let named = resource_pots
+      .iter()
+      .filter(|resource_pot| resource_pot.name == "admin")
+      .collect::<Vec<_>>();
+    assert_eq!(named.len(), 1);
+
+    // D is also statically imported by entry B, so it's placed with B
+    assert_eq!(named[0].modules(), vec![&"G".into()]);
+  }
 }
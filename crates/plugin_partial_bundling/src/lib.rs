@@ -9,8 +9,10 @@ use farmfe_core::{
     module_group::{ModuleGroup, ModuleGroupGraph},
     ModuleId,
   },
-  plugin::{Plugin, PluginHookContext},
-  resource::resource_pot::ResourcePot,
+  plugin::{Plugin, PluginFinalizeResourcesHookParams, PluginHookContext},
+  resource::{resource_pot::ResourcePot, Resource, ResourceOrigin, ResourceType},
+  serde_json,
+  stats::PartialBundlingStats,
 };
 use generate_module_buckets::{generate_module_buckets_map, group_module_buckets_by_module_group};
 use generate_resource_pots::generate_resource_pots;
@@ -70,20 +72,78 @@ impl Plugin for FarmPluginPartialBundling {
     let module_graph = context.module_graph.read();
     let module_group_graph = context.module_group_graph.read();
     // 2. generate module buckets and group by module group
-    let module_buckets_map = generate_module_buckets_map(modules, &module_graph);
+    let module_buckets_map =
+      generate_module_buckets_map(modules, &module_graph, &context.config.partial_bundling);
     let module_group_buckets =
       group_module_buckets_by_module_group(&module_buckets_map, &module_group_graph, &module_graph);
 
-    // 3. generate resource pots
+    // 3. generate resource pots, the placement of the modules is only recorded for `partialBundling.report`
+    let mut stats = context
+      .config
+      .partial_bundling
+      .report
+      .enabled()
+      .then(PartialBundlingStats::default);
     let resource_pots = generate_resource_pots(
       module_group_buckets,
       module_buckets_map,
       &module_graph,
       &context.config.partial_bundling,
+      stats.as_mut(),
     );
 
+    if let Some(stats) = stats {
+      context.record_manager.set_partial_bundling_stats(stats);
+    }
+
     Ok(Some(resource_pots))
   }
+
+  /// Emit the recorded partial bundling stats when `partialBundling.report` is enabled
+  fn finalize_resources(
+    &self,
+    params: &mut PluginFinalizeResourcesHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    if !context.config.partial_bundling.report.enabled() {
+      return Ok(None);
+    }
+
+    let Some(mut stats) = context.record_manager.partial_bundling_stats() else {
+      return Ok(None);
+    };
+
+    let resource_pot_map = context.resource_pot_map.read();
+
+    for (resource_pot_id, resource_pot_stats) in stats.resource_pots.iter_mut() {
+      if let Some(resource_pot) = resource_pot_map.resource_pot(resource_pot_id) {
+        resource_pot_stats.resources = resource_pot.resources().into_iter().cloned().collect();
+        resource_pot_stats.resources.sort();
+      }
+    }
+
+    let name = context
+      .config
+      .partial_bundling
+      .report
+      .clone()
+      .unwrap_or_default()
+      .filename;
+    params.resources_map.insert(
+      name.clone(),
+      Resource {
+        name: name.clone(),
+        bytes: serde_json::to_string_pretty(&stats).unwrap().into_bytes(),
+        emitted: false,
+        resource_type: ResourceType::Asset("json".to_string()),
+        origin: ResourceOrigin::ResourcePot(name),
+        info: None,
+      },
+    );
+    context.record_manager.set_partial_bundling_stats(stats);
+
+    Ok(Some(()))
+  }
 }
 
 impl FarmPluginPartialBundling {
@@ -8,10 +8,15 @@ use farmfe_core::{
   config::partial_bundling::PartialBundlingConfig,
   module::{module_graph::ModuleGraph, module_group::ModuleGroupId, ModuleId, ModuleType},
   resource::resource_pot::{ResourcePot, ResourcePotType},
+  stats::PartialBundlingMergeStep,
 };
 
 use crate::{module_pot::ModulePot, utils::hash_module_ids};
 
+/// resource pot id -> the steps that generate the resource pot, see `partialBundling.report`.
+/// It's only collected when the report is enabled
+pub type MergeHistory = HashMap<String, Vec<PartialBundlingMergeStep>>;
+
 #[derive(Debug, Clone)]
 pub struct ModuleGroupModulePots {
   pub module_group_id: ModuleGroupId,
@@ -99,6 +104,14 @@ impl<'a> CurrentGeneration<'a> {
   pub fn immutable(&self) -> bool {
     self.module_pots[0].immutable
   }
+
+  pub fn module_pot_names(&self) -> Vec<String> {
+    self
+      .module_pots
+      .iter()
+      .map(|module_pot| module_pot.name.clone())
+      .collect()
+  }
 }
 
 /// Merge module pots to resource pots in the same ModuleGroup.
@@ -108,6 +121,7 @@ pub fn merge_module_pots(
   config: &PartialBundlingConfig,
   base_resource_pot_name: &str,
   module_graph: &ModuleGraph,
+  mut history: Option<&mut MergeHistory>,
 ) -> Vec<ResourcePot> {
   // target_concurrent_requests = 0 means no limit
   let target_concurrent_requests = if config.target_concurrent_requests == 0 {
@@ -138,13 +152,14 @@ pub fn merge_module_pots(
     mutable_target_size,
     immutable_target_size,
     base_resource_pot_name,
+    history.as_deref_mut(),
   );
 
   let mut resource_pots_size_mp = HashMap::new();
 
   if config.enforce_target_concurrent_requests || config.enforce_target_min_size {
     for resource_pot in &resource_pots {
-      let size = get_modules_size(resource_pot.modules(), module_graph);
+      let size = get_modules_size(resource_pot.modules(), module_graph, config);
       resource_pots_size_mp.insert(resource_pot.id.clone(), size);
     }
   }
@@ -156,6 +171,7 @@ pub fn merge_module_pots(
       &resource_pots_size_mp,
       config.target_min_size,
       base_resource_pot_name,
+      history.as_deref_mut(),
     );
   }
 
@@ -165,6 +181,7 @@ pub fn merge_module_pots(
       &resource_pots_size_mp,
       target_concurrent_requests,
       base_resource_pot_name,
+      history,
     );
   }
 
@@ -177,6 +194,7 @@ fn merge_resource_pots_by_buckets(
   mutable_target_size: usize,
   immutable_target_size: usize,
   base_resource_pot_name: &str,
+  mut history: Option<&mut MergeHistory>,
 ) -> Vec<ResourcePot> {
   let mut final_resource_pots = vec![];
 
@@ -223,6 +241,15 @@ fn merge_resource_pots_by_buckets(
           resource_pot.add_module(module_id);
         }
 
+        if let Some(history) = history.as_deref_mut() {
+          history.insert(
+            resource_pot.id.clone(),
+            vec![PartialBundlingMergeStep::TargetSize {
+              target_size,
+              module_pots: current_generation.module_pot_names(),
+            }],
+          );
+        }
         resource_pots.push(resource_pot);
       }
     }
@@ -245,6 +272,15 @@ fn merge_resource_pots_by_buckets(
           resource_pot.add_module(module_id);
         }
 
+        if let Some(history) = history.as_deref_mut() {
+          history.insert(
+            resource_pot.id.clone(),
+            vec![PartialBundlingMergeStep::TargetSize {
+              target_size,
+              module_pots: current_generation.module_pot_names(),
+            }],
+          );
+        }
         resource_pots.push(resource_pot);
       }
     }
@@ -257,10 +293,14 @@ fn merge_resource_pots_by_buckets(
   final_resource_pots
 }
 
-fn get_modules_size(modules: Vec<&ModuleId>, module_graph: &ModuleGraph) -> usize {
+fn get_modules_size(
+  modules: Vec<&ModuleId>,
+  module_graph: &ModuleGraph,
+  config: &PartialBundlingConfig,
+) -> usize {
   modules
     .into_iter()
-    .map(|module_id| module_graph.module(module_id).unwrap().size)
+    .map(|module_id| config.module_size(module_graph.module(module_id).unwrap()))
     .sum()
 }
 
@@ -270,6 +310,7 @@ fn handle_enforce_target_min_size(
   resource_pots_size_mp: &HashMap<String, usize>,
   target_min_size: usize,
   base_resource_pot_name: &str,
+  mut history: Option<&mut MergeHistory>,
 ) -> Vec<ResourcePot> {
   let mut small_resource_pots_to_merge = vec![];
   let mut resource_pot_map = resource_pots
@@ -326,6 +367,11 @@ fn handle_enforce_target_min_size(
         resource_pot.immutable,
         base_resource_pot_name,
         &mut resource_pot_map,
+        history.as_deref_mut(),
+        || PartialBundlingMergeStep::EnforceTargetMinSize {
+          target_min_size,
+          resource_pots: merged_resource_pot_ids.clone(),
+        },
       );
 
       resource_pot_map.insert(merged_resource_pot.id.clone(), merged_resource_pot);
@@ -373,6 +419,16 @@ fn handle_enforce_target_min_size(
             }
           }
 
+          record_merge(
+            history.as_deref_mut(),
+            &merged_resource_pot_ids,
+            final_resource_pot_id,
+            || PartialBundlingMergeStep::EnforceTargetMinSize {
+              target_min_size,
+              resource_pots: merged_resource_pot_ids.clone(),
+            },
+          );
+
           found = true;
           break;
         }
@@ -386,6 +442,11 @@ fn handle_enforce_target_min_size(
           immutable,
           base_resource_pot_name,
           &mut resource_pot_map,
+          history.as_deref_mut(),
+          || PartialBundlingMergeStep::EnforceTargetMinSize {
+            target_min_size,
+            resource_pots: merged_resource_pot_ids.clone(),
+          },
         );
         final_resource_pot_ids.push(merged_resource_pot.id.clone());
         resource_pot_map.insert(merged_resource_pot.id.clone(), merged_resource_pot);
@@ -405,6 +466,8 @@ fn create_merged_resource_pot(
   immutable: bool,
   base_resource_pot_name: &str,
   resource_pot_map: &mut HashMap<String, ResourcePot>,
+  history: Option<&mut MergeHistory>,
+  step: impl FnOnce() -> PartialBundlingMergeStep,
 ) -> ResourcePot {
   let mut modules = HashSet::new();
 
@@ -429,15 +492,45 @@ fn create_merged_resource_pot(
     merged_resource_pot.add_module(module_id);
   }
 
+  record_merge(
+    history,
+    merged_resource_pot_ids,
+    &merged_resource_pot.id,
+    step,
+  );
+
   merged_resource_pot
 }
 
+/// The steps of the merged resource pots are moved to the resource pot they are merged into, followed by `step`.
+/// Nothing is recorded if the history is not collected
+fn record_merge(
+  history: Option<&mut MergeHistory>,
+  merged_resource_pot_ids: &[String],
+  resource_pot_id: &str,
+  step: impl FnOnce() -> PartialBundlingMergeStep,
+) {
+  let Some(history) = history else {
+    return;
+  };
+
+  let mut steps = history.remove(resource_pot_id).unwrap_or_default();
+
+  for merged_resource_pot_id in merged_resource_pot_ids {
+    steps.extend(history.remove(merged_resource_pot_id).unwrap_or_default());
+  }
+
+  steps.push(step());
+  history.insert(resource_pot_id.to_string(), steps);
+}
+
 /// Merge resource pots that are less than target_min_size to a new ResourcePot or into the smallest resource pot.
 fn handle_enforce_target_concurrent_requests(
   mut resource_pots: Vec<ResourcePot>,
   resource_pots_size_mp: &HashMap<String, usize>,
   target_concurrent_requests: usize,
   base_resource_pot_name: &str,
+  mut history: Option<&mut MergeHistory>,
 ) -> Vec<ResourcePot> {
   if resource_pots.len() <= target_concurrent_requests {
     return resource_pots;
@@ -507,6 +600,11 @@ fn handle_enforce_target_concurrent_requests(
         resource_pot.immutable,
         base_resource_pot_name,
         &mut resource_pot_map,
+        history.as_deref_mut(),
+        || PartialBundlingMergeStep::EnforceTargetConcurrentRequests {
+          target_concurrent_requests,
+          resource_pots: resource_pot_ids.clone(),
+        },
       );
       resource_pot_map.insert(merged_resource_pot.id.clone(), merged_resource_pot);
     }
@@ -521,6 +619,11 @@ fn handle_enforce_target_concurrent_requests(
         immutable,
         base_resource_pot_name,
         &mut resource_pot_map,
+        history.as_deref_mut(),
+        || PartialBundlingMergeStep::EnforceTargetConcurrentRequests {
+          target_concurrent_requests,
+          resource_pots: resource_pot_ids.clone(),
+        },
       );
       resource_pot_map.insert(merged_resource_pot.id.clone(), merged_resource_pot);
     }
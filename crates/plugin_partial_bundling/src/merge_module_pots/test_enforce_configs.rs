@@ -1,7 +1,11 @@
-use farmfe_core::config::partial_bundling::PartialBundlingConfig;
+use farmfe_core::{
+  config::partial_bundling::PartialBundlingConfig, stats::PartialBundlingMergeStep,
+};
 use farmfe_testing_helpers::construct_test_module_graph_complex;
 
-use super::{common::create_test_module_pot, merge_module_pots, ModuleGroupModulePots};
+use super::{
+  common::create_test_module_pot, merge_module_pots, MergeHistory, ModuleGroupModulePots,
+};
 
 #[test]
 fn test_enforce_configs_min_size() {
@@ -19,6 +23,7 @@ fn test_enforce_configs_min_size() {
   module_group_module_pots.add_module_pots("D".to_string(), vec![module_bucket_d]);
   module_group_module_pots.add_module_pots("H".to_string(), vec![module_bucket_h]);
 
+  let mut history = MergeHistory::new();
   let mut resource_pots = merge_module_pots(
     module_group_module_pots,
     &PartialBundlingConfig {
@@ -29,6 +34,7 @@ fn test_enforce_configs_min_size() {
     },
     "B",
     &module_graph,
+    Some(&mut history),
   );
 
   resource_pots.sort_by_key(|p| p.id.clone());
@@ -39,6 +45,25 @@ fn test_enforce_configs_min_size() {
 
   assert_eq!(resource_pots[1].immutable, true);
   assert_eq!(resource_pots[1].modules(), vec![&"D".into(), &"H".into()]);
+
+  assert_eq!(
+    history[&resource_pots[0].id],
+    vec![PartialBundlingMergeStep::TargetSize {
+      target_size: 20 * 1024,
+      module_pots: vec!["B".to_string(), "E".to_string()],
+    }]
+  );
+
+  // D and H are generated separately, then H is merged into D as it's smaller than the target min size
+  let steps = &history[&resource_pots[1].id];
+  assert_eq!(steps.len(), 3, "{steps:?}");
+  assert!(matches!(
+    &steps[2],
+    PartialBundlingMergeStep::EnforceTargetMinSize { target_min_size, resource_pots }
+      if *target_min_size == 20 * 1024 && resource_pots.len() == 1
+  ));
+  // the history of the resource pots that are merged is not kept
+  assert_eq!(history.len(), 2);
 }
 
 #[test]
@@ -67,6 +92,7 @@ fn test_enforce_configs_concurrent_requests() {
     },
     "B",
     &module_graph,
+    None,
   );
 
   resource_pots.sort_by_key(|p| p.id.clone());
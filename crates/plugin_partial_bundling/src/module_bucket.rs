@@ -1,6 +1,9 @@
 use std::collections::HashSet;
 
-use farmfe_core::module::{Module, ModuleId, ModuleType};
+use farmfe_core::{
+  config::partial_bundling::PartialBundlingConfig,
+  module::{Module, ModuleId, ModuleType},
+};
 
 /// A ModuleBucket is a collection of modules whose module_groups field is equal.
 /// For example, if there are two ModuleGroups A and B. if module c is in ModuleGroup A and ModuleGroup B, module d is only in ModuleGroup A, then c and d are in the different ModuleBucket.
@@ -9,7 +12,7 @@ use farmfe_core::module::{Module, ModuleId, ModuleType};
 #[derive(Debug)]
 pub struct ModuleBucket {
   pub id: String,
-  /// The size of this ModuleBucket. It's the sum of the modules' size.
+  /// The size of this ModuleBucket. It's the sum of the modules' size measured by `partialBundling.sizeMetric`.
   pub size: usize,
   /// The type of this ModuleBucket. All modules in this ModuleBucket have the same type.
   pub module_type: ModuleType,
@@ -23,13 +26,13 @@ pub struct ModuleBucket {
 }
 
 impl ModuleBucket {
-  pub fn new(id: String, module: &Module) -> Self {
+  pub fn new(id: String, module: &Module, config: &PartialBundlingConfig) -> Self {
     // The fields will be filled later when add modules to this ModuleBucket.
     Self {
       id,
       modules: HashSet::from([module.id.clone()]),
       module_groups: module.module_groups.clone(),
-      size: module.size,
+      size: config.module_size(module),
       module_type: module.module_type.clone(),
       immutable: module.immutable,
     }
@@ -63,9 +66,9 @@ impl ModuleBucket {
     &self.module_groups
   }
 
-  pub fn add_module(&mut self, module: &Module) {
+  pub fn add_module(&mut self, module: &Module, config: &PartialBundlingConfig) {
     let module_id = module.id.clone();
-    let size = module.size;
+    let size = config.module_size(module);
     self.size += size;
 
     self.modules.insert(module_id);
@@ -1,8 +1,11 @@
 use std::collections::HashSet;
 use std::path::PathBuf;
 
-use farmfe_core::module::ModuleId;
-use farmfe_toolkit::hash::sha256;
+use farmfe_core::module::{module_graph::ModuleGraph, module_group::ModuleGroupId, ModuleId};
+use farmfe_toolkit::{
+  hash::sha256,
+  script::magic_comments::{magic_comment_value, CHUNK_NAME_MAGIC_COMMENTS},
+};
 
 pub fn try_get_filename(path: PathBuf) -> String {
   path
@@ -26,3 +29,33 @@ pub fn hash_module_ids(module_ids: &HashSet<ModuleId>) -> String {
 
   sha256(&str.into_bytes(), 4)
 }
+
+/// The chunk name of a dynamic module group, specified by `import(/* webpackChunkName: "admin" */ './admin')`.
+/// If the dynamic imports of the group specify different names, the smallest one is used to keep the result stable.
+pub fn get_module_group_chunk_name(
+  module_group_id: &ModuleGroupId,
+  module_graph: &ModuleGraph,
+) -> Option<String> {
+  if module_graph.entries.contains_key(module_group_id) || !module_graph.has_module(module_group_id)
+  {
+    return None;
+  }
+
+  module_graph
+    .dependents(module_group_id)
+    .into_iter()
+    .filter_map(|(importer, edge)| {
+      let importer = module_graph.module(&importer)?;
+
+      edge
+        .items()
+        .iter()
+        .filter(|item| item.kind.is_dynamic())
+        .filter_map(|item| importer.dynamic_import_magic_comments.get(&item.source))
+        .filter_map(|comments| magic_comment_value(comments, &CHUNK_NAME_MAGIC_COMMENTS))
+        .filter(|name| !name.is_empty())
+        .min()
+        .map(|name| name.to_string())
+    })
+    .min()
+}
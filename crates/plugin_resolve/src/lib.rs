@@ -121,12 +121,39 @@ impl Plugin for FarmPluginResolve {
       let source = source.replace(".js", "");
 
       resolver
-        .resolve(&source, basedir, &param.kind, &resolve_options, context)
+        .resolve(
+          &source,
+          basedir.clone(),
+          &param.kind,
+          &resolve_options,
+          context,
+        )
         .map(|result| PluginResolveHookResult { query, ..result })
     } else {
       result.map(|result| PluginResolveHookResult { query, ..result })
     };
 
+    // watch the files tried for the source, so that creating the missing file updates the importer
+    if resolve_result.is_none() {
+      if let Some(importer) = &param.importer {
+        let mut candidate_files =
+          resolver.candidate_files(source, basedir.clone(), &resolve_options, context);
+
+        if source.ends_with(".js") {
+          candidate_files.extend(resolver.candidate_files(
+            &source.replace(".js", ""),
+            basedir,
+            &resolve_options,
+            context,
+          ));
+        }
+
+        if !candidate_files.is_empty() {
+          context.add_missing_watch_files(importer.clone(), candidate_files);
+        }
+      }
+    }
+
     if resolve_result.is_none() && context.config.resolve.auto_external_failed_resolve {
       resolve_result = Some(PluginResolveHookResult {
         resolved_path: param.source.clone(),
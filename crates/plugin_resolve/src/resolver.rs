@@ -1,3 +1,4 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::{
   path::{Path, PathBuf},
@@ -55,6 +56,43 @@ pub struct Resolver {
 pub const NODE_MODULES: &str = "node_modules";
 const BROWSER_SUBPATH_EXTERNAL_ID: &str = "__FARM_BROWSER_SUBPATH_EXTERNAL__";
 const REGEX_PREFIX: &str = "$__farm_regex:";
+
+/// The matched aliases of the source and the source with each alias replaced, the longest alias is matched first
+fn apply_alias<'a>(
+  source: &'a str,
+  alias_map: &'a HashMap<String, String>,
+) -> impl Iterator<Item = (&'a str, String)> + 'a {
+  // sort the alias by length, so that the longest alias will be matched first
+  let mut alias_list: Vec<_> = alias_map.keys().collect();
+  alias_list.sort_by_key(|a| Reverse(a.len()));
+
+  alias_list.into_iter().filter_map(move |alias| {
+    let replaced = alias_map.get(alias).unwrap();
+
+    // try regex alias first
+    if let Some(pattern) = alias.strip_prefix(REGEX_PREFIX) {
+      let regex = regex::Regex::new(pattern).unwrap();
+      if regex.is_match(source) {
+        Some((
+          alias.as_str(),
+          regex.replace(source, replaced.as_str()).to_string(),
+        ))
+      } else {
+        None
+      }
+    } else if alias.ends_with('$') && source == alias.trim_end_matches('$') {
+      Some((alias.as_str(), replaced.to_string()))
+    } else if !alias.ends_with('$') && source.starts_with(alias.as_str()) {
+      let aliased = RelativePath::new(source.trim_start_matches(alias.as_str()))
+        .to_logical_path(replaced)
+        .to_string_lossy()
+        .to_string();
+      Some((alias.as_str(), aliased))
+    } else {
+      None
+    }
+  })
+}
 const HIGHEST_PRIORITY_FIELD: &str = "exports";
 
 impl Resolver {
@@ -344,6 +382,65 @@ impl Resolver {
     None
   }
 
+  /// The files tried for a relative, absolute or aliased `source` in order, e.g. `./Foo` tries `/root/Foo`,
+  /// `/root/Foo.tsx`, ... and `/root/Foo/index.tsx`, .... Empty if the source is a package.
+  /// When the resolution fails, creating any of them may make the source resolvable
+  pub fn candidate_files(
+    &self,
+    source: &str,
+    base_dir: PathBuf,
+    options: &ResolveOptions,
+    context: &Arc<CompilationContext>,
+  ) -> Vec<String> {
+    let source = self.alias_source(source, context);
+    let file = if is_source_absolute(&source) {
+      PathBuf::from(&source)
+    } else if is_source_relative(&source) {
+      RelativePath::new(&source).to_logical_path(base_dir)
+    } else {
+      return vec![];
+    };
+    let extensions = if let Some(ext) = &options.dynamic_extensions {
+      ext
+    } else {
+      &context.config.resolve.extensions
+    };
+    let with_extensions = |file: PathBuf| {
+      let file_name = file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+      let mut files = vec![file.to_string_lossy().to_string()];
+
+      for ext in extensions {
+        files.push(
+          file
+            .with_file_name(format!("{file_name}.{ext}"))
+            .to_string_lossy()
+            .to_string(),
+        );
+      }
+
+      files
+    };
+
+    let mut candidates = with_extensions(file.clone());
+
+    for main_file in &context.config.resolve.main_files {
+      candidates.extend(with_extensions(file.join(main_file)));
+    }
+
+    candidates
+  }
+
+  /// Replace the alias of the source, the source is returned as is if no alias matches
+  fn alias_source(&self, source: &str, context: &Arc<CompilationContext>) -> String {
+    apply_alias(source, &context.config.resolve.alias)
+      .next()
+      .map(|(_, aliased)| aliased)
+      .unwrap_or_else(|| source.to_string())
+  }
+
   /// Try resolve as a file with the configured main fields.
   fn try_directory(
     &self,
@@ -415,31 +512,14 @@ impl Resolver {
     context: &Arc<CompilationContext>,
   ) -> Option<PluginResolveHookResult> {
     farm_profile_function!("try_alias".to_string());
-    // sort the alias by length, so that the longest alias will be matched first
-    let mut alias_list: Vec<_> = context.config.resolve.alias.keys().collect();
-    alias_list.sort_by(|a, b| b.len().cmp(&a.len()));
 
-    for alias in alias_list {
-      let replaced = context.config.resolve.alias.get(alias).unwrap();
+    for (alias, new_source) in apply_alias(source, &context.config.resolve.alias) {
       let mut result = None;
 
-      // try regex alias first
-      if let Some(alias) = alias.strip_prefix(REGEX_PREFIX) {
-        let regex = regex::Regex::new(alias).unwrap();
-        if regex.is_match(source) {
-          let replaced = regex.replace(source, replaced.as_str()).to_string();
-          result = self.resolve(&replaced, base_dir.clone(), kind, options, context);
-        }
-      } else if alias.ends_with('$') && source == alias.trim_end_matches('$') {
-        result = self.resolve(replaced, base_dir.clone(), kind, options, context);
-      } else if !alias.ends_with('$') && source.starts_with(alias) {
+      if alias.starts_with(REGEX_PREFIX) || alias.ends_with('$') {
+        result = self.resolve(&new_source, base_dir.clone(), kind, options, context);
+      } else {
         // Add absolute path and values in node_modules package
-
-        let source_left = RelativePath::new(source.trim_start_matches(alias));
-        let new_source = source_left
-          .to_logical_path(replaced)
-          .to_string_lossy()
-          .to_string();
         if Path::new(&new_source).is_absolute() && !Path::new(&new_source).is_relative() {
           result = self.resolve(&new_source, base_dir.clone(), kind, options, context);
         }
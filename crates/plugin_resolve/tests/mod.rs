@@ -486,3 +486,44 @@ fn resolve_package_subpath() {
     }
   );
 }
+
+#[test]
+fn resolve_candidate_files() {
+  fixture("tests/fixtures/resolve-alias/index.ts", |file, _| {
+    let cwd = file.parent().unwrap().to_path_buf();
+    let resolver = Resolver::new();
+    let context = Arc::new(
+      CompilationContext::new(
+        Config {
+          resolve: Box::new(ResolveConfig {
+            alias: HashMap::from([("@".to_string(), cwd.to_string_lossy().to_string())]),
+            extensions: vec!["tsx".to_string(), "ts".to_string()],
+            ..Default::default()
+          }),
+          ..Default::default()
+        },
+        vec![],
+      )
+      .unwrap(),
+    );
+    let path = |p: &str| cwd.join(p).to_string_lossy().to_string();
+    let expected = vec![
+      path("Foo"),
+      path("Foo.tsx"),
+      path("Foo.ts"),
+      path("Foo/index"),
+      path("Foo/index.tsx"),
+      path("Foo/index.ts"),
+    ];
+
+    for source in ["./Foo", "@/Foo"] {
+      let candidates =
+        resolver.candidate_files(source, cwd.clone(), &ResolveOptions::default(), &context);
+      assert_eq!(candidates, expected);
+    }
+
+    let candidates =
+      resolver.candidate_files("react", cwd.clone(), &ResolveOptions::default(), &context);
+    assert!(candidates.is_empty());
+  });
+}
@@ -4,7 +4,8 @@ use std::sync::Arc;
 use farmfe_core::enhanced_magic_string::collapse_sourcemap::collapse_sourcemap_chain;
 use farmfe_core::enhanced_magic_string::magic_string::MagicString;
 use farmfe_core::enhanced_magic_string::types::SourceMapOptions;
-use farmfe_core::plugin::ResolveKind;
+use farmfe_core::error::{CompilationError, Result};
+use farmfe_core::plugin::{OutputFilenameKind, PluginOutputFilenameHookParams, ResolveKind};
 use farmfe_core::resource::ResourceOrigin;
 use farmfe_core::{
   config::{ModuleFormat, TargetEnv, FARM_MODULE_SYSTEM},
@@ -16,11 +17,12 @@ use farmfe_core::{
   swc_ecma_ast::{self, Decl, ModuleDecl, ModuleItem, Pat},
 };
 use farmfe_toolkit::common::{append_source_map_comment, generate_source_map_resource};
-use farmfe_toolkit::fs::transform_output_entry_filename;
+use farmfe_toolkit::fs::{transform_output_filename, ENTRY_NAME};
 use farmfe_toolkit::get_dynamic_resources_map::{
   get_dynamic_resources_code, get_dynamic_resources_map,
 };
 use farmfe_toolkit::html::get_farm_global_this;
+use farmfe_toolkit::import_map::ImportMap;
 use farmfe_toolkit::sourcemap::SourceMap;
 use farmfe_utils::transform_string_to_static_str;
 
@@ -175,26 +177,34 @@ fn get_export_info_code(
       .iter()
       .map(|export| match export {
         ExportInfoOfEntryModule::Default => match context.config.output.format {
-          ModuleFormat::CommonJs => "module.exports = entry.default || entry;".to_string(),
+          ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+            "module.exports = entry.default || entry;".to_string()
+          }
           ModuleFormat::EsModule => "export default entry.default || entry;".to_string(),
         },
         ExportInfoOfEntryModule::Named { name, import_as } => {
           if let Some(import_as) = import_as {
             match context.config.output.format {
-              ModuleFormat::CommonJs => format!("module.exports.{import_as} = entry.{name};"),
-              ModuleFormat::EsModule => format!(
-                "var {name}=entry.{name};export {{ {name} as {import_as} }};"
-              ),
+              ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+                format!("module.exports.{import_as} = entry.{name};")
+              }
+              ModuleFormat::EsModule => {
+                format!("var {name}=entry.{name};export {{ {name} as {import_as} }};")
+              }
             }
           } else {
             match context.config.output.format {
-              ModuleFormat::CommonJs => format!("module.exports.{name} = entry.{name};"),
+              ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+                format!("module.exports.{name} = entry.{name};")
+              }
               ModuleFormat::EsModule => format!("var {name}=entry.{name};export {{ {name} }};"),
             }
           }
         }
         ExportInfoOfEntryModule::CJS => match context.config.output.format {
-          ModuleFormat::CommonJs => "module.exports = entry;".to_string(),
+          ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+            "module.exports = entry;".to_string()
+          }
           ModuleFormat::EsModule => "export default entry;".to_string(),
         },
       })
@@ -214,6 +224,8 @@ struct EntryResourceAndDepResources {
   pub dep_resources: Vec<String>,
   pub dynamic_resources: String,
   pub dynamic_module_resources_map: String,
+  /// [Some] if `output.importMap` is enabled, the dep resources and dynamic resources are loaded by logical names
+  pub import_map: Option<ImportMap>,
 }
 
 fn get_entry_resource_and_dep_resources_name(
@@ -232,9 +244,9 @@ fn get_entry_resource_and_dep_resources_name(
     .expect("module group is not found");
   let resource_pot_map = context.resource_pot_map.read();
 
-  for resource_pot_id in module_group.resource_pots() {
+  for resource_pot_id in module_group.sorted_resource_pots(module_graph, &resource_pot_map) {
     let resource_pot = resource_pot_map
-      .resource_pot(resource_pot_id)
+      .resource_pot(&resource_pot_id)
       .expect("resource pot is not found");
 
     if let Some(entry) = &resource_pot.entry_module {
@@ -278,11 +290,39 @@ fn get_entry_resource_and_dep_resources_name(
     resource_map,
     module_graph,
   );
-  let (dynamic_resources, dynamic_module_resources_map) =
-    get_dynamic_resources_code(&dynamic_resources_map, context.config.mode.clone());
+
+  let import_map = context.config.output.import_map.enabled().then(|| {
+    let names = result.dep_resources.iter().chain(
+      dynamic_resources_map
+        .values()
+        .flat_map(|resources| resources.iter().map(|(name, _)| name)),
+    );
+    let url_prefix = if context.config.output.target_env.is_node() {
+      "./"
+    } else {
+      context.config.output.public_path.as_str()
+    };
+
+    ImportMap::new(names, resource_map, &resource_pot_map, url_prefix)
+  });
+
+  let (dynamic_resources, dynamic_module_resources_map) = if let Some(import_map) = &import_map {
+    get_dynamic_resources_code(
+      &import_map.apply_to_dynamic_resources_map(dynamic_resources_map),
+      context.config.mode.clone(),
+      &HashMap::new(),
+    )
+  } else {
+    get_dynamic_resources_code(
+      &dynamic_resources_map,
+      context.config.mode.clone(),
+      &HashMap::new(),
+    )
+  };
 
   result.dynamic_resources = dynamic_resources;
   result.dynamic_module_resources_map = dynamic_module_resources_map;
+  result.import_map = import_map;
   result
 }
 
@@ -290,7 +330,7 @@ pub fn handle_entry_resources(
   resources_map: &mut HashMap<String, Resource>,
   context: &Arc<CompilationContext>,
   async_modules: &HashSet<ModuleId>,
-) {
+) -> Result<()> {
   let module_graph = context.module_graph.read();
   let module_group_graph = context.module_group_graph.read();
   let is_library = context.config.output.target_env.is_library();
@@ -300,7 +340,7 @@ pub fn handle_entry_resources(
   let mut runtime_resource = None;
   let mut should_inject_runtime = false;
 
-  for (entry, _) in &module_graph.entries {
+  for (entry, entry_name) in &module_graph.entries {
     let module = module_graph
       .module(entry)
       .expect("module is not found in module graph");
@@ -315,6 +355,7 @@ pub fn handle_entry_resources(
         mut dep_resources,
         dynamic_resources,
         dynamic_module_resources_map,
+        import_map,
       } = get_entry_resource_and_dep_resources_name(
         entry,
         module,
@@ -326,24 +367,60 @@ pub fn handle_entry_resources(
       dep_resources.sort();
 
       if !should_inject_runtime {
-        should_inject_runtime = !dep_resources.is_empty();
+        // the import map resource is imported by the entry, so the runtime must be imported before it
+        should_inject_runtime = !dep_resources.is_empty() || import_map.is_some();
       }
 
-      // 1. import 'dep' or require('dep') to entry resource if target env is node
-      let dep_resources_require_code = dep_resources
-        .iter()
-        .map(|rn| match context.config.output.format {
-          ModuleFormat::EsModule => format!("import \"./{rn}\";"),
-          ModuleFormat::CommonJs => format!("require(\"./{rn}\");"),
-        })
-        .collect::<Vec<_>>()
-        .join("");
-
       let farm_global_this = get_farm_global_this(
         &context.config.runtime.namespace,
         &context.config.output.target_env,
       );
 
+      // 1. import 'dep' or require('dep') to entry resource if target env is node.
+      // the hashed names of the dep resources are moved to the import map resource, which has a stable name,
+      // so the entry resource does not change when the dep resources change
+      let (dep_resources_require_code, dep_resources) = if let Some(import_map) = &import_map {
+        let import_map_resource = create_import_map_resource(
+          entry_name,
+          &entry_js_resource_name,
+          &dep_resources,
+          import_map,
+          &farm_global_this,
+          resources_map,
+          context,
+        )?;
+        let import_map_url =
+          relative_resource_url(&entry_js_resource_name, &import_map_resource.name);
+        let import_map_require_code = match context.config.output.format {
+          ModuleFormat::EsModule => format!("import \"{import_map_url}\";"),
+          ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+            format!("require(\"{import_map_url}\");")
+          }
+        };
+        resources_map.insert(import_map_resource.name.clone(), import_map_resource);
+
+        let mut logical_dep_resources = dep_resources
+          .iter()
+          .map(|rn| import_map.logical_name(rn))
+          .collect::<Vec<_>>();
+        logical_dep_resources.sort();
+
+        (import_map_require_code, logical_dep_resources)
+      } else {
+        let dep_resources_require_code = dep_resources
+          .iter()
+          .map(|rn| match context.config.output.format {
+            ModuleFormat::EsModule => format!("import \"./{rn}\";"),
+            ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+              format!("require(\"./{rn}\");")
+            }
+          })
+          .collect::<Vec<_>>()
+          .join("");
+
+        (dep_resources_require_code, dep_resources)
+      };
+
       // 4. setInitialLoadedResources and setDynamicModuleResourcesMap
       let set_initial_loaded_resources_code = format!(
         r#"{farm_global_this}.{FARM_MODULE_SYSTEM}.setInitialLoadedResources([{initial_loaded_resources}]);"#,
@@ -399,13 +476,15 @@ pub fn handle_entry_resources(
           let runtime_resource = if let Some(runtime_resource) = runtime_resource.as_ref() {
             runtime_resource
           } else {
-            runtime_resource = Some(create_farm_runtime_resource(runtime_code, context));
+            runtime_resource = Some(create_farm_runtime_resource(runtime_code, context)?);
             runtime_resource.as_ref().unwrap()
           };
 
           match context.config.output.format {
             ModuleFormat::EsModule => format!("import \"./{}\";", runtime_resource.name),
-            ModuleFormat::CommonJs => format!("require(\"./{}\");", runtime_resource.name),
+            ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+              format!("require(\"./{}\");", runtime_resource.name)
+            }
           }
         } else {
           runtime_code.clone()
@@ -460,6 +539,8 @@ pub fn handle_entry_resources(
       resources_map.insert(runtime_resource.name.clone(), runtime_resource);
     }
   }
+
+  Ok(())
 }
 
 fn create_runtime_code(
@@ -473,7 +554,9 @@ fn create_runtime_code(
           r#"import {FARM_NODE_MODULE} from 'node:module';global.nodeRequire = {FARM_NODE_MODULE}.createRequire(import.meta.url);"#
         )
       }
-      ModuleFormat::CommonJs => r#"global.nodeRequire = require;"#.to_string(), // _ => panic!("node only support cjs and esm format"),
+      ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+        r#"global.nodeRequire = require;"#.to_string()
+      } // _ => panic!("node only support cjs and esm format"),
     }
   } else {
     "".to_string()
@@ -509,20 +592,103 @@ fn create_runtime_code(
   format!("{node_specific_code}{farm_global_this_code}{runtime_resource_code}")
 }
 
-fn create_farm_runtime_resource(runtime_code: &str, context: &Arc<CompilationContext>) -> Resource {
+/// The import map resource imports the dep resources of the entry and registers the import map to the module system.
+/// Its name does not contain a content hash so the entry resource can reference it by a stable name
+fn create_import_map_resource(
+  entry_name: &str,
+  entry_js_resource_name: &str,
+  dep_resources: &[String],
+  import_map: &ImportMap,
+  farm_global_this: &str,
+  resources_map: &HashMap<String, Resource>,
+  context: &Arc<CompilationContext>,
+) -> Result<Resource> {
+  let filename = context
+    .config
+    .output
+    .import_map
+    .clone()
+    .unwrap_or_default()
+    .filename;
+  let name = filename.replace(ENTRY_NAME, entry_name);
+
+  if resources_map.contains_key(&name) {
+    return Err(CompilationError::GenericError(format!(
+      "The import map resource `{name}` of entry `{entry_name}` conflicts with another resource, \
+       `output.importMap.filename` must contain `{ENTRY_NAME}` when there are multiple entries"
+    )));
+  }
+
+  // the dep resources are imported relative to the import map resource instead of the entry resource
+  let dep_resources_require_code = dep_resources
+    .iter()
+    .map(|rn| {
+      let url = relative_resource_url(&name, rn);
+
+      match context.config.output.format {
+        ModuleFormat::EsModule => format!("import \"{url}\";"),
+        ModuleFormat::CommonJs | ModuleFormat::Iife | ModuleFormat::Umd => {
+          format!("require(\"{url}\");")
+        }
+      }
+    })
+    .collect::<Vec<_>>()
+    .join("");
+  let code = format!(
+    r#"{dep_resources_require_code}{farm_global_this}.{FARM_MODULE_SYSTEM}.setImportMap({});"#,
+    import_map.imports_code()
+  );
+
+  Ok(Resource {
+    name,
+    bytes: code.into_bytes(),
+    emitted: false,
+    resource_type: ResourceType::Js,
+    // the import map is generated for the entry, so it belongs to the resource pot of the entry
+    origin: resources_map[entry_js_resource_name].origin.clone(),
+    info: None,
+  })
+}
+
+/// The url of resource `to` that is imported by resource `from`, both names are relative to `output.path`
+fn relative_resource_url(from: &str, to: &str) -> String {
+  let mut from_dir = from.split('/').collect::<Vec<_>>();
+  from_dir.pop();
+  let to_parts = to.split('/').collect::<Vec<_>>();
+
+  let common = from_dir
+    .iter()
+    .zip(&to_parts[..to_parts.len() - 1])
+    .take_while(|(a, b)| a == b)
+    .count();
+  let prefix = match from_dir.len() - common {
+    0 => "./".to_string(),
+    n => "../".repeat(n),
+  };
+
+  format!("{prefix}{}", to_parts[common..].join("/"))
+}
+
+fn create_farm_runtime_resource(
+  runtime_code: &str,
+  context: &Arc<CompilationContext>,
+) -> Result<Resource> {
   let bytes = runtime_code.to_string().into_bytes();
-  let name = transform_output_entry_filename(
-    context.config.output.entry_filename.clone(),
-    "__farm_runtime",
+  let mut params = PluginOutputFilenameHookParams::new(
+    OutputFilenameKind::Entry,
     "__farm_runtime",
-    &bytes,
     "js", // todo: support configuring extension
-          // match context.config.output.format {
-          //   ModuleFormat::EsModule => "mjs",
-          //   ModuleFormat::CommonJs => "cjs",
-          // },
+    &context.config,
   );
-  Resource {
+  params.entry_name = Some("__farm_runtime".to_string());
+
+  let name = transform_output_filename(
+    &context.config.output.entry_filename,
+    &params,
+    &bytes,
+    context,
+  )?;
+  Ok(Resource {
     name: name.clone(),
     bytes,
     emitted: false,
@@ -530,5 +696,5 @@ fn create_farm_runtime_resource(runtime_code: &str, context: &Arc<CompilationCon
     resource_type: ResourceType::Js,
     origin: ResourceOrigin::ResourcePot(name),
     info: None,
-  }
+  })
 }
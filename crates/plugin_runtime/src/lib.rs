@@ -64,6 +64,15 @@ impl Plugin for FarmPluginRuntime {
     if config.output.target_env.is_library() {
       return Ok(None);
     }
+
+    // iife and umd bundles are generated by plugin_bundle, the runtime does not support them
+    if matches!(config.output.format, ModuleFormat::Iife | ModuleFormat::Umd) {
+      return Err(CompilationError::GenericError(format!(
+        "output.format `{}` is only supported by library targets, set output.targetEnv to `library`, `library-browser` or `library-node`",
+        config.output.format.as_str()
+      )));
+    }
+
     // runtime package entry file
     if !config.runtime.path.is_empty() {
       config.input.insert(
@@ -458,7 +467,7 @@ impl Plugin for FarmPluginRuntime {
 
     let async_modules = self.get_async_modules(context);
     let async_modules = async_modules.downcast_ref::<HashSet<ModuleId>>().unwrap();
-    handle_entry_resources::handle_entry_resources(param.resources_map, context, async_modules);
+    handle_entry_resources::handle_entry_resources(param.resources_map, context, async_modules)?;
 
     Ok(Some(()))
   }
@@ -8,7 +8,7 @@ use std::{
 
 use deps_analyzer::DepsAnalyzer;
 use farmfe_core::{
-  config::{Config, ModuleFormat, TargetEnv},
+  config::{Config, ModuleFormat, OutputConfig, TargetEnv},
   context::CompilationContext,
   error::Result,
   module::{
@@ -27,21 +27,23 @@ use farmfe_core::{
   swc_common::{comments::SingleThreadedComments, Mark, GLOBALS},
   swc_ecma_ast::EsVersion,
 };
-use farmfe_swc_transformer_import_glob::transform_import_meta_glob;
+use farmfe_swc_transformer_import_glob::{transform_import_meta_glob, transform_require_context};
 use farmfe_toolkit::{
   common::{
     create_swc_source_map, generate_source_map_resource, load_source_original_source_map, Source,
   },
   fs::read_file_utf8,
   script::{
-    module_type_from_id, parse_module, set_module_system_for_module_meta, swc_try_with::try_with,
-    syntax_from_module_type, ParseScriptModuleResult,
+    magic_comments::collect_dynamic_import_magic_comments, module_type_from_id, parse_module,
+    set_module_system_for_module_meta, swc_try_with::try_with, syntax_from_module_type,
+    ParseScriptModuleResult,
   },
   swc_ecma_transforms::resolver,
   swc_ecma_visit::VisitMutWith,
 };
 
-use import_meta_visitor::{replace_import_meta_url, ImportMetaVisitor};
+pub use import_meta_visitor::replace_import_meta_url;
+use import_meta_visitor::ImportMetaVisitor;
 #[cfg(feature = "swc_plugin")]
 use swc_plugins::{init_plugin_module_cache_once, transform_by_swc_plugins};
 
@@ -196,12 +198,26 @@ impl Plugin for FarmPluginScript {
 
       transform_url_with_import_meta_url(ast, &comments);
 
-      transform_import_meta_glob(
+      let mut watch_globs = transform_import_meta_glob(
         ast,
         context.config.root.clone(),
-        cur_dir,
+        cur_dir.clone(),
         &context.config.resolve.alias,
       )?;
+      // transform webpack-style `require.context`, the context directories are watched like `import.meta.glob`
+      let unresolved_mark = Mark::from_u32(script.unresolved_mark);
+      watch_globs.extend(GLOBALS.set(&context.meta.script.globals, || {
+        transform_require_context(
+          ast,
+          context.config.root.clone(),
+          cur_dir,
+          &context.config.resolve.alias,
+          unresolved_mark,
+        )
+      })?);
+      // creating or deleting a file matched by the globs updates the module
+      context.set_watch_globs(param.module_id.clone(), watch_globs);
+
       script.set_comments(comments.into())
     }
 
@@ -251,10 +267,14 @@ impl Plugin for FarmPluginScript {
     // set param.module.meta.module_system
     set_module_system_for_module_meta(param, context);
 
-    let is_replace_import_meta_url = context.config.output.target_env.is_library()
-      && matches!(context.config.output.format, ModuleFormat::CommonJs);
+    // magic comments of dynamic imports are used by partial bundling and resource hints
+    if param.deps.iter().any(|dep| dep.kind.is_dynamic()) {
+      let script = param.module.meta.as_script();
+      param.module.dynamic_import_magic_comments =
+        collect_dynamic_import_magic_comments(&script.ast, &script.comments);
+    }
 
-    if is_replace_import_meta_url {
+    if is_replace_import_meta_url_when_building(&context.config) {
       let ast = &mut param.module.meta.as_script_mut().ast;
       replace_import_meta_url(ast)
     };
@@ -331,3 +351,19 @@ impl FarmPluginScript {
     Self {}
   }
 }
+
+/// `import.meta.url` is replaced when building modules of library cjs outputs.
+/// When `output.variants` mix cjs and other formats, it's replaced in the module graph of the cjs outputs before generating them
+pub fn is_replace_import_meta_url_when_building(config: &Config) -> bool {
+  is_library_cjs(&config.output)
+    && config
+      .output
+      .variants
+      .iter()
+      .all(|variant| is_library_cjs(&config.output.variant(variant)))
+}
+
+/// Whether the output is a library cjs output, whose modules should not contain `import.meta.url`
+pub fn is_library_cjs(output: &OutputConfig) -> bool {
+  output.target_env.is_library() && output.format == ModuleFormat::CommonJs
+}
@@ -9,11 +9,13 @@ use std::{
 use base64::engine::{general_purpose, Engine};
 use farmfe_core::{
   cache_item,
-  config::{asset::AssetFormatMode, custom::get_config_assets_mode, Config},
+  config::{
+    asset::AssetFormatMode, config_regex::ConfigRegex, custom::get_config_assets_mode, Config,
+  },
   context::{CompilationContext, EmitFileParams},
   deserialize,
   module::ModuleType,
-  plugin::{Plugin, PluginResolveHookResult},
+  plugin::{OutputFilenameKind, Plugin, PluginOutputFilenameHookParams, PluginResolveHookResult},
   relative_path::RelativePath,
   resource::{Resource, ResourceOrigin, ResourceType},
   rkyv::Deserialize,
@@ -24,13 +26,13 @@ use farmfe_toolkit::{
   fs::{read_file_raw, read_file_utf8, transform_output_filename},
   lazy_static::lazy_static,
 };
-use farmfe_utils::{hash::sha256, stringify_query, FARM_IGNORE_ACTION_COMMENT};
+use farmfe_utils::{hash::sha256, relative, stringify_query, FARM_IGNORE_ACTION_COMMENT};
 
-// Default supported static assets: png, jpg, jpeg, gif, svg, webp, mp4, webm, wav, mp3, wma, m4a, aac, ico, ttf, woff, woff2
+// Default supported static assets: png, jpg, jpeg, gif, svg, webp, mp4, webm, wav, mp3, wma, m4a, aac, ico, ttf, woff, woff2, txt, eot, webmanifest
 lazy_static! {
   static ref DEFAULT_STATIC_ASSETS: Vec<&'static str> = vec![
     "png", "jpg", "jpeg", "gif", "svg", "webp", "mp4", "webm", "wav", "mp3", "wma", "m4a", "aac",
-    "ico", "ttf", "woff", "woff2", "txt", "eot"
+    "ico", "ttf", "woff", "woff2", "txt", "eot", "webmanifest"
   ];
 }
 
@@ -66,6 +68,26 @@ impl FarmPluginStaticAssets {
         .any(|a| a.eq_ignore_ascii_case(ext))
   }
 
+  /// Whether the asset should be inlined as a data uri, see `assets.inlineLimit`
+  fn should_inline(
+    &self,
+    resolved_path: &str,
+    size: usize,
+    context: &Arc<CompilationContext>,
+  ) -> bool {
+    let assets = &context.config.assets;
+
+    let Some(inline_limit) = assets.inline_limit else {
+      return false;
+    };
+
+    let is_match = |regexes: &Vec<ConfigRegex>| regexes.iter().any(|r| r.is_match(resolved_path));
+
+    size < inline_limit
+      && (assets.inline_include.is_empty() || is_match(&assets.inline_include))
+      && !is_match(&assets.inline_exclude)
+  }
+
   fn get_resource_name(name: &str, module_id: &str) -> String {
     let last_dot = name.rfind('.').unwrap_or(0);
     if last_dot == 0 {
@@ -208,17 +230,41 @@ impl Plugin for FarmPluginStaticAssets {
           .extension()
           .and_then(|s| s.to_str())
           .unwrap();
+        let module_id = context.str_to_module_id(&param.module_id);
+
+        if self.should_inline(param.resolved_path, bytes.len(), context) {
+          let data_uri = to_data_uri(ext, &bytes);
+          context.set_inlined_asset(&module_id, data_uri.clone());
+
+          return Ok(Some(farmfe_core::plugin::PluginTransformHookResult {
+            content: format!("export default {data_uri:?};"),
+            module_type: Some(ModuleType::Js),
+            source_map: None,
+            ignore_previous_source_map: false,
+          }));
+        }
+
+        // the asset may be inlined before it's updated
+        context.remove_inlined_asset(&module_id);
 
         let filename = Path::new(param.resolved_path)
           .file_prefix()
           .and_then(|s| s.to_str())
           .unwrap();
-        let resource_name = transform_output_filename(
-          context.config.output.assets_filename.clone(),
+        let mut filename_params = PluginOutputFilenameHookParams::new(
+          OutputFilenameKind::Asset,
           filename,
-          &bytes,
           ext,
-        ) + stringify_query(&param.query).as_str();
+          &context.config,
+        );
+        filename_params.dir = Some(relative_dir(&context.config.root, param.resolved_path));
+
+        let resource_name = transform_output_filename(
+          &context.config.output.assets_filename,
+          &filename_params,
+          &bytes,
+          context,
+        )? + stringify_query(&param.query).as_str();
 
         let resource_name = Self::get_resource_name(&resource_name, &param.module_id);
 
@@ -254,7 +300,7 @@ impl Plugin for FarmPluginStaticAssets {
           name: resource_name,
           content: bytes,
           resource_type: ResourceType::Asset(ext.to_string()),
-        });
+        })?;
 
         return Ok(Some(farmfe_core::plugin::PluginTransformHookResult {
           content,
@@ -275,6 +321,10 @@ impl Plugin for FarmPluginStaticAssets {
   ) -> farmfe_core::error::Result<Option<()>> {
     let cached_static_assets = deserialize!(cache, CachedStaticAssets);
 
+    for (module_id, data_uri) in cached_static_assets.inlined {
+      context.set_inlined_asset(&module_id.into(), data_uri);
+    }
+
     for asset in cached_static_assets.list {
       if let ResourceOrigin::Module(m) = asset.origin {
         context.emit_file(EmitFileParams {
@@ -282,7 +332,7 @@ impl Plugin for FarmPluginStaticAssets {
           name: asset.name,
           content: asset.bytes,
           resource_type: asset.resource_type,
-        });
+        })?;
       }
     }
 
@@ -295,6 +345,12 @@ impl Plugin for FarmPluginStaticAssets {
   ) -> farmfe_core::error::Result<Option<Vec<u8>>> {
     let mut list = vec![];
     let resources_map = context.resources_map.lock();
+    let inlined = context
+      .inlined_assets()
+      .into_iter()
+      .filter(|(m, _)| context.cache_manager.module_cache.has_cache(m))
+      .map(|(m, data_uri)| (m.to_string(), data_uri))
+      .collect::<HashMap<_, _>>();
 
     for (_, resource) in resources_map.iter() {
       if let ResourceOrigin::Module(m) = &resource.origin {
@@ -304,8 +360,8 @@ impl Plugin for FarmPluginStaticAssets {
       }
     }
 
-    if !list.is_empty() {
-      let cached_static_assets = CachedStaticAssets { list };
+    if !list.is_empty() || !inlined.is_empty() {
+      let cached_static_assets = CachedStaticAssets { list, inlined };
 
       Ok(Some(serialize!(&cached_static_assets)))
     } else {
@@ -317,6 +373,91 @@ impl Plugin for FarmPluginStaticAssets {
 #[cache_item]
 struct CachedStaticAssets {
   list: Vec<Resource>,
+  /// key: module id of the inlined asset, value: data uri
+  inlined: HashMap<String, String>,
+}
+
+/// Directory of the asset relative to root for `[dir]` and `[path]`. Assets outside of the root are placed under
+/// `_external` with `..` replaced by `_`, so they stay inside the output dir and do not collide with the assets under the
+/// root
+fn relative_dir(root: &str, resolved_path: &str) -> String {
+  let Some(parent) = Path::new(resolved_path).parent() else {
+    return String::new();
+  };
+
+  let dir = relative(root, &parent.to_string_lossy());
+  let segments = dir
+    .split('/')
+    .filter(|c| !matches!(*c, "" | "."))
+    .map(|c| if c == ".." { "_" } else { c })
+    .collect::<Vec<_>>();
+
+  if dir.starts_with("..") {
+    format!("_external/{}", segments.join("/"))
+  } else {
+    segments.join("/")
+  }
+}
+
+/// Inline svg as url encoded data uri if it's smaller than base64, other assets are always base64 encoded
+fn to_data_uri(ext: &str, bytes: &[u8]) -> String {
+  let mime_type = mime_guess::from_ext(ext).first_or_octet_stream();
+  let base64_uri = format!(
+    "data:{mime_type};base64,{}",
+    general_purpose::STANDARD.encode(bytes)
+  );
+
+  if ext.eq_ignore_ascii_case("svg") {
+    if let Ok(svg) = std::str::from_utf8(bytes) {
+      let encoded_uri = format!("data:image/svg+xml,{}", encode_svg(svg));
+
+      if encoded_uri.len() < base64_uri.len() {
+        return encoded_uri;
+      }
+    }
+  }
+
+  base64_uri
+}
+
+/// Url encode the svg, only the characters that are not allowed in a data uri are escaped to keep it small
+fn encode_svg(svg: &str) -> String {
+  // double quotes are replaced by single quotes to avoid escaping, unless the svg already contains single quotes
+  let replace_quote = !svg.contains('\'');
+  let mut encoded = String::with_capacity(svg.len());
+  let mut last_whitespace = false;
+
+  for c in svg.trim().chars() {
+    if c.is_whitespace() {
+      if !last_whitespace {
+        encoded.push_str("%20");
+      }
+      last_whitespace = true;
+      continue;
+    }
+    last_whitespace = false;
+
+    match c {
+      '"' if replace_quote => encoded.push('\''),
+      '"' => encoded.push_str("%22"),
+      '%' => encoded.push_str("%25"),
+      '#' => encoded.push_str("%23"),
+      '<' => encoded.push_str("%3C"),
+      '>' => encoded.push_str("%3E"),
+      '{' => encoded.push_str("%7B"),
+      '}' => encoded.push_str("%7D"),
+      c if !c.is_ascii() || c.is_ascii_control() => {
+        let mut buf = [0; 4];
+
+        for b in c.encode_utf8(&mut buf).bytes() {
+          encoded.push_str(&format!("%{b:02X}"));
+        }
+      }
+      c => encoded.push(c),
+    }
+  }
+
+  encoded
 }
 
 pub struct FarmPluginRaw {}
@@ -16,8 +16,10 @@
 
 use std::collections::HashMap;
 use std::path::Component;
+use std::path::Path;
 use std::path::PathBuf;
 
+use farmfe_core::module::watch_graph::{WatchGlob, WatchGlobPattern};
 use farmfe_core::regex;
 use farmfe_core::relative_path::RelativePath;
 use farmfe_core::swc_common::DUMMY_SP;
@@ -31,14 +33,20 @@ use farmfe_core::wax::Glob;
 use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
 use farmfe_utils::relative;
 
+pub use require_context::transform_require_context;
+
+mod require_context;
+
 const REGEX_PREFIX: &str = "$__farm_regex:";
 
+/// Transform `import.meta.glob` calls, returns the glob patterns of the calls, creating or deleting a file matched by the
+/// patterns should update the module
 pub fn transform_import_meta_glob(
   ast: &mut SwcModule,
   root: String,
   cur_dir: String,
   alias: &HashMap<String, String>,
-) -> farmfe_core::error::Result<()> {
+) -> farmfe_core::error::Result<Vec<WatchGlob>> {
   let mut visitor = ImportGlobVisitor::new(cur_dir, root, alias);
   ast.visit_mut_with(&mut visitor);
 
@@ -119,7 +127,7 @@ pub fn transform_import_meta_glob(
     }
   }
 
-  Ok(())
+  Ok(visitor.watch_globs)
 }
 
 /// import { <import> as __glob__0_0 } from './dir/foo.js'
@@ -231,6 +239,7 @@ pub struct ImportGlobVisitor<'a> {
   cur_dir: String,
   root: String,
   alias: &'a HashMap<String, String>,
+  watch_globs: Vec<WatchGlob>,
   pub errors: Vec<String>,
 }
 
@@ -242,6 +251,7 @@ impl<'a> ImportGlobVisitor<'a> {
       root,
       errors: vec![],
       alias,
+      watch_globs: vec![],
     }
   }
 
@@ -336,6 +346,7 @@ impl<'a> ImportGlobVisitor<'a> {
   /// Glob the sources and filter negative sources, return globs relative paths
   fn glob_and_filter_sources(&mut self, sources: &Vec<String>) -> HashMap<String, String> {
     let mut paths = vec![];
+    let mut watch_glob = WatchGlob::default();
 
     for source in sources {
       let mut negative = false;
@@ -392,6 +403,14 @@ impl<'a> ImportGlobVisitor<'a> {
       let glob = Glob::new(&rel_source);
       match glob {
         Ok(glob) => {
+          let watch_pattern = create_watch_glob_pattern(&root, glob.clone());
+
+          if negative {
+            watch_glob.excludes.push(watch_pattern);
+          } else {
+            watch_glob.includes.push(watch_pattern);
+          }
+
           let p = glob
             .walk(&root)
             // filter out directory
@@ -465,6 +484,10 @@ impl<'a> ImportGlobVisitor<'a> {
       }
     }
 
+    if !watch_glob.includes.is_empty() {
+      self.watch_globs.push(watch_glob);
+    }
+
     filtered_paths
   }
 
@@ -815,3 +838,26 @@ fn get_object_literal(expr: &ExprOrSpread) -> Option<HashMap<String, String>> {
     _ => None,
   }
 }
+
+/// Split the glob into the invariant directory and the pattern relative to the directory, so that only the directory is
+/// watched, e.g. `src/pages/**/*.ts` is split into `/root/src/pages` and `**/*.ts`
+fn create_watch_glob_pattern(root: &str, glob: Glob) -> WatchGlobPattern {
+  let (prefix, glob) = glob.partition();
+  let base = Path::new(root).join(prefix);
+  let pattern = glob.to_string();
+
+  // the glob is a literal path
+  if pattern.is_empty() {
+    if let (Some(parent), Some(file_name)) = (base.parent(), base.file_name()) {
+      return WatchGlobPattern {
+        base: parent.to_string_lossy().to_string(),
+        pattern: farmfe_core::wax::escape(&file_name.to_string_lossy()).to_string(),
+      };
+    }
+  }
+
+  WatchGlobPattern {
+    base: base.to_string_lossy().to_string(),
+    pattern,
+  }
+}
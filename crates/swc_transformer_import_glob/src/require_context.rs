@@ -0,0 +1,355 @@
+//! Transform webpack-style `require.context`. See https://webpack.js.org/guides/dependency-management/#requirecontext
+//! for example:
+//! ```js
+//! const context = require.context('./dir', false, /\.js$/);
+//! ```
+//! will be transformed to:
+//! ```js
+//! const context = (function() {
+//!   var map = {
+//!     "./bar.js": function() { return require("./dir/bar.js"); },
+//!     "./foo.js": function() { return require("./dir/foo.js"); }
+//!   };
+//!   function webpackContextResolve(req) { ... }
+//!   function webpackContext(req) { return map[webpackContextResolve(req)](); }
+//!   webpackContext.keys = function() { return Object.keys(map); };
+//!   webpackContext.resolve = webpackContextResolve;
+//!   webpackContext.id = "./dir";
+//!   return webpackContext;
+//! })();
+//! ```
+//! The modules are loaded by `import()` instead of `require()` when the mode is `lazy` or `lazy-once`, and the context
+//! function returns a promise when the mode is `lazy`, `lazy-once` or `eager`. In `lazy-once` mode all modules of the
+//! context are loaded together by the first call, the following calls reuse the loaded modules.
+//!
+//! Only the global `require` is transformed, a `require` declared in the module is left untouched.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use farmfe_core::module::watch_graph::WatchGlob;
+use farmfe_core::regex::Regex;
+use farmfe_core::relative_path::RelativePath;
+use farmfe_core::swc_common::{FileName, Mark, SourceMap, Span, DUMMY_SP};
+use farmfe_core::swc_ecma_ast::{
+  CallExpr, Callee, EsVersion, Expr, ExprOrSpread, Ident, Lit, MemberExpr, MemberProp,
+  Module as SwcModule,
+};
+use farmfe_core::swc_ecma_parser::{parse_file_as_expr, Syntax};
+use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
+use farmfe_utils::relative;
+
+use crate::ImportGlobVisitor;
+
+/// the default `regExp` argument of `require.context`
+const DEFAULT_CONTEXT_REGEX: &str = r"^\./.*$";
+
+/// Transform `require.context` calls, returns the glob patterns of the context directories, the patterns should be
+/// watched so that the importer is updated when a file is added to or removed from the directory.
+/// `unresolved_mark` is the mark of the global identifiers, it must be called inside `GLOBALS.set`.
+pub fn transform_require_context(
+  ast: &mut SwcModule,
+  root: String,
+  cur_dir: String,
+  alias: &HashMap<String, String>,
+  unresolved_mark: Mark,
+) -> farmfe_core::error::Result<Vec<WatchGlob>> {
+  let mut visitor = RequireContextVisitor {
+    glob_visitor: ImportGlobVisitor::new(cur_dir, root, alias),
+    unresolved_mark,
+  };
+  ast.visit_mut_with(&mut visitor);
+
+  if !visitor.glob_visitor.errors.is_empty() {
+    return Err(farmfe_core::error::CompilationError::GenericError(
+      visitor.glob_visitor.errors.join("\n"),
+    ));
+  }
+
+  Ok(visitor.glob_visitor.watch_globs)
+}
+
+#[derive(Debug, PartialEq)]
+enum RequireContextMode {
+  Sync,
+  Lazy,
+  LazyOnce,
+  Eager,
+}
+
+#[derive(Debug)]
+struct RequireContextInfo {
+  /// './dir' of `require.context('./dir', true, /\.js$/, 'sync')`
+  dir: String,
+  recursive: bool,
+  regex: Regex,
+  mode: RequireContextMode,
+}
+
+struct RequireContextVisitor<'a> {
+  glob_visitor: ImportGlobVisitor<'a>,
+  unresolved_mark: Mark,
+}
+
+impl<'a> RequireContextVisitor<'a> {
+  fn create_require_context_info(&mut self, args: &[ExprOrSpread]) -> Option<RequireContextInfo> {
+    let Some(box Expr::Lit(Lit::Str(dir))) = args.first().map(|arg| &arg.expr) else {
+      self
+        .glob_visitor
+        .errors
+        .push("Error when require.context: the directory must be a string literal".to_string());
+      return None;
+    };
+    let dir = dir.value.to_string();
+
+    let recursive = match args.get(1).map(|arg| &arg.expr) {
+      None => true,
+      Some(box Expr::Lit(Lit::Bool(b))) => b.value,
+      Some(_) => {
+        self.glob_visitor.errors.push(format!(
+          "Error when require.context {dir}: useSubdirectories must be a boolean literal"
+        ));
+        return None;
+      }
+    };
+
+    let regex = match args.get(2).map(|arg| &arg.expr) {
+      None => Regex::new(DEFAULT_CONTEXT_REGEX),
+      Some(box Expr::Lit(Lit::Regex(regex))) => {
+        let flags = regex.flags.chars().filter(|f| matches!(f, 'i' | 'm' | 's'));
+        let flags = flags.collect::<String>();
+
+        if flags.is_empty() {
+          Regex::new(&regex.exp)
+        } else {
+          Regex::new(&format!("(?{flags}){}", regex.exp))
+        }
+      }
+      Some(_) => {
+        self.glob_visitor.errors.push(format!(
+          "Error when require.context {dir}: regExp must be a regular expression literal"
+        ));
+        return None;
+      }
+    };
+    let regex = match regex {
+      Ok(regex) => regex,
+      Err(err) => {
+        self.glob_visitor.errors.push(format!(
+          "Error when require.context {dir}: unsupported regExp: {err}"
+        ));
+        return None;
+      }
+    };
+
+    let mode = match args.get(3).map(|arg| &arg.expr) {
+      None => RequireContextMode::Sync,
+      Some(box Expr::Lit(Lit::Str(mode))) => match &*mode.value {
+        "sync" => RequireContextMode::Sync,
+        "lazy" => RequireContextMode::Lazy,
+        "lazy-once" => RequireContextMode::LazyOnce,
+        "eager" => RequireContextMode::Eager,
+        mode => {
+          self.glob_visitor.errors.push(format!(
+            "Error when require.context {dir}: unsupported mode `{mode}`, only `sync`, `lazy`, `lazy-once` and `eager` are supported"
+          ));
+          return None;
+        }
+      },
+      Some(_) => {
+        self.glob_visitor.errors.push(format!(
+          "Error when require.context {dir}: mode must be a string literal"
+        ));
+        return None;
+      }
+    };
+
+    Some(RequireContextInfo {
+      dir,
+      recursive,
+      regex,
+      mode,
+    })
+  }
+
+  /// Glob the files of the context directory, returns the sorted (key, relative file) pairs matched by the regex.
+  /// The key is relative to the context directory like webpack, e.g. `./foo.js`
+  fn glob_context_files(&mut self, info: &RequireContextInfo) -> Option<Vec<(String, String)>> {
+    let dir = self.glob_visitor.try_alias(&info.dir);
+
+    if !dir.starts_with('.') && !dir.starts_with('/') {
+      self.glob_visitor.errors.push(format!(
+        "Error when require.context {dir}: the directory must be relative path. e.g. './dir' or '/dir'(relative to root)"
+      ));
+      return None;
+    }
+
+    let cur_dir = self.glob_visitor.cur_dir.clone();
+    let context_dir = if let Some(dir) = dir.strip_prefix('/') {
+      RelativePath::new(dir).to_logical_path(&self.glob_visitor.root)
+    } else {
+      RelativePath::new(&dir).to_logical_path(&cur_dir)
+    };
+    let context_dir = context_dir.to_string_lossy().to_string();
+    let pattern = format!(
+      "{}/{}",
+      dir.trim_end_matches('/'),
+      if info.recursive { "**/*" } else { "*" }
+    );
+
+    let mut files = self
+      .glob_visitor
+      .glob_and_filter_sources(&vec![pattern])
+      .into_keys()
+      .filter_map(|relative_file| {
+        let file = RelativePath::new(&relative_file).to_logical_path(&cur_dir);
+        let key = format!("./{}", relative(&context_dir, &file.to_string_lossy()));
+
+        info.regex.is_match(&key).then_some((key, relative_file))
+      })
+      .collect::<Vec<_>>();
+    files.sort();
+
+    Some(files)
+  }
+
+  fn create_context_expr(
+    &mut self,
+    info: &RequireContextInfo,
+    files: Vec<(String, String)>,
+    require_span: Span,
+  ) -> Option<Box<Expr>> {
+    // the modules of eager mode are still in the current resource pot, only the context function is async
+    let load = if matches!(
+      info.mode,
+      RequireContextMode::Lazy | RequireContextMode::LazyOnce
+    ) {
+      "import"
+    } else {
+      "require"
+    };
+    let map_props = files
+      .iter()
+      .map(|(key, relative_file)| {
+        format!(
+          "    {}: function() {{ return {load}({}); }}",
+          to_js_string(key),
+          to_js_string(relative_file)
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(",\n");
+    let context_fn = match info.mode {
+      RequireContextMode::Sync => {
+        "function webpackContext(req) { return map[webpackContextResolve(req)](); }"
+      }
+      RequireContextMode::Lazy | RequireContextMode::Eager => {
+        "function webpackContext(req) { return Promise.resolve().then(function() { return map[webpackContextResolve(req)](); }); }"
+      }
+      // all modules are loaded by the first call, like the single chunk of webpack
+      RequireContextMode::LazyOnce => {
+        "var modules; function webpackContext(req) { return Promise.resolve().then(function() { var key = webpackContextResolve(req); var keys = Object.keys(map); modules = modules || Promise.all(keys.map(function(k) { return map[k](); })); return modules.then(function(loaded) { return loaded[keys.indexOf(key)]; }); }); }"
+      }
+    };
+    let code = format!(
+      r#"(function() {{
+  var map = {{
+{map_props}
+  }};
+  function webpackContextResolve(req) {{
+    if (!Object.prototype.hasOwnProperty.call(map, req)) {{
+      var e = new Error("Cannot find module '" + req + "'");
+      e.code = "MODULE_NOT_FOUND";
+      throw e;
+    }}
+    return req;
+  }}
+  {context_fn}
+  webpackContext.keys = function() {{ return Object.keys(map); }};
+  webpackContext.resolve = webpackContextResolve;
+  webpackContext.id = {id};
+  return webpackContext;
+}})()"#,
+      id = to_js_string(&info.dir)
+    );
+
+    let cm = Arc::new(SourceMap::default());
+    let fm = cm.new_source_file(FileName::Anon, code);
+
+    match parse_file_as_expr(
+      &fm,
+      Syntax::Es(Default::default()),
+      EsVersion::EsNext,
+      None,
+      &mut vec![],
+    ) {
+      Ok(mut expr) => {
+        // the spans of the parsed expr are meaningless in the original module, `require` keeps the span of the original
+        // `require` so it's still recognized as the commonjs require
+        expr.visit_mut_with(&mut Respanner { require_span });
+        Some(expr)
+      }
+      Err(err) => {
+        self.glob_visitor.errors.push(format!(
+          "Error when require.context {}: failed to generate the context module: {err:?}",
+          info.dir
+        ));
+        None
+      }
+    }
+  }
+}
+
+impl<'a> VisitMut for RequireContextVisitor<'a> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    match expr {
+      Expr::Call(CallExpr {
+        callee:
+          Callee::Expr(box Expr::Member(MemberExpr {
+            obj: box Expr::Ident(require),
+            prop: MemberProp::Ident(Ident { sym, .. }),
+            ..
+          })),
+        args,
+        ..
+      }) if require.sym == *"require"
+        && require.span.ctxt.outer() == self.unresolved_mark
+        && *sym == *"context" =>
+      {
+        let require_span = require.span;
+
+        if let Some(info) = self.create_require_context_info(args) {
+          if let Some(files) = self.glob_context_files(&info) {
+            if let Some(context_expr) = self.create_context_expr(&info, files, require_span) {
+              *expr = *context_expr;
+            }
+          }
+        }
+      }
+      _ => {
+        expr.visit_mut_children_with(self);
+      }
+    }
+  }
+}
+
+struct Respanner {
+  require_span: Span,
+}
+
+impl VisitMut for Respanner {
+  fn visit_mut_span(&mut self, span: &mut Span) {
+    *span = DUMMY_SP;
+  }
+
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    if ident.sym == *"require" {
+      ident.span = self.require_span;
+    } else {
+      ident.visit_mut_children_with(self);
+    }
+  }
+}
+
+fn to_js_string(s: &str) -> String {
+  farmfe_core::serde_json::to_string(s).unwrap()
+}
@@ -0,0 +1,27 @@
+const context = (function() {
+    var map = {
+        "./baz.js": function() {
+            return require("./dir/sub/baz.js");
+        }
+    };
+    function webpackContextResolve(req) {
+        if (!Object.prototype.hasOwnProperty.call(map, req)) {
+            var e = new Error("Cannot find module '" + req + "'");
+            e.code = "MODULE_NOT_FOUND";
+            throw e;
+        }
+        return req;
+    }
+    function webpackContext(req) {
+        return Promise.resolve().then(function() {
+            return map[webpackContextResolve(req)]();
+        });
+    }
+    webpackContext.keys = function() {
+        return Object.keys(map);
+    };
+    webpackContext.resolve = webpackContextResolve;
+    webpackContext.id = "@/sub";
+    return webpackContext;
+})();
+context('./baz.js').then((m)=>console.log(m));
@@ -0,0 +1,3 @@
+const context = require.context('@/sub', true, /\.js$/, 'eager');
+
+context('./baz.js').then((m) => console.log(m));
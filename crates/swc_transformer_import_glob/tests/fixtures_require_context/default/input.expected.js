@@ -0,0 +1,34 @@
+const context = (function() {
+    var map = {
+        "./README.md": function() {
+            return require("./dir/README.md");
+        },
+        "./bar.ts": function() {
+            return require("./dir/bar.ts");
+        },
+        "./foo.js": function() {
+            return require("./dir/foo.js");
+        },
+        "./sub/baz.js": function() {
+            return require("./dir/sub/baz.js");
+        }
+    };
+    function webpackContextResolve(req) {
+        if (!Object.prototype.hasOwnProperty.call(map, req)) {
+            var e = new Error("Cannot find module '" + req + "'");
+            e.code = "MODULE_NOT_FOUND";
+            throw e;
+        }
+        return req;
+    }
+    function webpackContext(req) {
+        return map[webpackContextResolve(req)]();
+    }
+    webpackContext.keys = function() {
+        return Object.keys(map);
+    };
+    webpackContext.resolve = webpackContextResolve;
+    webpackContext.id = "./dir";
+    return webpackContext;
+})();
+context.keys().forEach((key)=>context(key));
@@ -0,0 +1,3 @@
+const context = require.context('./dir');
+
+context.keys().forEach((key) => context(key));
@@ -0,0 +1,3 @@
+const context = require.context('./dir', true, /\.(js|ts)$/, 'lazy');
+
+Promise.all(context.keys().map((key) => context(key)));
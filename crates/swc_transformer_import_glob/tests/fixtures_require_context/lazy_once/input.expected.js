@@ -0,0 +1,41 @@
+const context = (function() {
+    var map = {
+        "./bar.ts": function() {
+            return import("./dir/bar.ts");
+        },
+        "./foo.js": function() {
+            return import("./dir/foo.js");
+        },
+        "./sub/baz.js": function() {
+            return import("./dir/sub/baz.js");
+        }
+    };
+    function webpackContextResolve(req) {
+        if (!Object.prototype.hasOwnProperty.call(map, req)) {
+            var e = new Error("Cannot find module '" + req + "'");
+            e.code = "MODULE_NOT_FOUND";
+            throw e;
+        }
+        return req;
+    }
+    var modules;
+    function webpackContext(req) {
+        return Promise.resolve().then(function() {
+            var key = webpackContextResolve(req);
+            var keys = Object.keys(map);
+            modules = modules || Promise.all(keys.map(function(k) {
+                return map[k]();
+            }));
+            return modules.then(function(loaded) {
+                return loaded[keys.indexOf(key)];
+            });
+        });
+    }
+    webpackContext.keys = function() {
+        return Object.keys(map);
+    };
+    webpackContext.resolve = webpackContextResolve;
+    webpackContext.id = "./dir";
+    return webpackContext;
+})();
+Promise.all(context.keys().map((key)=>context(key)));
@@ -0,0 +1,3 @@
+const context = require.context('./dir', false, /\.js$/i);
+
+context.keys().forEach((key) => context(key));
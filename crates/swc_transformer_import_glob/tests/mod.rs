@@ -55,3 +55,30 @@ fn test_import_meta_glob() {
     }
   });
 }
+
+#[test]
+fn test_import_meta_glob_watch_globs() {
+  let dir = std::env::current_dir()
+    .unwrap()
+    .join("tests/fixtures/glob_import_negative");
+  let dir = dir.to_string_lossy().to_string();
+  let ParseScriptModuleResult { mut ast, .. } = parse_module(
+    "input.js",
+    "const modules = import.meta.glob(['./dir/*.js', '!**/bar.js'])",
+    Syntax::Es(Default::default()),
+    EsVersion::EsNext,
+  )
+  .unwrap();
+
+  let watch_globs =
+    transform_import_meta_glob(&mut ast, dir.clone(), dir.clone(), &HashMap::new()).unwrap();
+  assert_eq!(watch_globs.len(), 1);
+
+  let watch_glob = &watch_globs[0];
+  assert_eq!(watch_glob.includes[0].base, format!("{dir}/dir"));
+  assert_eq!(watch_glob.includes[0].pattern, "*.js");
+  assert!(watch_glob.is_match(&format!("{dir}/dir/baz.js")));
+  assert!(!watch_glob.is_match(&format!("{dir}/dir/bar.js")));
+  assert!(!watch_glob.is_match(&format!("{dir}/dir/baz.ts")));
+  assert!(!watch_glob.is_match(&format!("{dir}/baz.js")));
+}
@@ -0,0 +1,123 @@
+use std::{collections::HashMap, sync::Arc};
+
+use farmfe_core::{
+  module::watch_graph::WatchGlob,
+  swc_common::{Globals, Mark, SourceMap, GLOBALS},
+  swc_ecma_ast::{EsVersion, Module},
+  swc_ecma_parser::Syntax,
+};
+use farmfe_swc_transformer_import_glob::transform_require_context;
+use farmfe_testing_helpers::fixture;
+use farmfe_toolkit::{
+  script::{codegen_module, parse_module, ParseScriptModuleResult},
+  swc_ecma_transforms::resolver,
+  swc_ecma_visit::VisitMutWith,
+};
+
+/// Resolve the identifiers of the module like the script plugin does, then transform `require.context`
+fn transform(
+  ast: &mut Module,
+  dir: &str,
+  alias: &HashMap<String, String>,
+) -> farmfe_core::error::Result<Vec<WatchGlob>> {
+  GLOBALS.set(&Globals::new(), || {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    ast.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+
+    transform_require_context(
+      ast,
+      dir.to_string(),
+      dir.to_string(),
+      alias,
+      unresolved_mark,
+    )
+  })
+}
+
+#[test]
+fn test_require_context() {
+  fixture!(
+    "tests/fixtures_require_context/**/input.js",
+    |file, _crate_path| {
+      println!("Testing {file:?}...");
+      let file_content = std::fs::read_to_string(&file).unwrap();
+      let cm = Arc::new(SourceMap::default());
+      let ParseScriptModuleResult { mut ast, .. } = parse_module(
+        file.to_string_lossy().to_string().as_str(),
+        &file_content,
+        Syntax::Es(Default::default()),
+        EsVersion::EsNext,
+      )
+      .unwrap();
+      let dir = file.parent().unwrap().to_str().unwrap();
+
+      let watch_globs = transform(
+        &mut ast,
+        dir,
+        &HashMap::from([(
+          "@".to_string(),
+          file
+            .parent()
+            .unwrap()
+            .join("dir")
+            .to_string_lossy()
+            .to_string(),
+        )]),
+      )
+      .unwrap();
+      assert_eq!(watch_globs.len(), 1);
+      assert!(watch_globs[0].includes[0].base.starts_with(dir));
+
+      let code = codegen_module(&ast, EsVersion::EsNext, cm, None, false, None).unwrap();
+      let code = String::from_utf8(code).unwrap();
+
+      let expected_file = file.with_extension("expected.js");
+      // write to file if not exists
+      if !expected_file.exists() {
+        std::fs::write(&expected_file, code).unwrap();
+      } else {
+        let expected = std::fs::read_to_string(&expected_file).unwrap();
+        assert_eq!(code, expected.replace("\r\n", "\n"));
+      }
+    }
+  );
+}
+
+#[test]
+fn test_require_context_unsupported_mode() {
+  let ParseScriptModuleResult { mut ast, .. } = parse_module(
+    "input.js",
+    "require.context('./dir', true, /\\.js$/, 'weak');",
+    Syntax::Es(Default::default()),
+    EsVersion::EsNext,
+  )
+  .unwrap();
+
+  let err = transform(&mut ast, ".", &HashMap::new())
+    .unwrap_err()
+    .to_string();
+  assert!(err.contains("unsupported mode `weak`"), "{err}");
+}
+
+#[test]
+fn test_require_context_shadowed_require() {
+  let code = "function load(require) {\n  return require.context('./dir');\n}\n";
+  let ParseScriptModuleResult { mut ast, .. } = parse_module(
+    "input.js",
+    code,
+    Syntax::Es(Default::default()),
+    EsVersion::EsNext,
+  )
+  .unwrap();
+
+  // the local `require` is not the webpack require, it's left untouched
+  let watch_globs = transform(&mut ast, ".", &HashMap::new()).unwrap();
+  assert!(watch_globs.is_empty());
+
+  let cm = Arc::new(SourceMap::default());
+  let output = codegen_module(&ast, EsVersion::EsNext, cm, None, false, None).unwrap();
+  assert!(String::from_utf8(output)
+    .unwrap()
+    .contains("require.context('./dir')"));
+}
@@ -1,4 +1,5 @@
 use std::{
+  fmt::Debug,
   path::{Path, PathBuf},
   sync::Arc,
 };
@@ -12,13 +13,14 @@ use farmfe_core::{
     SourcemapConfig,
   },
   enhanced_magic_string::collapse_sourcemap::collapse_sourcemap_chain,
+  error::ErrorLocation,
   relative_path::RelativePath,
   resource::{resource_pot::ResourcePot, Resource, ResourceOrigin, ResourceType},
   serde_json::Value,
   swc_common::{
     comments::{Comment, CommentKind, SingleThreadedComments},
     source_map::SourceMapGenConfig,
-    BytePos, FileName, LineCol, SourceFile, SourceMap,
+    BytePos, FileName, LineCol, SourceFile, SourceMap, Span,
   },
 };
 use farmfe_utils::hash::base64_decode;
@@ -38,6 +40,22 @@ pub fn create_swc_source_map(source: Source) -> (Arc<SourceMap>, Arc<SourceFile>
   (cm, sf)
 }
 
+/// Location of a swc parser error, the code of the location is the name of the error kind, e.g. `TS1109`
+pub fn create_error_location<K: Debug>(
+  cm: &SourceMap,
+  span: Span,
+  kind: &K,
+) -> Option<Box<ErrorLocation>> {
+  let kind = format!("{kind:?}");
+  let code = kind
+    .split(|c: char| !c.is_alphanumeric() && c != '_')
+    .next()
+    .unwrap_or_default()
+    .to_string();
+
+  ErrorLocation::from_span(cm, span, Some(code)).map(Box::new)
+}
+
 pub fn append_source_map_comment(
   resource: &mut Resource,
   map: &Resource,
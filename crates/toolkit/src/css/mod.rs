@@ -16,7 +16,9 @@ use swc_css_parser::{
 };
 use swc_error_reporters::handler::try_with_handler;
 
-use crate::common::{build_source_map, create_swc_source_map, Source};
+use crate::common::{build_source_map, create_error_location, create_swc_source_map, Source};
+
+pub mod purge;
 
 pub struct ParseCssModuleResult {
   pub ast: Stylesheet,
@@ -74,6 +76,11 @@ pub fn parse_css_stylesheet(
     }
   }
 
+  let loc = recovered_errors.first().and_then(|err| {
+    let (span, kind) = *err.clone().into_inner();
+    create_error_location(&cm, span, &kind)
+  });
+
   try_with_handler(cm, Default::default(), |handler| {
     for err in recovered_errors {
       err.to_diagnostics(handler).emit();
@@ -90,6 +97,7 @@ pub fn parse_css_stylesheet(
     } else {
       "failed to handle with unknown panic message".to_string()
     },
+    loc,
   })
 }
 
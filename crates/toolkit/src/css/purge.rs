@@ -0,0 +1,322 @@
+//! Utilities to remove css rules whose selectors can not match the given candidates.
+//!
+//! Candidates are class names, ids and tag names collected from script or html asts by [PurgeCandidatesCollector].
+//! A selector is considered used when all the class, id and tag names of it are present in the candidates.
+use std::collections::HashSet;
+
+use farmfe_core::{
+  config::config_regex::ConfigRegex,
+  swc_css_ast::{
+    ComplexSelector, ComplexSelectorChildren, ComponentValue, CompoundSelector, QualifiedRule,
+    QualifiedRulePrelude, Rule, Stylesheet, SubclassSelector, TypeSelector,
+  },
+  swc_ecma_ast::{JSXElementName, JSXText, PropName, Str, TplElement},
+  swc_html_ast::{Element, Text},
+};
+use swc_ecma_visit::{Visit as EcmaVisit, VisitWith as EcmaVisitWith};
+use swc_html_visit::{Visit as HtmlVisit, VisitWith as HtmlVisitWith};
+
+/// Tags that are always present in a document even if they are not written explicitly
+const ALWAYS_USED_TAGS: [&str; 3] = ["html", "head", "body"];
+/// At rules whose block contains qualified rules that should be purged too
+const CONTAINER_AT_RULES: [&str; 5] = ["media", "supports", "layer", "container", "document"];
+
+/// Collect class, id and tag candidates from script asts(`swc_ecma_visit`) and html asts(`swc_html_visit`)
+#[derive(Default)]
+pub struct PurgeCandidatesCollector {
+  pub candidates: HashSet<String>,
+}
+
+impl PurgeCandidatesCollector {
+  fn add_tokens(&mut self, text: &str) {
+    for token in text.split(|c: char| {
+      c.is_whitespace() || matches!(c, '"' | '\'' | '`' | '<' | '>' | '=' | ',' | ';' | '{' | '}')
+    }) {
+      if !token.is_empty() {
+        self.candidates.insert(token.to_string());
+      }
+    }
+  }
+}
+
+impl EcmaVisit for PurgeCandidatesCollector {
+  fn visit_str(&mut self, n: &Str) {
+    self.add_tokens(&n.value);
+  }
+
+  fn visit_tpl_element(&mut self, n: &TplElement) {
+    self.add_tokens(&n.raw);
+  }
+
+  fn visit_jsx_text(&mut self, n: &JSXText) {
+    self.add_tokens(&n.value);
+  }
+
+  fn visit_jsx_element_name(&mut self, n: &JSXElementName) {
+    if let JSXElementName::Ident(ident) = n {
+      self.candidates.insert(ident.sym.to_string());
+    }
+  }
+
+  // `clsx({ active: isActive })`
+  fn visit_prop_name(&mut self, n: &PropName) {
+    match n {
+      PropName::Ident(ident) => {
+        self.candidates.insert(ident.sym.to_string());
+      }
+      _ => n.visit_children_with(self),
+    }
+  }
+}
+
+impl HtmlVisit for PurgeCandidatesCollector {
+  fn visit_element(&mut self, element: &Element) {
+    self.candidates.insert(element.tag_name.to_string());
+
+    for attr in &element.attributes {
+      if let Some(value) = &attr.value {
+        self.add_tokens(value);
+      }
+    }
+
+    element.visit_children_with(self);
+  }
+
+  fn visit_text(&mut self, text: &Text) {
+    // inline scripts are kept as text children of html
+    self.add_tokens(&text.data);
+  }
+}
+
+pub struct CssPurger<'a> {
+  candidates: &'a HashSet<String>,
+  safelist: &'a Vec<ConfigRegex>,
+}
+
+impl<'a> CssPurger<'a> {
+  pub fn new(candidates: &'a HashSet<String>, safelist: &'a Vec<ConfigRegex>) -> Self {
+    Self {
+      candidates,
+      safelist,
+    }
+  }
+
+  /// Remove unused rules, return the count of removed rules
+  pub fn purge_stylesheet(&self, stylesheet: &mut Stylesheet) -> usize {
+    let mut removed = 0;
+
+    stylesheet.rules.retain_mut(|rule| match rule {
+      Rule::QualifiedRule(rule) => {
+        let used = self.purge_qualified_rule(rule);
+        removed += usize::from(!used);
+        used
+      }
+      Rule::AtRule(at_rule) => {
+        if !CONTAINER_AT_RULES.iter().any(|name| at_rule.name == **name) {
+          return true;
+        }
+
+        if let Some(block) = &mut at_rule.block {
+          let (used, removed_in_block) = self.purge_block_values(&mut block.value);
+          removed += removed_in_block;
+          used
+        } else {
+          true
+        }
+      }
+      Rule::ListOfComponentValues(_) => true,
+    });
+
+    removed
+  }
+
+  /// Purge the qualified rules of a at rule block, return whether the block still contains rules
+  fn purge_block_values(&self, values: &mut Vec<ComponentValue>) -> (bool, usize) {
+    if values.is_empty() {
+      return (true, 0);
+    }
+
+    let mut removed = 0;
+
+    values.retain_mut(|value| match value {
+      ComponentValue::QualifiedRule(rule) => {
+        let used = self.purge_qualified_rule(rule);
+        removed += usize::from(!used);
+        used
+      }
+      ComponentValue::AtRule(at_rule)
+        if CONTAINER_AT_RULES.iter().any(|name| at_rule.name == **name) =>
+      {
+        if let Some(block) = &mut at_rule.block {
+          let (used, removed_in_block) = self.purge_block_values(&mut block.value);
+          removed += removed_in_block;
+          used
+        } else {
+          true
+        }
+      }
+      _ => true,
+    });
+
+    (!values.is_empty(), removed)
+  }
+
+  /// Whether any selector of the rule is used. At rules that do not contain qualified rules, like `@font-face` and `@keyframes`,
+  /// are always treated as used
+  pub fn is_rule_used(&self, rule: &Rule) -> bool {
+    match rule {
+      Rule::QualifiedRule(rule) => self.is_qualified_rule_used(rule),
+      Rule::AtRule(at_rule) if CONTAINER_AT_RULES.iter().any(|name| at_rule.name == **name) => {
+        at_rule
+          .block
+          .as_ref()
+          .map(|block| self.is_block_values_used(&block.value))
+          .unwrap_or(true)
+      }
+      _ => true,
+    }
+  }
+
+  fn is_block_values_used(&self, values: &[ComponentValue]) -> bool {
+    values.is_empty()
+      || values.iter().any(|value| match value {
+        ComponentValue::QualifiedRule(rule) => self.is_qualified_rule_used(rule),
+        ComponentValue::AtRule(at_rule)
+          if CONTAINER_AT_RULES.iter().any(|name| at_rule.name == **name) =>
+        {
+          at_rule
+            .block
+            .as_ref()
+            .map(|block| self.is_block_values_used(&block.value))
+            .unwrap_or(true)
+        }
+        _ => false,
+      })
+  }
+
+  fn is_qualified_rule_used(&self, rule: &QualifiedRule) -> bool {
+    match &rule.prelude {
+      QualifiedRulePrelude::SelectorList(selector_list) => selector_list
+        .children
+        .iter()
+        .any(|selector| self.is_complex_selector_used(selector)),
+      _ => true,
+    }
+  }
+
+  /// Remove unused selectors of the rule, return false if none of the selectors is used
+  fn purge_qualified_rule(&self, rule: &mut QualifiedRule) -> bool {
+    match &mut rule.prelude {
+      QualifiedRulePrelude::SelectorList(selector_list) => {
+        selector_list
+          .children
+          .retain(|selector| self.is_complex_selector_used(selector));
+        !selector_list.children.is_empty()
+      }
+      // relative selectors only appear in nested rules, keep them as is
+      _ => true,
+    }
+  }
+
+  /// Whether all the class, id and tag names of the selector are present in the candidates
+  pub fn is_complex_selector_used(&self, selector: &ComplexSelector) -> bool {
+    selector.children.iter().all(|child| match child {
+      ComplexSelectorChildren::CompoundSelector(compound) => self.is_compound_selector_used(compound),
+      ComplexSelectorChildren::Combinator(_) => true,
+    })
+  }
+
+  fn is_compound_selector_used(&self, compound: &CompoundSelector) -> bool {
+    if let Some(box TypeSelector::TagName(tag)) = &compound.type_selector {
+      let name = tag.name.value.value.to_lowercase();
+
+      if !ALWAYS_USED_TAGS.contains(&name.as_str()) && !self.is_name_used(&name) {
+        return false;
+      }
+    }
+
+    compound.subclass_selectors.iter().all(|sub| match sub {
+      SubclassSelector::Class(class) => self.is_name_used(&class.text.value),
+      SubclassSelector::Id(id) => self.is_name_used(&id.text.value),
+      // attribute selectors and pseudo selectors are not purged
+      _ => true,
+    })
+  }
+
+  fn is_name_used(&self, name: &str) -> bool {
+    self.candidates.contains(name) || self.safelist.iter().any(|r| r.is_match(name))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{collections::HashSet, sync::Arc};
+
+  use farmfe_core::config::config_regex::ConfigRegex;
+
+  use crate::css::{codegen_css_stylesheet, parse_css_stylesheet};
+
+  use super::CssPurger;
+
+  #[test]
+  fn purge_unused_rules() {
+    let css = r#"
+.used { color: red; }
+.unused { color: blue; }
+.used, .unused { margin: 0; }
+div.used > span { padding: 0; }
+p { color: green; }
+body { margin: 0; }
+#app { display: block; }
+.keep-me { color: black; }
+@media (max-width: 100px) {
+  .unused { color: blue; }
+}
+@media (min-width: 100px) {
+  .used:hover { color: blue; }
+  .unused { color: blue; }
+}
+@keyframes unused { from { opacity: 0; } }
+"#;
+    let mut stylesheet = parse_css_stylesheet("test.css", Arc::new(css.to_string()))
+      .unwrap()
+      .ast;
+    let candidates = HashSet::from(["used", "div", "span", "app"].map(String::from));
+    let safelist = vec![ConfigRegex::new("^keep-")];
+    let purger = CssPurger::new(&candidates, &safelist);
+
+    let removed = purger.purge_stylesheet(&mut stylesheet);
+    let (code, _) = codegen_css_stylesheet(&stylesheet, None, true);
+
+    assert_eq!(removed, 4);
+    assert_eq!(
+      code,
+      ".used{color:red}.used{margin:0}div.used>span{padding:0}body{margin:0}#app{display:block}.keep-me{color:black}@media(min-width:100px){.used:hover{color:blue}}@keyframes unused{from{opacity:0}}"
+    );
+  }
+
+  #[test]
+  fn rule_used() {
+    let css = r#"
+.used, .unused { color: red; }
+.unused { color: blue; }
+@media (max-width: 100px) { .unused { color: blue; } }
+@media (max-width: 100px) { .used { color: blue; } }
+@font-face { font-family: foo; }
+"#;
+    let stylesheet = parse_css_stylesheet("test.css", Arc::new(css.to_string()))
+      .unwrap()
+      .ast;
+    let candidates = HashSet::from(["used".to_string()]);
+    let safelist = vec![];
+    let purger = CssPurger::new(&candidates, &safelist);
+
+    let used = stylesheet
+      .rules
+      .iter()
+      .map(|rule| purger.is_rule_used(rule))
+      .collect::<Vec<_>>();
+
+    assert_eq!(used, vec![true, false, false, true, true]);
+  }
+}
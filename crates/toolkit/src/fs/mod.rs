@@ -1,4 +1,12 @@
-use farmfe_core::error::{CompilationError, Result};
+use std::sync::Arc;
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  plugin::PluginOutputFilenameHookParams,
+  regex::Regex,
+};
+use lazy_static::lazy_static;
 
 use crate::hash::sha256;
 
@@ -8,6 +16,18 @@ pub const CONTENT_HASH: &str = "[contentHash]";
 pub const CONTENT_HASH_NEW: &str = "[hash]";
 pub const EXT: &str = "[ext]";
 pub const ENTRY_NAME: &str = "[entryName]";
+pub const DIR: &str = "[dir]";
+pub const PATH: &str = "[path]";
+pub const FORMAT: &str = "[format]";
+pub const TARGET: &str = "[target]";
+
+const DEFAULT_HASH_LENGTH: usize = 8;
+const MAX_HASH_LENGTH: usize = 64;
+
+lazy_static! {
+  /// `[hash]`, `[contentHash]`, `[hash:N]` or `[contentHash:N]`
+  static ref CONTENT_HASH_REGEX: Regex = Regex::new(r"\[(?:contentHash|hash)(?::([^\]]*))?\]").unwrap();
+}
 
 /// read content of the path, return utf8 string.
 pub fn read_file_utf8(path: &str) -> Result<String> {
@@ -20,47 +40,145 @@ pub fn read_file_raw(path: &str) -> Result<Vec<u8>> {
   std::fs::read(path).map_err(|e| CompilationError::GenericError(format!("{e:?}")))
 }
 
+/// Get the filename of the resource from the template. Plugins can override the template via the `output_filename` hook,
+/// e.g. function-style `output.filename` in js config.
 pub fn transform_output_filename(
-  filename_config: String,
-  name: &str,
+  filename_config: &str,
+  params: &PluginOutputFilenameHookParams,
   bytes: &[u8],
-  ext: &str,
-) -> String {
-  let mut res = filename_config;
-
-  if res.contains(RESOURCE_NAME) {
-    res = res.replace(RESOURCE_NAME, name);
-  } else if res.contains(RESOURCE_NAME_NEW) {
-    res = res.replace(RESOURCE_NAME_NEW, name);
+  context: &Arc<CompilationContext>,
+) -> Result<String> {
+  let template = context.plugin_driver.output_filename(params, context)?;
+
+  render_output_filename(
+    template.as_deref().unwrap_or(filename_config),
+    params,
+    bytes,
+  )
+}
+
+/// Replace the placeholders of the filename template, `[hash:N]` means the first N characters of the content hash.
+pub fn render_output_filename(
+  template: &str,
+  params: &PluginOutputFilenameHookParams,
+  bytes: &[u8],
+) -> Result<String> {
+  let mut res = template.to_string();
+
+  if let Some(entry_name) = &params.entry_name {
+    res = res.replace(ENTRY_NAME, entry_name);
   }
 
-  if res.contains(CONTENT_HASH) {
-    let content_hash = sha256(bytes, 8);
-    res = res.replace(CONTENT_HASH, &content_hash);
-  } else if res.contains(CONTENT_HASH_NEW) {
-    let content_hash = sha256(bytes, 8);
-    res = res.replace(CONTENT_HASH_NEW, &content_hash);
+  res = res
+    .replace(RESOURCE_NAME, &params.name)
+    .replace(RESOURCE_NAME_NEW, &params.name)
+    .replace(EXT, &params.ext)
+    .replace(FORMAT, &params.format)
+    .replace(TARGET, &params.target);
+
+  if res.contains(DIR) || res.contains(PATH) {
+    let dir = params.dir.as_deref().unwrap_or_default().trim_matches('/');
+    let path = if dir.is_empty() {
+      String::new()
+    } else {
+      format!("{dir}/")
+    };
+
+    res = res.replace(DIR, dir).replace(PATH, &path);
+    // `[dir]/[name]` of files under root
+    res = res.trim_start_matches('/').replace("//", "/");
   }
 
-  if res.contains(EXT) {
-    res = res.replace(EXT, ext);
+  let mut hash_lengths = vec![];
+
+  for cap in CONTENT_HASH_REGEX.captures_iter(&res) {
+    let len = match cap.get(1) {
+      Some(len) => len
+        .as_str()
+        .parse::<usize>()
+        .ok()
+        .filter(|len| (1..=MAX_HASH_LENGTH).contains(len))
+        .ok_or_else(|| CompilationError::InvalidOutputFilenameTemplate {
+          template: template.to_string(),
+          msg: format!(
+            "hash length of `{}` should be a number between 1 and {MAX_HASH_LENGTH}",
+            &cap[0]
+          ),
+        })?,
+      None => DEFAULT_HASH_LENGTH,
+    };
+
+    hash_lengths.push(len);
   }
 
-  res
+  if let Some(max_len) = hash_lengths.iter().max() {
+    let content_hash = sha256(bytes, *max_len);
+
+    // the placeholders are visited in the same order as above, so the validated lengths are reused
+    let mut lengths = hash_lengths.iter();
+
+    res = CONTENT_HASH_REGEX
+      .replace_all(&res, |_: &farmfe_core::regex::Captures| {
+        content_hash[..*lengths.next().unwrap()].to_string()
+      })
+      .to_string();
+  }
+
+  Ok(res)
 }
 
-pub fn transform_output_entry_filename(
-  entry_filename_config: String,
-  name: &str,
-  entry_filename: &str,
-  bytes: &[u8],
-  ext: &str,
-) -> String {
-  let mut res = entry_filename_config;
+#[cfg(test)]
+mod tests {
+  use farmfe_core::plugin::{OutputFilenameKind, PluginOutputFilenameHookParams};
+
+  use super::render_output_filename;
 
-  if res.contains(ENTRY_NAME) {
-    res = res.replace(ENTRY_NAME, entry_filename);
+  fn params(dir: Option<&str>) -> PluginOutputFilenameHookParams {
+    PluginOutputFilenameHookParams {
+      kind: OutputFilenameKind::Asset,
+      name: "logo".to_string(),
+      entry_name: None,
+      ext: "png".to_string(),
+      dir: dir.map(|d| d.to_string()),
+      format: "esm".to_string(),
+      target: "browser".to_string(),
+    }
   }
 
-  transform_output_filename(res, name, bytes, ext)
+  #[test]
+  fn render_output_filename_placeholders() {
+    let params = params(Some("src/assets"));
+
+    assert_eq!(
+      render_output_filename("[dir]/[name].[hash:4].[ext]", &params, b"a").unwrap(),
+      "src/assets/logo.ca97.png"
+    );
+    assert_eq!(
+      render_output_filename("[path][name]-[contentHash].[ext]", &params, b"a").unwrap(),
+      "src/assets/logo-ca978112.png"
+    );
+    assert_eq!(
+      render_output_filename("[target]/[format]/[resourceName].[ext]", &params, b"a").unwrap(),
+      "browser/esm/logo.png"
+    );
+  }
+
+  #[test]
+  fn render_output_filename_without_dir() {
+    assert_eq!(
+      render_output_filename("[dir]/[name].[ext]", &params(None), b"a").unwrap(),
+      "logo.png"
+    );
+    assert_eq!(
+      render_output_filename("[path][name].[ext]", &params(Some("")), b"a").unwrap(),
+      "logo.png"
+    );
+  }
+
+  #[test]
+  fn render_output_filename_invalid_hash_length() {
+    assert!(render_output_filename("[name].[hash:0].[ext]", &params(None), b"a").is_err());
+    assert!(render_output_filename("[name].[hash:65].[ext]", &params(None), b"a").is_err());
+    assert!(render_output_filename("[name].[hash:abc].[ext]", &params(None), b"a").is_err());
+  }
 }
@@ -78,6 +78,7 @@ pub fn get_dynamic_resources_map(
 pub fn get_dynamic_resources_code(
   dynamic_resources_map: &HashMap<ModuleId, Vec<(String, ResourceType)>>,
   mode: Mode,
+  resources_integrity: &HashMap<String, String>,
 ) -> (String, String) {
   let mut dynamic_resources_code_vec = vec![];
   let mut dynamic_resources = vec![];
@@ -97,12 +98,21 @@ pub fn get_dynamic_resources_code(
         continue;
       }
 
+      let integrity = resources_integrity
+        .get(resource_name)
+        .map(|integrity| format!(", integrity: '{integrity}'"))
+        .unwrap_or_default();
+
       match resource_type {
         ResourceType::Js => {
-          dynamic_resources.push(format!(r#"{{ path: '{resource_name}', type: 0 }}"#));
+          dynamic_resources.push(format!(
+            r#"{{ path: '{resource_name}', type: 0{integrity} }}"#
+          ));
         }
         ResourceType::Css => {
-          dynamic_resources.push(format!(r#"{{ path: '{resource_name}', type: 1 }}"#));
+          dynamic_resources.push(format!(
+            r#"{{ path: '{resource_name}', type: 1{integrity} }}"#
+          ));
         }
         _ => {
           panic!(
@@ -1,3 +1,18 @@
 pub use farmfe_utils::hash::base64_decode;
 pub use farmfe_utils::hash::base64_encode;
 pub use farmfe_utils::hash::sha256;
+pub use farmfe_utils::hash::sha2_base64;
+
+use farmfe_core::config::html::IntegrityAlgorithm;
+use farmfe_utils::hash::{Sha256, Sha384, Sha512};
+
+/// Subresource integrity of the bytes, e.g. `sha384-xxx`
+pub fn integrity(bytes: &[u8], algorithm: IntegrityAlgorithm) -> String {
+  let digest = match algorithm {
+    IntegrityAlgorithm::Sha256 => sha2_base64::<Sha256>(bytes),
+    IntegrityAlgorithm::Sha384 => sha2_base64::<Sha384>(bytes),
+    IntegrityAlgorithm::Sha512 => sha2_base64::<Sha512>(bytes),
+  };
+
+  format!("{}-{}", algorithm.prefix(), digest)
+}
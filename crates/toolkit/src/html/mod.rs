@@ -16,7 +16,7 @@ use swc_html_parser::{
   parser::{Parser, ParserConfig},
 };
 
-use crate::common::{create_swc_source_map, Source};
+use crate::common::{create_error_location, create_swc_source_map, Source};
 
 /// Parse html content to swc_html_ast's [Document], present the ast of HTML.
 pub fn parse_html_document(id: &str, content: Arc<String>) -> farmfe_core::error::Result<Document> {
@@ -47,6 +47,11 @@ pub fn parse_html_document(id: &str, content: Arc<String>) -> farmfe_core::error
     }
   }
 
+  let loc = recovered_errors.first().and_then(|err| {
+    let (span, kind) = *err.clone().into_inner();
+    create_error_location(&cm, span, &kind)
+  });
+
   try_with_handler(cm, Default::default(), |handler| {
     for err in recovered_errors {
       err.to_diagnostics(handler).emit();
@@ -63,6 +68,7 @@ pub fn parse_html_document(id: &str, content: Arc<String>) -> farmfe_core::error
     } else {
       "failed to handle with unknown panic message".to_string()
     },
+    loc,
   })
 }
 
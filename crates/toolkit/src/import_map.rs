@@ -0,0 +1,120 @@
+use std::collections::{BTreeMap, HashMap};
+
+use farmfe_core::{
+  module::ModuleId,
+  resource::{resource_pot_map::ResourcePotMap, Resource, ResourceOrigin, ResourceType},
+  serde_json,
+};
+
+/// Maps the emitted (hashed) js and css resources to stable logical names, so the runtime can load chunks
+/// by logical names and the code that references a chunk does not change when the content hash of the chunk changes.
+///
+/// The logical name of a resource is `<resource pot name>.<ext>`, which only depends on the modules of the resource pot.
+#[derive(Debug, Default)]
+pub struct ImportMap {
+  /// resource name -> logical name
+  logical_names: HashMap<String, String>,
+  /// logical name -> url of the resource, sorted to keep the generated import map stable
+  imports: BTreeMap<String, String>,
+}
+
+impl ImportMap {
+  /// `url_prefix` is prepended to the resource names, for example the public path in browser or `./` in node.
+  pub fn new<'a>(
+    resource_names: impl IntoIterator<Item = &'a String>,
+    resources_map: &HashMap<String, Resource>,
+    resource_pot_map: &ResourcePotMap,
+    url_prefix: &str,
+  ) -> Self {
+    let mut import_map = Self::default();
+
+    for name in resource_names {
+      let Some(resource) = resources_map.get(name) else {
+        continue;
+      };
+
+      if let Some(logical_name) = logical_resource_name(resource, resource_pot_map) {
+        import_map.imports.insert(
+          logical_name.clone(),
+          format!("{url_prefix}{}", resource.name),
+        );
+        import_map
+          .logical_names
+          .insert(resource.name.clone(), logical_name);
+      }
+    }
+
+    import_map
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.imports.is_empty()
+  }
+
+  /// the logical name of the resource, or the resource name itself if the resource is not in the import map
+  pub fn logical_name(&self, resource_name: &str) -> String {
+    self
+      .logical_names
+      .get(resource_name)
+      .cloned()
+      .unwrap_or_else(|| resource_name.to_string())
+  }
+
+  /// replace the resource names of the dynamic resources map with the logical names
+  pub fn apply_to_dynamic_resources_map(
+    &self,
+    dynamic_resources_map: HashMap<ModuleId, Vec<(String, ResourceType)>>,
+  ) -> HashMap<ModuleId, Vec<(String, ResourceType)>> {
+    dynamic_resources_map
+      .into_iter()
+      .map(|(module_id, resources)| {
+        (
+          module_id,
+          resources
+            .into_iter()
+            .map(|(name, ty)| (self.logical_name(&name), ty))
+            .collect(),
+        )
+      })
+      .collect()
+  }
+
+  /// re-key the values that are keyed by resource names, like the integrity hashes, by the logical names
+  pub fn apply_to_keys<V: Clone>(&self, map: &HashMap<String, V>) -> HashMap<String, V> {
+    map
+      .iter()
+      .map(|(name, v)| (self.logical_name(name), v.clone()))
+      .collect()
+  }
+
+  /// `{"logical.js":"/logical.3f2a.js"}`
+  pub fn imports_code(&self) -> String {
+    serde_json::to_string(&self.imports).unwrap()
+  }
+
+  /// content of `<script type="importmap">`
+  pub fn to_json(&self) -> String {
+    format!(r#"{{"imports":{}}}"#, self.imports_code())
+  }
+}
+
+/// Only js and css resources generated from resource pots are loaded by the runtime
+pub fn logical_resource_name(
+  resource: &Resource,
+  resource_pot_map: &ResourcePotMap,
+) -> Option<String> {
+  if !matches!(resource.resource_type, ResourceType::Js | ResourceType::Css) {
+    return None;
+  }
+
+  let ResourceOrigin::ResourcePot(resource_pot_id) = &resource.origin else {
+    return None;
+  };
+  let resource_pot = resource_pot_map.resource_pot(resource_pot_id)?;
+
+  Some(format!(
+    "{}.{}",
+    resource_pot.name,
+    resource.resource_type.to_ext()
+  ))
+}
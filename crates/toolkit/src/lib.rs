@@ -39,6 +39,7 @@ pub use lazy_static;
 pub use sourcemap;
 
 pub mod get_dynamic_resources_map;
+pub mod import_map;
 pub mod minify;
 
 // pluginutils
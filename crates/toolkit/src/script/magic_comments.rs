@@ -0,0 +1,164 @@
+//! Magic comments of dynamic imports, for example:
+//! ```js
+//! import(/* webpackChunkName: "admin", webpackPrefetch: true */ './admin');
+//! ```
+//! Both the webpack style keys and the farm native keys (e.g. `farmChunkName`) are supported.
+use std::collections::HashMap;
+
+use farmfe_core::{
+  module::CommentsMetaData,
+  swc_common::{comments::Comment, BytePos, Spanned},
+  swc_ecma_ast::{CallExpr, Expr, Lit, Module as SwcModule},
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use super::is_dynamic_import;
+
+/// the dynamic imported modules are placed into a resource pot named by the value
+pub const CHUNK_NAME_MAGIC_COMMENTS: [&str; 2] = ["farmChunkName", "webpackChunkName"];
+/// `true` or `false`, overrides `html.resourceHints.prefetch` for the dynamic import
+pub const PREFETCH_MAGIC_COMMENTS: [&str; 2] = ["farmPrefetch", "webpackPrefetch"];
+/// `true` to preload the resources of the dynamic import
+pub const PRELOAD_MAGIC_COMMENTS: [&str; 2] = ["farmPreload", "webpackPreload"];
+
+/// key value pairs of the magic comments of a dynamic import, quotes of the value are removed
+pub type MagicComments = HashMap<String, String>;
+
+/// Value of the first key that presents in the magic comments, the farm native key takes precedence
+pub fn magic_comment_value<'a>(comments: &'a MagicComments, keys: &[&str]) -> Option<&'a str> {
+  keys
+    .iter()
+    .find_map(|key| comments.get(*key))
+    .map(|v| v.as_str())
+}
+
+/// `Some(true)` or `Some(false)` if the value of the first present key is a boolean
+pub fn magic_comment_bool(comments: &MagicComments, keys: &[&str]) -> Option<bool> {
+  magic_comment_value(comments, keys).and_then(|v| v.parse().ok())
+}
+
+/// Collect magic comments of all dynamic imports in the module, keyed by the import source.
+/// Only the comments in front of the string literal argument are collected.
+pub fn collect_dynamic_import_magic_comments(
+  ast: &SwcModule,
+  comments: &CommentsMetaData,
+) -> HashMap<String, MagicComments> {
+  let leading_comments = comments
+    .leading
+    .iter()
+    .map(|item| (item.byte_pos, &item.comment))
+    .collect::<HashMap<_, _>>();
+
+  let mut collector = MagicCommentsCollector {
+    leading_comments,
+    result: HashMap::new(),
+  };
+  ast.visit_with(&mut collector);
+
+  collector.result
+}
+
+/// Parse `key: value, key2: value2` pairs from the comment text, invalid pairs are ignored
+pub fn parse_magic_comment(text: &str) -> MagicComments {
+  let mut pairs = vec![];
+  let mut current = String::new();
+  let mut quote = None;
+
+  for c in text.chars() {
+    match c {
+      '\'' | '"' | '`' if quote == Some(c) => quote = None,
+      '\'' | '"' | '`' if quote.is_none() => quote = Some(c),
+      ',' if quote.is_none() => {
+        pairs.push(std::mem::take(&mut current));
+        continue;
+      }
+      _ => {}
+    }
+
+    current.push(c);
+  }
+  pairs.push(current);
+
+  pairs
+    .into_iter()
+    .filter_map(|pair| {
+      let (key, value) = pair.split_once(':')?;
+      let key = key.trim();
+      let value = value.trim().trim_matches(|c| matches!(c, '\'' | '"' | '`'));
+
+      if key.is_empty() {
+        return None;
+      }
+
+      Some((key.to_string(), value.to_string()))
+    })
+    .collect()
+}
+
+struct MagicCommentsCollector<'a> {
+  leading_comments: HashMap<BytePos, &'a Vec<Comment>>,
+  result: HashMap<String, MagicComments>,
+}
+
+impl<'a> Visit for MagicCommentsCollector<'a> {
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if is_dynamic_import(call_expr) && !call_expr.args.is_empty() {
+      if let box Expr::Lit(Lit::Str(str)) = &call_expr.args[0].expr {
+        if let Some(comments) = self.leading_comments.get(&str.span_lo()) {
+          let magic_comments = comments
+            .iter()
+            .flat_map(|comment| parse_magic_comment(&comment.text))
+            .collect::<MagicComments>();
+
+          if !magic_comments.is_empty() {
+            self
+              .result
+              .entry(str.value.to_string())
+              .or_default()
+              .extend(magic_comments);
+          }
+        }
+      }
+    }
+
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{
+    magic_comment_bool, magic_comment_value, parse_magic_comment, PREFETCH_MAGIC_COMMENTS,
+  };
+
+  #[test]
+  fn parse_magic_comment_pairs() {
+    let comments = parse_magic_comment(r#" farmPrefetch: false, webpackChunkName: "a, b" "#);
+
+    assert_eq!(comments.len(), 2);
+    assert_eq!(comments.get("farmPrefetch").unwrap(), "false");
+    assert_eq!(comments.get("webpackChunkName").unwrap(), "a, b");
+    assert!(parse_magic_comment("not a magic comment").is_empty());
+  }
+
+  #[test]
+  fn farm_keys_take_precedence() {
+    let comments = parse_magic_comment("webpackPrefetch: true, farmPrefetch: false");
+
+    assert_eq!(
+      magic_comment_value(&comments, &PREFETCH_MAGIC_COMMENTS),
+      Some("false")
+    );
+    assert_eq!(
+      magic_comment_bool(&comments, &PREFETCH_MAGIC_COMMENTS),
+      Some(false)
+    );
+    assert_eq!(
+      magic_comment_bool(
+        &parse_magic_comment("webpackPrefetch: 1"),
+        &PREFETCH_MAGIC_COMMENTS
+      ),
+      None
+    );
+  }
+}
@@ -14,7 +14,7 @@ use farmfe_core::{
   plugin::{PluginFinalizeModuleHookParam, ResolveKind},
   swc_common::{
     comments::{Comments, SingleThreadedComments},
-    BytePos, FileName, LineCol, Mark, SourceMap,
+    BytePos, FileName, LineCol, Mark, SourceMap, Spanned,
   },
   swc_ecma_ast::{
     CallExpr, Callee, EsVersion, Expr, Ident, Import, MemberProp, Module as SwcModule, ModuleItem,
@@ -24,13 +24,14 @@ use farmfe_core::{
 use swc_ecma_visit::{Visit, VisitWith};
 use swc_error_reporters::handler::try_with_handler;
 
-use crate::common::{create_swc_source_map, minify_comments, Source};
+use crate::common::{create_error_location, create_swc_source_map, minify_comments, Source};
 
 pub use farmfe_toolkit_plugin_types::swc_ast::ParseScriptModuleResult;
 
 use self::swc_try_with::try_with;
 
 pub mod defined_idents_collector;
+pub mod magic_comments;
 pub mod swc_try_with;
 pub mod constant;
 
@@ -63,6 +64,10 @@ pub fn parse_module(
     }
   }
 
+  let loc = recovered_errors
+    .first()
+    .and_then(|err| create_error_location(&cm, err.span(), err.kind()));
+
   try_with_handler(cm, Default::default(), |handler| {
     for err in recovered_errors {
       err.into_diagnostic(handler).emit();
@@ -79,6 +84,7 @@ pub fn parse_module(
     } else {
       "failed to handle with unknown panic message".to_string()
     },
+    loc,
   })
 }
 
@@ -99,6 +105,7 @@ pub fn parse_stmt(
     .map_err(|e| CompilationError::ParseError {
       resolved_path: id.to_string(),
       msg: format!("{e:?}"),
+      loc: create_error_location(&cm, e.span(), e.kind()),
     })
 }
 
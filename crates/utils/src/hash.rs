@@ -1,5 +1,6 @@
 use base64::{engine::general_purpose, Engine};
-use sha2::{Digest, Sha256};
+use sha2::Digest;
+pub use sha2::{Sha256, Sha384, Sha512};
 
 pub fn sha256(bytes: &[u8], len: usize) -> String {
   let mut hasher = Sha256::new();
@@ -11,6 +12,11 @@ pub fn sha256(bytes: &[u8], len: usize) -> String {
   format!("{hash:x}")[..len].to_string()
 }
 
+/// base64 encoded sha2 digest of the bytes, `D` is one of [Sha256], [Sha384] and [Sha512]
+pub fn sha2_base64<D: Digest>(bytes: &[u8]) -> String {
+  base64_encode(&D::digest(bytes))
+}
+
 pub fn base64_encode(bytes: &[u8]) -> String {
   general_purpose::STANDARD.encode(bytes)
 }
@@ -26,6 +32,14 @@ mod tests {
     assert_eq!(super::sha256(b"hello world", 8), "b94d27b9".to_string());
   }
 
+  #[test]
+  fn test_sha2_base64() {
+    assert_eq!(
+      super::sha2_base64::<super::Sha256>(b"hello world"),
+      "uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek="
+    );
+  }
+
   #[test]
   fn test_base64_encode() {
     assert_eq!(super::base64_encode(b"hello world"), "aGVsbG8gd29ybGQ=");
@@ -44,6 +44,12 @@ export interface JsTracedModuleGraph {
   edges: Record<string, Array<string>>
   reverseEdges: Record<string, Array<string>>
 }
+export interface JsErroredModule {
+  id: string
+  message: string
+  /** json of [farmfe_core::error::ErrorLocation] */
+  loc?: any
+}
 export interface JsUpdateResult {
   added: Array<string>
   changed: Array<string>
@@ -53,6 +59,8 @@ export interface JsUpdateResult {
   boundaries: Record<string, Array<Array<string>>>
   dynamicResourcesMap?: Record<string, Array<Array<string>>>
   extraWatchResult: WatchDiffResult
+  /** modules that failed to build and are kept as error modules when `compilation.errorTolerant` is enabled */
+  errors: Array<JsErroredModule>
 }
 export type JsCompiler = Compiler
 export declare class Compiler {
@@ -71,6 +79,7 @@ export declare class Compiler {
   resources(): Record<string, Buffer>
   resourcesMap(): Record<string, unknown>
   watchModules(): Array<string>
+  erroredModules(): Array<JsErroredModule>
   relativeModulePaths(): Array<string>
   resource(name: string): Buffer | null
   stats(): string
@@ -66,6 +66,7 @@ export class Compiler {
       await this._bindingCompiler.compile();
     }
     this.compiling = false;
+    this.logErroredModules();
   }
 
   compileSync() {
@@ -77,6 +78,17 @@ export class Compiler {
     this.compiling = true;
     this._bindingCompiler.compileSync();
     this.compiling = false;
+    this.logErroredModules();
+  }
+
+  /**
+   * Modules that failed to build are kept as error modules when `compilation.errorTolerant` is enabled,
+   * the compilation succeeds but the errors should still be reported
+   */
+  private logErroredModules() {
+    for (const { id, message } of this._bindingCompiler.erroredModules()) {
+      this.logger.error(`Failed to build ${id}:\n${message}`);
+    }
   }
 
   async update(
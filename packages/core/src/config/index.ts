@@ -42,8 +42,10 @@ import {
   setProcessEnv
 } from './env.js';
 import {
+  createOutputFilenamePlugin,
   getValidPublicPath,
-  normalizeOutput
+  normalizeOutput,
+  normalizeOutputVariants
 } from './normalize-config/normalize-output.js';
 import { normalizePersistentCache } from './normalize-config/normalize-persistent-cache.js';
 import { parseUserConfig } from './schema.js';
@@ -148,6 +150,7 @@ export async function resolveConfig(
   // Clear the console according to the cli command
 
   checkClearScreen(inlineOptions);
+  normalizeOutputVariants(inlineOptions);
   logger = logger ?? new Logger();
   inlineOptions.mode = inlineOptions.mode ?? mode;
   // configPath may be file or directory
@@ -225,7 +228,12 @@ export async function resolveConfig(
   resolvedUserConfig.root = normalizeBasePath(
     resolvedUserConfig.compilation.root
   );
-  resolvedUserConfig.jsPlugins = sortFarmJsPlugins;
+  const outputFilenamePlugin = createOutputFilenamePlugin(
+    resolvedUserConfig.compilation
+  );
+  resolvedUserConfig.jsPlugins = outputFilenamePlugin
+    ? [...sortFarmJsPlugins, outputFilenamePlugin]
+    : sortFarmJsPlugins;
   resolvedUserConfig.rustPlugins = rustPlugins;
 
   // Temporarily dealing with alias objects and arrays in js will be unified in rust in the future.]
@@ -916,7 +924,7 @@ export async function loadConfigFile(
       );
 
       return {
-        config: config && parseUserConfig(config),
+        config: config && normalizeOutputVariants(parseUserConfig(config)),
         configFilePath: configFilePath
       };
     }
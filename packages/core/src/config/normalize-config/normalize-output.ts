@@ -1,7 +1,13 @@
 import { browsersWithSupportForFeatures } from 'farm-browserslist-generator';
 
 import path, { isAbsolute } from 'node:path';
-import { Config } from '../../types/binding.js';
+import type { JsPlugin } from '../../plugin/type.js';
+import {
+  Config,
+  OutputConfig,
+  OutputFilename,
+  OutputFilenameInfo
+} from '../../types/binding.js';
 import { urlRegex } from '../../utils/http.js';
 import { Logger } from '../../utils/logger.js';
 import {
@@ -9,7 +15,53 @@ import {
   mapTargetEnvValue,
   normalizeBasePath
 } from '../../utils/share.js';
-import { ResolvedCompilation } from '../types.js';
+import { ResolvedCompilation, UserConfig } from '../types.js';
+
+type OutputFilenameFunction = Exclude<OutputFilename, string>;
+
+/**
+ * `compilation.output` can be a list of outputs that share one build, the first one is the primary output
+ * and the others are passed to the compiler as its `variants`
+ */
+export function normalizeOutputVariants<T extends UserConfig>(config: T): T {
+  const output: OutputConfig | OutputConfig[] | undefined =
+    config.compilation?.output;
+
+  if (!Array.isArray(output)) {
+    return config;
+  }
+
+  const [primary, ...rest] = output;
+  const variants = rest.map((variant) => {
+    const { entryFilename, filename, assetsFilename, targetEnv, format } =
+      variant;
+
+    if (
+      [entryFilename, filename, assetsFilename].some(
+        (name) => typeof name === 'function'
+      )
+    ) {
+      throw new Error(
+        'Function filenames are only supported by the first item of compilation.output, use filename templates with `[format]` or `[target]` for the others.'
+      );
+    }
+
+    return {
+      entryFilename,
+      filename,
+      assetsFilename,
+      targetEnv,
+      format
+    } as OutputConfig['variants'][number];
+  });
+
+  config.compilation.output = {
+    ...primary,
+    variants: [...(primary.variants ?? []), ...variants]
+  };
+
+  return config;
+}
 
 export function normalizeOutput(
   config: ResolvedCompilation,
@@ -271,3 +323,44 @@ export function getValidPublicPath(publicPath = '/'): string {
 
   return validPublicPath;
 }
+
+/**
+ * Function-style `output.filename`, `output.entryFilename` and `output.assetsFilename` are not serializable,
+ * they are called by the rust compiler through the `outputFilename` hook of an internal plugin.
+ */
+export function createOutputFilenamePlugin(
+  config: ResolvedCompilation
+): JsPlugin | undefined {
+  const output = config.output ?? {};
+  const functions: [OutputFilenameInfo['kind'], OutputFilenameFunction][] = [];
+
+  for (const [key, kind] of [
+    ['entryFilename', 'entry'],
+    ['filename', 'chunk'],
+    ['assetsFilename', 'asset']
+  ] as const) {
+    const filename = output[key];
+
+    if (typeof filename === 'function') {
+      functions.push([kind, filename]);
+      // the template is always returned by the hook, this is only a placeholder for the rust config
+      output[key] = '[resourceName].[ext]';
+    }
+  }
+
+  if (!functions.length) {
+    return;
+  }
+
+  const filenameOfKind = new Map(functions);
+
+  return {
+    name: 'farm:output-filename',
+    outputFilename: {
+      filters: { kinds: [...filenameOfKind.keys()] },
+      executor(info) {
+        return filenameOfKind.get(info.kind)?.(info);
+      }
+    }
+  };
+}
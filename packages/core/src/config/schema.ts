@@ -23,37 +23,84 @@ const pathFilterSchema = z.union([
 
 const pathRewriteSchema = z.union([stringRewriteSchema, functionRewriteSchema]);
 
+const outputFilenameSchema = z.union([
+  z.string(),
+  z.function().args(z.any()).returns(z.string())
+]);
+
+const outputVariantSchema = z
+  .object({
+    entryFilename: z.string().optional(),
+    filename: z.string().optional(),
+    assetsFilename: z.string().optional(),
+    targetEnv: z
+      .enum(['library', 'library-browser', 'library-node'])
+      .optional(),
+    format: z.enum(['cjs', 'esm', 'iife', 'umd']).optional()
+  })
+  .strict();
+
+const outputSchema = z
+  .object({
+    entryFilename: outputFilenameSchema.optional(),
+    filename: outputFilenameSchema.optional(),
+    path: z.string().optional(),
+    publicPath: z.string().optional(),
+    assetsFilename: outputFilenameSchema.optional(),
+    targetEnv: z
+      .enum([
+        'browser',
+        'node',
+        'node-legacy',
+        'node-next',
+        'node16',
+        'browser-legacy',
+        'browser-esnext',
+        'browser-es2015',
+        'browser-es2017',
+        'library',
+        'library-browser',
+        'library-node'
+      ])
+      .optional(),
+    format: z.enum(['cjs', 'esm', 'iife', 'umd']).optional(),
+    globalName: z
+      .string()
+      .regex(/^[A-Za-z_$][\w$]*$/, 'must be a valid identifier')
+      .optional(),
+    clean: z.boolean().optional(),
+    manifest: z
+      .union([
+        z.boolean(),
+        z.object({ filename: z.string().optional() }).strict()
+      ])
+      .optional(),
+    importMap: z
+      .union([
+        z.boolean(),
+        z.object({ filename: z.string().optional() }).strict()
+      ])
+      .optional(),
+    preserveModules: z
+      .union([
+        z.boolean(),
+        z.object({ root: z.string().optional() }).strict()
+      ])
+      .optional(),
+    variants: z.array(outputVariantSchema).optional()
+  })
+  .strict();
+
 const compilationConfigSchema = z
   .object({
     root: z.string().optional(),
     input: z.record(z.string()).optional(),
     output: z
-      .object({
-        entryFilename: z.string().optional(),
-        filename: z.string().optional(),
-        path: z.string().optional(),
-        publicPath: z.string().optional(),
-        assetsFilename: z.string().optional(),
-        targetEnv: z
-          .enum([
-            'browser',
-            'node',
-            'node-legacy',
-            'node-next',
-            'node16',
-            'browser-legacy',
-            'browser-esnext',
-            'browser-es2015',
-            'browser-es2017',
-            'library',
-            'library-browser',
-            'library-node'
-          ])
-          .optional(),
-        format: z.enum(['cjs', 'esm']).optional(),
-        clean: z.boolean().optional()
-      })
-      .strict()
+      .union([
+        outputSchema,
+        // the first output is the primary one, the others are its variants
+        z.array(outputSchema.omit({ variants: true })).nonempty()
+      ])
       .optional(),
     resolve: z
       .object({
@@ -104,7 +151,10 @@ const compilationConfigSchema = z
       .object({
         include: z.array(z.string()).optional(),
         publicDir: z.string().optional(),
-        mode: z.enum(['browser', 'node']).optional()
+        mode: z.enum(['browser', 'node']).optional(),
+        inlineLimit: z.number().nonnegative().optional(),
+        inlineInclude: z.array(z.string()).optional(),
+        inlineExclude: z.array(z.string()).optional()
       })
       .strict()
       .optional(),
@@ -184,6 +234,7 @@ const compilationConfigSchema = z
         targetConcurrentRequests: z.number().positive().int().optional(),
         targetMinSize: z.number().nonnegative().int().optional(),
         targetMaxSize: z.number().nonnegative().int().optional(),
+        sizeMetric: z.enum(['source', 'estimatedGzip']).optional(),
         groups: z
           .array(
             z.object({
@@ -207,11 +258,19 @@ const compilationConfigSchema = z
         enforceTargetConcurrentRequests: z.boolean().optional(),
         enforceTargetMinSize: z.boolean().optional(),
         immutableModules: z.array(z.string()).optional(),
-        immutableModulesWeight: z.number().optional()
+        immutableModulesWeight: z.number().optional(),
+        report: z
+          .union([
+            z.boolean(),
+            z.object({ filename: z.string().optional() }).strict()
+          ])
+          .optional()
       })
       .strict()
       .optional(),
     lazyCompilation: z.boolean().optional(),
+    errorTolerant: z.boolean().optional(),
+    continueOnError: z.boolean().optional(),
     treeShaking: z.boolean().optional(),
     minify: z
       .union([
@@ -268,10 +327,37 @@ const compilationConfigSchema = z
                 .optional()
             })
           ])
+          .optional(),
+        purge: z
+          .union([
+            z.null(),
+            z.object({
+              safelist: z.array(z.string()).optional(),
+              include: z.array(z.string()).optional(),
+              exclude: z.array(z.string()).optional()
+            })
+          ])
           .optional()
       })
       .optional(),
-    html: z.object({ base: z.string().optional() }).optional(),
+    html: z
+      .object({
+        base: z.string().optional(),
+        criticalCss: z
+          .object({ threshold: z.number().nonnegative().optional() })
+          .optional(),
+        resourceHints: z
+          .object({
+            preload: z.array(z.enum(['script', 'css', 'font'])).optional(),
+            modulePreload: z.boolean().optional(),
+            prefetch: z.boolean().optional()
+          })
+          .optional(),
+        integrity: z.enum(['sha256', 'sha384', 'sha512']).optional(),
+        cspNonce: z.string().optional(),
+        cspHashes: z.enum(['sha256', 'sha384', 'sha512']).optional()
+      })
+      .optional(),
     persistentCache: z.union([
       z.boolean(),
       z
@@ -299,6 +385,19 @@ const compilationConfigSchema = z
         .optional()
     ]),
     comments: z.union([z.boolean(), z.literal('license')]).optional(),
+    compression: z
+      .union([
+        z.boolean(),
+        z
+          .object({
+            algorithms: z.array(z.enum(['gzip', 'brotli'])).optional(),
+            threshold: z.number().nonnegative().optional(),
+            gzipLevel: z.number().min(0).max(9).optional(),
+            brotliLevel: z.number().min(0).max(11).optional()
+          })
+          .strict()
+      ])
+      .optional(),
     custom: z.record(z.string(), z.string()).optional()
   })
   .strict();
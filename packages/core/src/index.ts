@@ -70,6 +70,9 @@ export async function start(
       logger
     );
 
+    // the errors of the error modules are shown in the overlay of the dev server, so it's only enabled by default here
+    resolvedUserConfig.compilation.errorTolerant ??= true;
+
     if (
       resolvedUserConfig.compilation.lazyCompilation &&
       typeof resolvedUserConfig.server?.host === 'string'
@@ -1,5 +1,6 @@
 import type { UserConfig as ViteUserConfig } from 'vite';
 import type { UserConfig } from '../../config/types.js';
+import type { OutputFilename } from '../../types/binding.js';
 import { Logger } from '../../index.js';
 import merge from '../../utils/merge.js';
 import { EXTERNAL_KEYS, VITE_DEFAULT_ASSETS } from './constants.js';
@@ -75,9 +76,13 @@ export function farmUserConfigToViteConfig(config: UserConfig): ViteUserConfig {
       ssr: config.compilation?.output?.targetEnv === 'node',
       rollupOptions: {
         output: {
-          assetFileNames: config.compilation?.output?.assetsFilename,
-          entryFileNames: config.compilation?.output?.entryFilename,
-          chunkFileNames: config.compilation?.output?.filename
+          assetFileNames: filenameTemplate(
+            config.compilation?.output?.assetsFilename
+          ),
+          entryFileNames: filenameTemplate(
+            config.compilation?.output?.entryFilename
+          ),
+          chunkFileNames: filenameTemplate(config.compilation?.output?.filename)
         }
       }
       // other options are not supported in farm
@@ -442,3 +447,8 @@ export function viteConfigToFarmConfig(
 
   return merge({}, origFarmConfig, farmConfig);
 }
+
+/** function-style farm filenames receive different arguments from rollup's, so they are not passed to vite */
+function filenameTemplate(filename: OutputFilename | undefined) {
+  return typeof filename === 'string' ? filename : undefined;
+}
@@ -113,6 +113,42 @@ export function convertPlugin(plugin: JsPlugin): void {
     }
   }
 
+  for (const hookName of ['processModule', 'analyzeDeps'] as const) {
+    const hook = plugin[hookName];
+
+    if (!hook) {
+      continue;
+    }
+
+    if (!hook.filters?.moduleTypes && !hook.filters?.resolvedPaths) {
+      throw new Error(
+        `${hookName} hook of plugin ${plugin.name} must have at least one filter(like moduleTypes or resolvedPaths)`
+      );
+    }
+
+    hook.filters.moduleTypes ??= [];
+    hook.filters.resolvedPaths =
+      hook.filters.resolvedPaths?.map(normalizeFilterPath) ?? [];
+  }
+
+  if (plugin.generateResources) {
+    plugin.generateResources.filters ??= {};
+
+    if (
+      !plugin.generateResources.filters.moduleIds &&
+      !plugin.generateResources.filters.resourcePotTypes
+    ) {
+      throw new Error(
+        `generateResources hook of plugin ${plugin.name} must have at least one filter(like moduleIds or resourcePotTypes)`
+      );
+    }
+
+    plugin.generateResources.filters.resourcePotTypes ??= [];
+    plugin.generateResources.filters.moduleIds =
+      plugin.generateResources.filters.moduleIds?.map(normalizeFilterPath) ??
+      [];
+  }
+
   if (plugin.renderResourcePot) {
     plugin.renderResourcePot.filters ??= {};
 
@@ -151,6 +187,11 @@ export function convertPlugin(plugin: JsPlugin): void {
     }
   }
 
+  if (plugin.outputFilename) {
+    plugin.outputFilename.filters ??= {};
+    plugin.outputFilename.filters.kinds ??= ['entry', 'chunk', 'asset'];
+  }
+
   if (plugin.resolve?.filters?.importers?.length) {
     plugin.resolve.filters.importers =
       plugin.resolve.filters.importers.map(normalizeFilterPath);
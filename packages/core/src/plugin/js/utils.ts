@@ -358,6 +358,8 @@ function transformFarmFormatToRollupFormat(
   } else if (config.format === 'cjs') {
     if (config.targetEnv === 'node') return 'cjs';
     return 'amd';
+  } else if (config.format === 'iife' || config.format === 'umd') {
+    return config.format;
   }
 }
 
@@ -366,12 +368,13 @@ export function transformFarmConfigToRollupNormalizedOutputOptions(
 ): NormalizedOutputOptions {
   return {
     amd: { autoId: false, define: 'define', forceJsExtensionForImports: false },
-    assetFileNames: config.output.assetsFilename,
-    chunkFileNames: config.output.filename,
+    // function-style filenames are replaced by templates before they are passed to the compiler
+    assetFileNames: config.output.assetsFilename as string,
+    chunkFileNames: config.output.filename as string,
     compact: Boolean(config.minify),
     dir: config.output.path,
     dynamicImportInCjs: true,
-    entryFileNames: config.output.entryFilename,
+    entryFileNames: config.output.entryFilename as string,
     esModule: 'if-default-prop',
     experimentalMinChunkSize: config?.partialBundling?.targetMinSize || 1,
     exports: 'auto',
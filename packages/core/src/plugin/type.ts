@@ -1,12 +1,15 @@
 import { Compiler, ResolvedUserConfig, Server, UserConfig } from '../index.js';
 import {
   Config,
+  ModuleType,
+  OutputFilenameInfo,
   PluginLoadHookParam,
   PluginLoadHookResult,
   PluginResolveHookParam,
   PluginResolveHookResult,
   PluginTransformHookParam,
-  PluginTransformHookResult
+  PluginTransformHookResult,
+  ResolveKind
 } from '../types/binding.js';
 
 // https://stackoverflow.com/questions/61047551/typescript-union-of-string-and-string-literals
@@ -112,10 +115,102 @@ export interface Resource {
   bytes: number[];
   emitted: boolean;
   resourceType: string;
-  origin: { type: 'ResourcePot' | 'Module'; value: string };
+  origin: { type: 'ResourcePot' | 'Module' | 'Resource'; value: string };
   info?: ResourcePotInfo;
 }
 
+export interface PluginProcessModuleHookParams {
+  moduleId: ModuleId;
+  moduleType: ModuleType;
+  content: string;
+}
+
+export interface PluginProcessModuleHookResult {
+  /** the module is parsed again from the returned content, only script and css modules are supported */
+  content: string;
+}
+
+export interface PluginAnalyzeDepsHookResultEntry {
+  source: string;
+  kind: ResolveKind;
+}
+
+export interface PluginAnalyzeDepsHookParams {
+  moduleId: ModuleId;
+  moduleType: ModuleType;
+  /** deps analyzed by previous plugins */
+  deps: PluginAnalyzeDepsHookResultEntry[];
+}
+
+/** A snapshot of the module graph, modules are sorted by id */
+export interface ModuleGraphSnapshot {
+  entries: ModuleId[];
+  modules: {
+    id: ModuleId;
+    moduleType: ModuleType;
+    immutable: boolean;
+    external: boolean;
+    size: number;
+    /** `dynamic` is true if the dependency is only imported dynamically */
+    dependencies: { id: ModuleId; dynamic: boolean }[];
+  }[];
+}
+
+export interface ModuleGraphPatchEdge {
+  from: ModuleId;
+  to: ModuleId;
+  /** the source of the added edge, defaults to `to` */
+  source?: string;
+  /** defaults to `import` */
+  kind?: ResolveKind;
+}
+
+/** applied in the order: removeEdges, removeModules, addEdges */
+export interface ModuleGraphPatch {
+  removeEdges?: ModuleGraphPatchEdge[];
+  /** the edges of the removed modules are removed too */
+  removeModules?: ModuleId[];
+  addEdges?: ModuleGraphPatchEdge[];
+}
+
+export interface ModuleGroup {
+  /** the entry module of the group, it's always a member of the group */
+  id: ModuleId;
+  modules: ModuleId[];
+  /** the module groups that are loaded dynamically by this group */
+  dependencies?: ModuleId[];
+}
+
+export interface PluginPartialBundlingHookParams {
+  /** modules matched by `partialBundling.enforceResources` are not included */
+  modules: {
+    id: ModuleId;
+    moduleType: ModuleType;
+    immutable: boolean;
+    size: number;
+    moduleGroups: ModuleId[];
+  }[];
+}
+
+export interface PartialBundlingResourcePot {
+  name: string;
+  /** inferred from the module types when omitted */
+  resourcePotType?: ResourcePotType;
+  modules: ModuleId[];
+}
+
+export interface PluginGenerateResourcesHookParams {
+  /** rendered content of the resource pot */
+  content: string;
+  sourceMapChain: string[];
+  resourcePotInfo: ResourcePotInfo;
+}
+
+export interface PluginGenerateResourcesHookResult {
+  resource: Resource;
+  sourceMap?: Resource;
+}
+
 export type PluginFinalizeResourcesHookParams = {
   resourcesMap: Record<string, Resource>;
   config: Config['config'];
@@ -171,8 +266,50 @@ export interface JsPlugin {
     PluginTransformHookResult
   >;
 
+  /**
+   * Process the module after it's parsed, the ast of the module is not available in js plugins, return new content to replace the module
+   */
+  processModule?: JsPluginHook<
+    { resolvedPaths?: string[]; moduleTypes?: string[] },
+    PluginProcessModuleHookParams,
+    PluginProcessModuleHookResult | undefined
+  >;
+
+  /**
+   * Return the deps of the module, the returned deps replace the deps analyzed by previous plugins
+   */
+  analyzeDeps?: JsPluginHook<
+    { resolvedPaths?: string[]; moduleTypes?: string[] },
+    PluginAnalyzeDepsHookParams,
+    PluginAnalyzeDepsHookResultEntry[]
+  >;
+
   buildEnd?: { executor: Callback<Record<string, never>, void> };
 
+  /**
+   * Optimize the module graph, mutating the snapshot has no effect, return a patch to modify the module graph
+   */
+  optimizeModuleGraph?: {
+    executor: Callback<ModuleGraphSnapshot, ModuleGraphPatch | undefined>;
+  };
+
+  /**
+   * Return the module groups to replace the default ones. Every entry and dynamically imported module should be the id of a module group
+   */
+  analyzeModuleGraph?: {
+    executor: Callback<ModuleGraphSnapshot, ModuleGroup[]>;
+  };
+
+  /**
+   * Return the resource pots to replace the default partial bundling result. Every module should be placed in exactly one resource pot
+   */
+  partialBundling?: {
+    executor: Callback<
+      PluginPartialBundlingHookParams,
+      PartialBundlingResourcePot[]
+    >;
+  };
+
   renderStart?: {
     executor: Callback<Config['config'], void>;
   };
@@ -195,6 +332,24 @@ export interface JsPlugin {
     string
   >;
 
+  generateResources?: JsPluginHook<
+    {
+      resourcePotTypes?: ResourcePotType[];
+      moduleIds?: string[];
+    },
+    PluginGenerateResourcesHookParams,
+    PluginGenerateResourcesHookResult
+  >;
+
+  /**
+   * Return a filename template to override `output.filename`, `output.entryFilename` or `output.assetsFilename`
+   */
+  outputFilename?: JsPluginHook<
+    { kinds?: OutputFilenameInfo['kind'][] },
+    OutputFilenameInfo,
+    string
+  >;
+
   finalizeResources?: {
     executor: Callback<
       PluginFinalizeResourcesHookParams,
@@ -133,6 +133,28 @@ export class HmrEngine {
         }
       `);
       });
+
+      // the error modules are kept in the module graph when errorTolerant is enabled, show them in the overlay
+      if (result.errors?.length) {
+        const [{ id, loc }] = result.errors;
+        const errorStr = JSON.stringify({
+          id,
+          loc,
+          frame: loc?.frame,
+          message: result.errors
+            .map(({ id, message }) => `${id}: ${message}`)
+            .join('\n\n')
+        });
+        this._devServer.ws.clients.forEach((client: WebSocketClient) => {
+          client.rawSend(`
+            {
+              type: 'error',
+              err: ${errorStr},
+              overlay: ${this._devServer.config.hmr.overlay}
+            }
+          `);
+        });
+      }
     } catch (err) {
       checkClearScreen(this._compiler.config.config);
       throw new Error(logError(err) as unknown as string);
@@ -20,6 +20,7 @@ export type ResolveKind =
   | 'cssUrl'
   | 'scriptSrc'
   | 'linkHref'
+  | 'htmlAsset'
   | 'hmrUpdate'
   | string;
 
@@ -115,15 +116,38 @@ type BrowserTargetsRecord = Partial<
   >
 > & { [key: string]: string };
 
+export interface OutputFilenameInfo {
+  kind: 'entry' | 'chunk' | 'asset';
+  /** `[name]` or `[resourceName]` */
+  name: string;
+  /** `[entryName]`, only available for entry resources */
+  entryName?: string;
+  /** `[ext]` */
+  ext: string;
+  /** `[dir]`, directory of the source file relative to root, only available for assets. Files outside of root are placed under `_external` with `..` replaced by `_` */
+  dir?: string;
+  /** `[format]` */
+  format: 'esm' | 'cjs' | 'iife' | 'umd';
+  /** `[target]` */
+  target: string;
+}
+
+/**
+ * A filename template or a function that returns the template of each file.
+ * Supported placeholders: `[name]`, `[resourceName]`, `[entryName]`, `[hash]`, `[contentHash]`, `[hash:N]`, `[ext]`,
+ * `[dir]`, `[path]`(`[dir]` with a trailing `/`), `[format]` and `[target]`
+ */
+export type OutputFilename = string | ((info: OutputFilenameInfo) => string);
+
 export interface OutputConfig {
   /**
    * Configure the file name of the output files which contain the entry modules. Prior to filename
    */
-  entryFilename?: string;
+  entryFilename?: OutputFilename;
   /**
    * Configure the name of all the output files
    */
-  filename?: string;
+  filename?: OutputFilename;
   /**
    * Output dir that production files are emitted to.
    */
@@ -138,7 +162,7 @@ export interface OutputConfig {
   /**
    * the same as `filename`, but only for static assets like `.png`, `.jpg`
    */
-  assetsFilename?: string;
+  assetsFilename?: OutputFilename;
   /**
    * Target execution environment of production files, browser or node. browser is equal to `browser-es2017`, node is equal to `node16`.
    * You can also set target env version like `node16`, `node-legacy`, 'browser-legacy`, 'browser-es2015', 'browser-2017', 'browser-esnext'. Farm will automatically downgrade syntax and inject polyfill according to the specified target env.
@@ -158,13 +182,68 @@ export interface OutputConfig {
     | 'library-browser'
     | 'library-node';
   /**
-   * output module format
+   * output module format, `iife` and `umd` are only supported by library targets
+   */
+  format?: 'cjs' | 'esm' | 'iife' | 'umd';
+  /**
+   * The global variable that the exports of the bundle are assigned to when `format` is `iife` or `umd`, must be a valid identifier that is not a reserved word.
+   * External modules are read from the globals configured by `external: [{ jquery: '$' }]`
    */
-  format?: 'cjs' | 'esm';
+  globalName?: string;
   /**
    * clean output.path automatically or not
    */
   clean?: boolean;
+  /**
+   * Emit a manifest that maps entries and source modules to their emitted js, css, assets, static imports and dynamic imports,
+   * for backends that render html themselves. `filename` is `manifest.json` by default.
+   * @default false
+   */
+  manifest?:
+    | boolean
+    | {
+        filename?: string;
+      };
+  /**
+   * Load chunks through stable logical names that are resolved by a generated import map,
+   * so changing one module only changes its own chunk and the import map instead of every chunk that references it.
+   * Html entries get an inline `<script type="importmap">`, other entries import `filename` (`[entryName].import-map.js` by default), which must not contain a content hash.
+   * @default false
+   */
+  importMap?:
+    | boolean
+    | {
+        filename?: string;
+      };
+  /**
+   * Emit one file per module instead of the chunks generated by partial bundling, imports between the files are rewritten to relative paths.
+   * The emitted files keep the paths of the modules relative to `root` (the project root by default), modules outside of it are placed under `_external`.
+   * The extension of `output.entryFilename`/`output.filename` is kept when it's literal, e.g. `[name].mjs` emits `.mjs` files.
+   * Only supported by library targets with `esm` or `cjs` format.
+   * @default false
+   */
+  preserveModules?:
+    | boolean
+    | {
+        root?: string;
+      };
+  /**
+   * Additional outputs generated from the same module graph, for example a `cjs` output besides the `esm` one.
+   * Modules are resolved and transformed once for the primary output, then each variant is generated separately and shares the persistent cache.
+   * Unset fields are inherited from the primary output, make sure the filenames are distinct, e.g. by `[format]`.
+   * The stats of the variants are merged into the stats of the compilation. In watch mode, the variants are regenerated after every update.
+   * Only supported by library targets.
+   * Configuring `output` as a list is the same as putting the rest of the list here.
+   */
+  variants?: OutputVariantConfig[];
+}
+
+export interface OutputVariantConfig {
+  entryFilename?: string;
+  filename?: string;
+  assetsFilename?: string;
+  targetEnv?: 'library' | 'library-browser' | 'library-node';
+  format?: OutputConfig['format'];
 }
 
 export interface ResolveConfig {
@@ -314,6 +393,19 @@ export interface CssConfig {
   prefixer?: {
     targets?: string[] | string | BrowserTargetsRecord;
   } | null;
+  /**
+   * Remove css rules whose selectors are not used by any script or html module. Disabled by default.
+   * Class names, ids and tag names are collected from string literals, template literals, object keys and jsx of script modules,
+   * and from the tags and attributes of html modules.
+   */
+  purge?: {
+    // regexes of class names, ids or tag names that should always be kept
+    safelist?: string[];
+    // only purge css modules whose path matches these regexes
+    include?: string[];
+    // never purge css modules whose path matches these regexes
+    exclude?: string[];
+  } | null;
   /**
    * You SHOULD NOT use this option. It's preserved vite css options for compatibility of vite plugins
    */
@@ -368,6 +460,12 @@ export interface PartialBundlingConfig {
    * @default 1500KB
    */
   targetMaxSize?: number;
+  /**
+   * The size of modules that `targetMinSize` and `targetMaxSize` are compared with.
+   * `source` is the size of the source content, `estimatedGzip` is the estimated size after minify and gzip, which is computed after the module is transformed and cached with the module.
+   * @default 'source'
+   */
+  sizeMetric?: 'source' | 'estimatedGzip';
   /**
    * A group of modules that should be placed together when bundling.
    */
@@ -397,6 +495,20 @@ export interface PartialBundlingConfig {
    * @default ["node_modules"]
    */
   immutableModules?: string[];
+  /**
+   * Emit a json report that explains why the modules are placed in their resources: the module bucket, the matched `groups` or `enforceResources`,
+   * and the merges caused by the target sizes and `targetConcurrentRequests`. The report is also recorded in the compilation stats.
+   * @default false
+   */
+  report?:
+    | boolean
+    | {
+        /**
+         * name of the report file, relative to `output.path`
+         * @default 'partial-bundling-report.json'
+         */
+        filename?: string;
+      };
 }
 
 export interface PresetEnvConfig {
@@ -441,11 +553,68 @@ export interface Config {
       include?: string[];
       publicDir?: string;
       mode?: 'node' | 'browser';
+      /**
+       * Assets smaller than this size in bytes are inlined as data uris instead of being emitted, for js imports, css `url()` and html references.
+       * Svg is url encoded if it's smaller than base64. Disabled by default.
+       */
+      inlineLimit?: number;
+      /**
+       * Only inline assets whose path matches these regexes, all assets under `inlineLimit` are inlined if empty
+       */
+      inlineInclude?: string[];
+      /**
+       * Never inline assets whose path matches these regexes
+       */
+      inlineExclude?: string[];
     };
     script?: ScriptConfig;
     css?: CssConfig;
     html?: {
       base?: string;
+      /**
+       * Inline the initial css of html entries into a `<style>` tag instead of injecting `<link>`.
+       */
+      criticalCss?: {
+        /**
+         * When the initial css is larger than this size in bytes, only the rules used by the html are inlined,
+         * the whole css is still loaded without blocking rendering. The whole css is inlined if not set.
+         */
+        threshold?: number;
+      };
+      /**
+       * Inject `<link rel="preload">` for initial resources and `<link rel="prefetch">` for dynamic resources.
+       */
+      resourceHints?: {
+        /**
+         * Types of the initial resources to preload. Default to `['script', 'css', 'font']`.
+         * Stylesheets that are already loaded by a blocking `<link rel="stylesheet">` are not preloaded.
+         */
+        preload?: ('script' | 'css' | 'font')[];
+        /**
+         * Use `modulepreload` instead of `preload` for scripts. Only enable it when scripts are loaded as module scripts.
+         */
+        modulePreload?: boolean;
+        /**
+         * Prefetch the resources of dynamic imports. Default to `true`.
+         * Use `import(/* farmPrefetch: false *\/ './lazy')` or `webpackPrefetch` to override it for a single import,
+         * and `import(/* farmPreload: true *\/ './lazy')` or `webpackPreload` to preload the resources of the import.
+         */
+        prefetch?: boolean;
+      };
+      /**
+       * Add `integrity` and `crossorigin` attributes to injected `<script>` and `<link>`.
+       * Resources loaded dynamically by the runtime are verified too.
+       */
+      integrity?: 'sha256' | 'sha384' | 'sha512';
+      /**
+       * Placeholder of the CSP nonce, e.g. `{{nonce}}`. The server should replace it with a random nonce for each request.
+       * It's added to all injected inline `<script>` and `<style>`, and to the styles and resources created by the runtime.
+       */
+      cspNonce?: string;
+      /**
+       * Emit `[htmlName].csp.json` that contains the hashes of inline scripts and styles of each html.
+       */
+      cspHashes?: 'sha256' | 'sha384' | 'sha512';
     };
     /**
      * Configure whether to enable sourcemap, optional configuration items and descriptions are as follows:
@@ -464,6 +633,16 @@ export interface Config {
      * Whether to enable lazy compilation, configure to false to disabled. See https://farmfe.org/docs/features/lazy-compilation
      */
     lazyCompilation?: boolean;
+    /**
+     * Keep the modules that fail to build as error modules instead of failing the compilation, the errors are shown in
+     * the overlay and cleared once the modules compile again. Enabled by default by the dev server
+     */
+    errorTolerant?: boolean;
+    /**
+     * Keep building the other modules after module errors and report all the errors at once in a deterministic order,
+     * useful in CI. Defaults to false
+     */
+    continueOnError?: boolean;
     /**
      * Whether to enable tree shake, set to false to disable. See https://farmfe.org/docs/features/tree-shake
      */
@@ -474,6 +653,33 @@ export interface Config {
     presetEnv?: boolean | PresetEnvConfig;
     persistentCache?: boolean | PersistentCacheConfig;
     comments?: boolean | 'license';
+    /**
+     * Emit `.gz` and `.br` variants of js, css, html, svg and json resources larger than `threshold` bytes.
+     * The compressed sizes are recorded in the compilation stats. HMR updates in development are not compressed.
+     * @default false
+     */
+    compression?:
+      | boolean
+      | {
+          /**
+           * @default ['gzip', 'brotli']
+           */
+          algorithms?: ('gzip' | 'brotli')[];
+          /**
+           * @default 1024
+           */
+          threshold?: number;
+          /**
+           * 0-9
+           * @default 9
+           */
+          gzipLevel?: number;
+          /**
+           * 0-11
+           * @default 11
+           */
+          brotliLevel?: number;
+        };
     custom?: Record<string, any>;
   };
   jsPlugins?: JsPlugin[];
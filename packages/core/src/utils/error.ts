@@ -2,7 +2,16 @@ export function convertErrorMessage(error: Error) {
   let errorMessage = '';
 
   try {
-    errorMessage = JSON.parse(error.message).join('\n');
+    // the errors that have a location are serialized as json objects, see SerializedCompilationError
+    errorMessage = JSON.parse(error.message)
+      .map((message: string) => {
+        try {
+          return JSON.parse(message).message ?? message;
+        } catch {
+          return message;
+        }
+      })
+      .join('\n');
   } catch {
     errorMessage = error.message;
   }
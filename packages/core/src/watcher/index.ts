@@ -113,6 +113,18 @@ export class FileWatcher implements ImplFileWatcher {
       handlePathChange(path);
     });
 
+    // created files are handled when they are matched by the glob patterns of a module, e.g. `import.meta.glob('./dir/*.ts')`
+    // and `require.context('./dir')`, or when an import of a module failed to resolve because they were missing
+    this._watcher.on('add', (path) => {
+      if (this._close) return;
+      handlePathChange(path);
+    });
+
+    this._watcher.on('unlink', (path) => {
+      if (this._close) return;
+      handlePathChange(path);
+    });
+
     const handleUpdateFinish = (updateResult: JsUpdateResult) => {
       const added = [
         ...updateResult.added,
@@ -46,6 +46,8 @@ test('resolveUserConfig', async () => {
     targetEnv: 'browser'
   });
   expect(config.compilation.lazyCompilation).toEqual(true);
+  // only the dev server enables errorTolerant by default, development builds and watch mode fail on errors
+  expect(config.compilation.errorTolerant).toBeUndefined();
   expect(config.compilation.sourcemap).toEqual(true);
   expect(config.compilation.minify).toEqual(false);
   expect(config.compilation.presetEnv).toEqual(false);
@@ -5,7 +5,10 @@ import {
   normalizeUserCompilationConfig
 } from '../../src/config/index.js';
 import { mergeFarmCliConfig } from '../../src/config/mergeConfig.js';
-import { normalizeOutput } from '../../src/config/normalize-config/normalize-output.js';
+import {
+  createOutputFilenamePlugin,
+  normalizeOutput
+} from '../../src/config/normalize-config/normalize-output.js';
 import { NoopLogger } from '../../src/index.js';
 
 describe('mergeFarmCliConfig', () => {
@@ -147,3 +150,36 @@ describe('normalizeOutput', () => {
     });
   });
 });
+
+describe('createOutputFilenamePlugin', () => {
+  test('string filenames do not create the plugin', () => {
+    const resolvedConfig: ResolvedCompilation = {
+      output: { filename: '[name].[hash:12].[ext]' }
+    };
+
+    expect(createOutputFilenamePlugin(resolvedConfig)).toBeUndefined();
+    expect(resolvedConfig.output.filename).toEqual('[name].[hash:12].[ext]');
+  });
+
+  test('function filenames are called through the outputFilename hook', async () => {
+    const resolvedConfig: ResolvedCompilation = {
+      output: {
+        assetsFilename: (info) => `static/${info.dir}/[name].[hash:6].[ext]`
+      }
+    };
+    const plugin = createOutputFilenamePlugin(resolvedConfig);
+
+    expect(typeof resolvedConfig.output.assetsFilename).toEqual('string');
+    expect(plugin.outputFilename.filters.kinds).toEqual(['asset']);
+    expect(
+      await plugin.outputFilename.executor({
+        kind: 'asset',
+        name: 'logo',
+        ext: 'png',
+        dir: 'src/assets',
+        format: 'esm',
+        target: 'browser'
+      })
+    ).toEqual('static/src/assets/[name].[hash:6].[ext]');
+  });
+});
@@ -0,0 +1 @@
+(globalThis as any).injected = true;
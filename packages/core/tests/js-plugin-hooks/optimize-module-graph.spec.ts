@@ -0,0 +1,88 @@
+import { expect, test } from 'vitest';
+import { getOutputResult } from '../common.js';
+import { getCompiler, getOutputFilePath } from './common.js';
+
+test('Js Plugin Execution - optimizeModuleGraph', async () => {
+  const hookName = 'partial-bundling';
+  const calledHooks: string[] = [];
+  const compiler = await getCompiler(
+    'optimize-module-graph',
+    [
+      {
+        name: 'test-optimize-module-graph',
+        priority: 1000,
+        optimizeModuleGraph: {
+          executor: async (moduleGraph) => {
+            calledHooks.push('optimizeModuleGraph');
+            const index = moduleGraph.modules.find(
+              (m) => m.id === 'index.ts?foo=bar'
+            );
+            expect(index?.dependencies).toEqual([
+              { id: 'dep.ts', dynamic: false }
+            ]);
+
+            return {
+              removeEdges: [{ from: 'index.ts?foo=bar', to: 'dep.ts' }],
+              addEdges: [
+                {
+                  from: 'index.ts?foo=bar',
+                  to: 'dep.ts',
+                  source: './dep',
+                  kind: 'dynamicImport'
+                }
+              ]
+            };
+          }
+        },
+        analyzeModuleGraph: {
+          executor: async (moduleGraph) => {
+            calledHooks.push('analyzeModuleGraph');
+            // the patch returned by optimizeModuleGraph is applied to the module graph
+            const index = moduleGraph.modules.find(
+              (m) => m.id === 'index.ts?foo=bar'
+            );
+            expect(index?.dependencies).toEqual([
+              { id: 'dep.ts', dynamic: true }
+            ]);
+
+            return moduleGraph.entries.map((entry) => ({
+              id: entry,
+              modules: moduleGraph.modules.map((m) => m.id)
+            }));
+          }
+        }
+      }
+    ],
+    hookName
+  );
+
+  await compiler.compile();
+  await compiler.writeResourcesToDisk();
+
+  expect(calledHooks).toEqual(['optimizeModuleGraph', 'analyzeModuleGraph']);
+
+  const outputFilePath = getOutputFilePath('optimize-module-graph', hookName);
+  const result = await getOutputResult(outputFilePath);
+  expect(result.default).toBe('dep');
+});
+
+test('Js Plugin Execution - optimizeModuleGraph should patch existing modules', async () => {
+  const hookName = 'partial-bundling';
+  const compiler = await getCompiler(
+    'optimize-module-graph-invalid',
+    [
+      {
+        name: 'test-optimize-module-graph-invalid',
+        priority: 1000,
+        optimizeModuleGraph: {
+          executor: async () => ({ removeModules: ['not-exist.ts'] })
+        }
+      }
+    ],
+    hookName
+  );
+
+  await expect(compiler.compile()).rejects.toThrow(
+    'module not-exist.ts does not exist in the module graph'
+  );
+});
@@ -0,0 +1,167 @@
+import { readFileSync } from 'node:fs';
+import { expect, test } from 'vitest';
+import { getOutputResult } from '../common.js';
+import { getCompiler, getOutputFilePath } from './common.js';
+
+test('Js Plugin Execution - analyzeDeps/analyzeModuleGraph/partialBundling', async () => {
+  const hookName = 'partial-bundling';
+  const calledHooks: string[] = [];
+  const compiler = await getCompiler(
+    '',
+    [
+      {
+        name: 'test-partial-bundling',
+        priority: 1000,
+        analyzeDeps: {
+          filters: {
+            moduleTypes: ['ts']
+          },
+          executor: async (param) => {
+            if (param.moduleId !== 'index.ts?foo=bar') {
+              return;
+            }
+
+            calledHooks.push('analyzeDeps');
+            return [...param.deps, { source: './injected', kind: 'import' }];
+          }
+        },
+        analyzeModuleGraph: {
+          executor: async (moduleGraph) => {
+            calledHooks.push('analyzeModuleGraph');
+            expect(moduleGraph.entries).toEqual(['index.ts?foo=bar']);
+
+            return moduleGraph.entries.map((entry) => ({
+              id: entry,
+              modules: moduleGraph.modules.map((m) => m.id)
+            }));
+          }
+        },
+        partialBundling: {
+          executor: async (param) => {
+            calledHooks.push('partialBundling');
+            const ids = param.modules.map((m) => m.id);
+            ids.sort();
+            expect(ids).toEqual(['dep.ts', 'index.ts?foo=bar', 'injected.ts']);
+
+            return [
+              {
+                name: 'index',
+                modules: ids
+              }
+            ];
+          }
+        }
+      }
+    ],
+    hookName
+  );
+
+  await compiler.compile();
+  await compiler.writeResourcesToDisk();
+
+  expect(calledHooks).toEqual([
+    'analyzeDeps',
+    'analyzeModuleGraph',
+    'partialBundling'
+  ]);
+
+  const outputFilePath = getOutputFilePath('', hookName);
+  const result = await getOutputResult(outputFilePath);
+  expect(result.default).toBe('dep');
+});
+
+test('Js Plugin Execution - partialBundling should place every module', async () => {
+  const hookName = 'partial-bundling';
+  const compiler = await getCompiler(
+    '',
+    [
+      {
+        name: 'test-partial-bundling-missing-modules',
+        priority: 1000,
+        partialBundling: {
+          executor: async (param) => [
+            { name: 'index', modules: [param.modules[0].id] }
+          ]
+        }
+      }
+    ],
+    hookName
+  );
+
+  await expect(compiler.compile()).rejects.toThrow(
+    'is not placed in any resource pot'
+  );
+});
+
+test('Js Plugin Execution - analyzeModuleGraph should group every module', async () => {
+  const hookName = 'partial-bundling';
+  const compiler = await getCompiler(
+    '',
+    [
+      {
+        name: 'test-analyze-module-graph-missing-modules',
+        priority: 1000,
+        analyzeModuleGraph: {
+          executor: async (moduleGraph) =>
+            moduleGraph.entries.map((entry) => ({ id: entry, modules: [] }))
+        }
+      }
+    ],
+    hookName
+  );
+
+  await expect(compiler.compile()).rejects.toThrow(
+    'module dep.ts is not in any of the returned module groups'
+  );
+});
+
+test('Js Plugin Execution - generateResources', async () => {
+  const hookName = 'partial-bundling';
+  const calledHooks: string[] = [];
+  const compiler = await getCompiler(
+    'generate-resources',
+    [
+      {
+        name: 'test-generate-resources',
+        priority: 1000,
+        generateResources: {
+          filters: {
+            moduleIds: ['^index.ts\\?foo=bar$']
+          },
+          executor: async (param) => {
+            calledHooks.push('generateResources');
+            expect(param.resourcePotInfo.resourcePotType).toBe('js');
+
+            return {
+              resource: {
+                name: param.resourcePotInfo.name,
+                bytes: Array.from(
+                  Buffer.from(`${param.content}\n/* generated by js plugin */`)
+                ),
+                emitted: false,
+                resourceType: 'js',
+                origin: {
+                  type: 'ResourcePot',
+                  value: param.resourcePotInfo.id
+                }
+              }
+            };
+          }
+        }
+      }
+    ],
+    hookName
+  );
+
+  await compiler.compile();
+  await compiler.writeResourcesToDisk();
+
+  expect(calledHooks).toEqual(['generateResources']);
+
+  const outputFilePath = getOutputFilePath('generate-resources', hookName);
+  expect(readFileSync(outputFilePath, 'utf-8')).toContain(
+    '/* generated by js plugin */'
+  );
+  const result = await getOutputResult(outputFilePath);
+  expect(result.default).toBe('dep');
+});
@@ -0,0 +1,63 @@
+import { expect, test } from 'vitest';
+import { getOutputResult } from '../common.js';
+import { getCompiler, getOutputFilePath } from './common.js';
+
+test('Js Plugin Execution - processModule', async () => {
+  const hookName = 'partial-bundling';
+  const calledHooks: string[] = [];
+  const compiler = await getCompiler(
+    'process-module',
+    [
+      {
+        name: 'test-process-module',
+        priority: 1000,
+        processModule: {
+          filters: {
+            resolvedPaths: ['dep\\.ts$']
+          },
+          executor: async (param) => {
+            calledHooks.push('processModule');
+            expect(param.moduleId).toBe('dep.ts');
+            expect(param.moduleType).toBe('ts');
+
+            return {
+              content: param.content.replace("'dep'", "'processed'")
+            };
+          }
+        }
+      }
+    ],
+    hookName
+  );
+
+  await compiler.compile();
+  await compiler.writeResourcesToDisk();
+
+  expect(calledHooks).toEqual(['processModule']);
+
+  const outputFilePath = getOutputFilePath('process-module', hookName);
+  const result = await getOutputResult(outputFilePath);
+  expect(result.default).toBe('processed');
+});
+
+test('Js Plugin Execution - processModule should return parsable content', async () => {
+  const hookName = 'partial-bundling';
+  const compiler = await getCompiler(
+    'process-module-invalid',
+    [
+      {
+        name: 'test-process-module-invalid',
+        priority: 1000,
+        processModule: {
+          filters: {
+            resolvedPaths: ['dep\\.ts$']
+          },
+          executor: async () => ({ content: 'export const = ;' })
+        }
+      }
+    ],
+    hookName
+  );
+
+  await expect(compiler.compile()).rejects.toThrow('dep.ts');
+});
@@ -280,6 +280,14 @@ export class ModuleSystem {
     this.dynamicModuleResourcesMap = dynamicModuleResourcesMap;
   }
 
+  // The import map is injected during compile time when `output.importMap` is enabled, it maps the logical resource names to the emitted resources
+  setImportMap(imports: Record<string, string>): void {
+    this.resourceLoader.importMap = {
+      ...(this.resourceLoader.importMap || {}),
+      ...imports,
+    };
+  }
+
   // The public paths are injected during compile time
   setPublicPaths(publicPaths: string[]): void {
     this.publicPaths = publicPaths;
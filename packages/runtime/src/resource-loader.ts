@@ -6,6 +6,8 @@ import type { ResourceLoadResult } from './plugin';
 export interface Resource {
   path: string;
   type: 0 | 1; // 0: script, 1: link
+  // subresource integrity of the resource, injected when `html.integrity` is enabled
+  integrity?: string;
 }
 
 // Injected during build
@@ -16,6 +18,38 @@ export const targetEnv = __farm_global_this__.__FARM_TARGET_ENV__ || 'node';
 export const isBrowser =
   targetEnv === 'browser' && __global_this__.document;
 
+/**
+ * Use the nonce of `<meta property="csp-nonce">` injected when `html.cspNonce` is configured.
+ */
+function setCspNonce(element: HTMLScriptElement | HTMLLinkElement) {
+  const meta = document.querySelector<HTMLMetaElement>(
+    'meta[property="csp-nonce"]'
+  );
+
+  if (meta && meta.nonce) {
+    element.nonce = meta.nonce;
+  }
+}
+
+/**
+ * Read the `<script type="importmap">` injected when `output.importMap` is enabled.
+ */
+function readDocumentImportMap(): Record<string, string> {
+  const script = document.querySelector<HTMLScriptElement>(
+    'script[type="importmap"]'
+  );
+
+  if (!script || !script.textContent) {
+    return {};
+  }
+
+  try {
+    return JSON.parse(script.textContent).imports || {};
+  } catch (e) {
+    return {};
+  }
+}
+
 /**
  * Loading resources according to their type and target env.
  */
@@ -24,12 +58,27 @@ export class ResourceLoader {
   private _loadingResources: Record<string, Promise<void>> = {};
 
   publicPaths: string[];
+  // logical resource name -> url of the emitted resource, set when `output.importMap` is enabled
+  importMap: Record<string, string> | null = null;
 
   constructor(private moduleSystem: ModuleSystem, publicPaths: string[]) {
     this.publicPaths = publicPaths;
   }
 
+  /**
+   * Resolve the url of a logical resource name through the import map, return undefined if the resource is not in the import map
+   */
+  resolve(path: string): string | undefined {
+    if (!this.importMap) {
+      this.importMap = isBrowser ? readDocumentImportMap() : {};
+    }
+
+    return this.importMap[path];
+  }
+
   load(resource: Resource, index = 0): Promise<void> {
+    const resolved = this.resolve(resource.path);
+
     // it's not running in browser
     if (!isBrowser) {
       const result = this.moduleSystem.pluginContainer.hookBail(
@@ -41,9 +90,9 @@ export class ResourceLoader {
         return result.then((res: ResourceLoadResult) => {
           if (!res.success && res.retryWithDefaultResourceLoader) {
             if (resource.type === 0) {
-              return this._loadScript(`./${resource.path}`);
+              return this._loadScript(resolved ?? `./${resource.path}`);
             } else if (resource.type === 1) {
-              return this._loadLink(`./${resource.path}`);
+              return this._loadLink(resolved ?? `./${resource.path}`);
             }
           } else if (!res.success) {
             throw new Error(
@@ -53,17 +102,19 @@ export class ResourceLoader {
         });
       } else {
         if (resource.type === 0) {
-          return this._loadScript(`./${resource.path}`);
+          return this._loadScript(resolved ?? `./${resource.path}`);
         } else if (resource.type === 1) {
-          return this._loadLink(`./${resource.path}`);
+          return this._loadLink(resolved ?? `./${resource.path}`);
         }
       }
     }
 
     const publicPath = this.publicPaths[index];
-    const url = `${
-      publicPath.endsWith('/') ? publicPath.slice(0, -1) : publicPath
-    }/${resource.path}`;
+    const url =
+      resolved ??
+      `${
+        publicPath.endsWith('/') ? publicPath.slice(0, -1) : publicPath
+      }/${resource.path}`;
 
     if (this._loadedResources[resource.path]) {
       return Promise.resolve();
@@ -107,9 +158,9 @@ export class ResourceLoader {
     let promise = Promise.resolve();
 
     if (resource.type === 0) {
-      promise = this._loadScript(url);
+      promise = this._loadScript(url, resource.integrity);
     } else if (resource.type === 1) {
-      promise = this._loadLink(url);
+      promise = this._loadLink(url, resource.integrity);
     }
 
     this._loadingResources[resource.path] = promise;
@@ -139,7 +190,7 @@ export class ResourceLoader {
     return promise;
   }
 
-  private _loadScript(path: string): Promise<void> {
+  private _loadScript(path: string, integrity?: string): Promise<void> {
     // @ts-ignore
     if (FARM_RUNTIME_TARGET_ENV !== 'browser') {
       return import(path);
@@ -147,6 +198,13 @@ export class ResourceLoader {
       return new Promise((resolve, reject) => {
         const script = document.createElement('script');
         script.src = path;
+        setCspNonce(script);
+
+        if (integrity) {
+          script.integrity = integrity;
+          script.crossOrigin = 'anonymous';
+        }
+
         document.body.appendChild(script);
 
         script.onload = () => {
@@ -159,7 +217,7 @@ export class ResourceLoader {
     }
   }
 
-  private _loadLink(path: string): Promise<void> {
+  private _loadLink(path: string, integrity?: string): Promise<void> {
     // @ts-ignore
     if (FARM_RUNTIME_TARGET_ENV !== 'browser') {
       // return Promise.reject(new Error('Not support loading css in SSR'));
@@ -170,6 +228,13 @@ export class ResourceLoader {
         const link = document.createElement('link');
         link.rel = 'stylesheet';
         link.href = path;
+        setCspNonce(link);
+
+        if (integrity) {
+          link.integrity = integrity;
+          link.crossOrigin = 'anonymous';
+        }
+
         document.head.appendChild(link);
 
         link.onload = () => {
@@ -296,6 +296,7 @@ impl Plugin for FarmPluginSass {
         farmfe_core::error::CompilationError::TransformError {
           resolved_path: param.resolved_path.to_string(),
           msg: e.message().to_string(),
+          loc: None,
         }
       })?;
 